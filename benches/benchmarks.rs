@@ -875,6 +875,45 @@ fn dynamic_matmul_comparison(c: &mut Criterion) {
         });
     }
 }
+/// The triple loop previously used for non-BLAS types, kept as a baseline.
+fn naive_matmul(a: &[i32], b: &[i32], c: &mut [i32], m: usize, n: usize, k: usize) {
+    for i in 0..m {
+        for l in 0..k {
+            for j in 0..n {
+                c[i * n + j] += a[i * k + l] * b[l * n + j];
+            }
+        }
+    }
+}
+fn integer_rand(rows: usize, columns: usize) -> MatrixDxD<i32> {
+    let mut rng = rand::thread_rng();
+    let data = (0..rows * columns).map(|_| rng.gen_range(0..100)).collect();
+    MatrixDxD::try_from((rows, columns, data)).unwrap()
+}
+fn integer_matmul_comparison(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Integer matrix multiplication");
+    group.warm_up_time(std::time::Duration::from_millis(100));
+    group.measurement_time(std::time::Duration::from_millis(500));
+    for i in [16, 32, 64, 128, 256] {
+        group.bench_with_input(BenchmarkId::new("ndarray", i), &i, |b, &i| {
+            let x = ndarray::Array2::from_shape_fn((i, i), |(j, k)| (j * k % 100) as i32);
+            let y = ndarray::Array2::from_shape_fn((i, i), |(j, k)| (j + k) as i32 % 100);
+            b.iter(|| x.dot(&y))
+        });
+        group.bench_with_input(BenchmarkId::new("naive", i), &i, |b, &i| {
+            let (x, y) = (integer_rand(i, i), integer_rand(i, i));
+            b.iter(|| {
+                let mut z = vec![0; i * i];
+                naive_matmul(x.as_slice(), y.as_slice(), &mut z, i, i, i);
+                z
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("static-la", i), &i, |b, &i| {
+            let (x, y) = (integer_rand(i, i), integer_rand(i, i));
+            b.iter(|| x.matmul(&y))
+        });
+    }
+}
 
 criterion_group!(
     benches,
@@ -888,6 +927,7 @@ criterion_group!(
     dynamic_sub_comparison,
     dynamic_mul_comparison,
     dynamic_div_comparison,
-    dynamic_matmul_comparison
+    dynamic_matmul_comparison,
+    integer_matmul_comparison
 );
 criterion_main!(benches);
\ No newline at end of file
@@ -1,236 +1,128 @@
+use crate::iter::zip_rows_apply;
 use crate::*;
 use std::ops::BitAndAssign;
 
 // T
 // --------------------------------------------------
-impl<T: BitAndAssign + Copy> BitAndAssign<T> for MatrixDxD<T> {
-    fn bitand_assign(&mut self, x: T) {
-        for a in self.data.iter_mut() {
-            *a &= x;
-        }
-    }
-}
-impl<T: BitAndAssign + Copy, const COLUMNS: usize> BitAndAssign<T> for MatrixDxS<T, COLUMNS> {
-    fn bitand_assign(&mut self, x: T) {
-        for a in self.data.iter_mut() {
-            *a &= x;
-        }
-    }
-}
-impl<T: BitAndAssign + Copy, const ROWS: usize> BitAndAssign<T> for MatrixSxD<T, ROWS> {
-    fn bitand_assign(&mut self, x: T) {
-        for a in self.data.iter_mut() {
-            *a &= x;
-        }
-    }
-}
-impl<T: BitAndAssign + Copy, const ROWS: usize, const COLUMNS: usize> BitAndAssign<T>
-    for MatrixSxS<T, ROWS, COLUMNS>
+impl<T: BitAndAssign + Copy, R: Dim, C: Dim, L: Layout> BitAndAssign<T> for Matrix<T, R, C, L>
 where
-    [(); ROWS * COLUMNS]:,
+    (R, C, L): Storage<T>,
 {
     fn bitand_assign(&mut self, x: T) {
-        for a in self.data.iter_mut() {
+        for a in self.data.as_mut().iter_mut() {
             *a &= x;
         }
     }
 }
-// MatrixDxD
+// Matrix
 // --------------------------------------------------
-impl<T: BitAndAssign + Copy> BitAndAssign<MatrixDxD<T>> for MatrixDxD<T> {
-    fn bitand_assign(&mut self, other: Self) {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
-        }
-    }
-}
-impl<T: BitAndAssign + Copy, const COLUMNS: usize> BitAndAssign<MatrixDxS<T, COLUMNS>>
-    for MatrixDxD<T>
-{
-    fn bitand_assign(&mut self, other: MatrixDxS<T, COLUMNS>) {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
-        }
-    }
-}
-impl<T: BitAndAssign + Copy, const ROWS: usize> BitAndAssign<MatrixSxD<T, ROWS>> for MatrixDxD<T> {
-    fn bitand_assign(&mut self, other: MatrixSxD<T, ROWS>) {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
-        }
-    }
-}
-impl<T: BitAndAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitAndAssign<MatrixSxS<T, ROWS, COLUMNS>> for MatrixDxD<T>
+impl<T: BitAndAssign + Copy, R: Dim, C: Dim, L: Layout, R2: Dim, C2: Dim, L2: Layout>
+    BitAndAssign<Matrix<T, R2, C2, L2>> for Matrix<T, R, C, L>
 where
-    [(); ROWS * COLUMNS]:,
+    R: Join<R2>,
+    C: Join<C2>,
+    (R, C, L): Storage<T>,
+    (R2, C2, L2): Storage<T>,
 {
-    fn bitand_assign(&mut self, other: MatrixSxS<T, ROWS, COLUMNS>) {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
-        }
+    fn bitand_assign(&mut self, other: Matrix<T, R2, C2, L2>) {
+        self.zip_apply(&other, |a, b| *a &= *b);
     }
 }
-// MatrixDxS
+// ViewDxDMut
 // --------------------------------------------------
-impl<T: BitAndAssign + Copy, const COLUMNS: usize> BitAndAssign<MatrixDxS<T, COLUMNS>>
-    for MatrixDxS<T, COLUMNS>
-{
-    fn bitand_assign(&mut self, other: Self) {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
-        }
-    }
-}
-impl<T: BitAndAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitAndAssign<MatrixSxD<T, ROWS>> for MatrixDxS<T, COLUMNS>
-{
-    fn bitand_assign(&mut self, other: MatrixSxD<T, ROWS>) {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
-        }
-    }
-}
-impl<T: BitAndAssign + Copy, const COLUMNS: usize> BitAndAssign<MatrixDxD<T>>
-    for MatrixDxS<T, COLUMNS>
-{
-    fn bitand_assign(&mut self, other: MatrixDxD<T>) {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
+impl<'a, T: BitAndAssign + Copy> BitAndAssign<T> for ViewDxDMut<'a, T> {
+    fn bitand_assign(&mut self, x: T) {
+        for a in self.iter_mut() {
+            *a &= x;
         }
     }
 }
-impl<T: BitAndAssign + Copy + Default, const ROWS: usize, const COLUMNS: usize>
-    BitAndAssign<MatrixSxS<T, ROWS, COLUMNS>> for MatrixDxS<T, COLUMNS>
+impl<'a, T: BitAndAssign + Copy, R2: Dim, C2: Dim, L2: Layout> BitAndAssign<Matrix<T, R2, C2, L2>>
+    for ViewDxDMut<'a, T>
 where
-    [(); ROWS * COLUMNS]:,
+    Dynamic: Join<R2>,
+    Dynamic: Join<C2>,
+    (R2, C2, L2): Storage<T>,
 {
-    fn bitand_assign(&mut self, other: MatrixSxS<T, ROWS, COLUMNS>) {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
-        }
+    fn bitand_assign(&mut self, other: Matrix<T, R2, C2, L2>) {
+        let shape = (self.rows(), self.columns());
+        zip_rows_apply(self.iter_mut(), shape, &other, |a, b| *a &= *b);
     }
 }
-// MatrixSxD
+// ViewDxSMut
 // --------------------------------------------------
-impl<T: BitAndAssign + Copy, const ROWS: usize> BitAndAssign<MatrixSxD<T, ROWS>>
-    for MatrixSxD<T, ROWS>
-{
-    fn bitand_assign(&mut self, other: Self) {
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
-        }
-    }
-}
-impl<T: BitAndAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitAndAssign<MatrixDxS<T, COLUMNS>> for MatrixSxD<T, ROWS>
+impl<'a, T: BitAndAssign + Copy, const COLUMNS: usize> BitAndAssign<T>
+    for ViewDxSMut<'a, T, COLUMNS>
 {
-    fn bitand_assign(&mut self, other: MatrixDxS<T, COLUMNS>) {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
-        }
-    }
-}
-impl<T: BitAndAssign + Copy, const ROWS: usize> BitAndAssign<MatrixDxD<T>> for MatrixSxD<T, ROWS> {
-    fn bitand_assign(&mut self, other: MatrixDxD<T>) {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
+    fn bitand_assign(&mut self, x: T) {
+        for a in self.iter_mut() {
+            *a &= x;
         }
     }
 }
-impl<T: BitAndAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitAndAssign<MatrixSxS<T, ROWS, COLUMNS>> for MatrixSxD<T, ROWS>
+impl<'a, T: BitAndAssign + Copy, const COLUMNS: usize, R2: Dim, C2: Dim, L2: Layout>
+    BitAndAssign<Matrix<T, R2, C2, L2>> for ViewDxSMut<'a, T, COLUMNS>
 where
-    [(); ROWS * COLUMNS]:,
+    Dynamic: Join<R2>,
+    Static<COLUMNS>: Join<C2>,
+    (R2, C2, L2): Storage<T>,
 {
-    fn bitand_assign(&mut self, other: MatrixSxS<T, ROWS, COLUMNS>) {
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
-        }
+    fn bitand_assign(&mut self, other: Matrix<T, R2, C2, L2>) {
+        let shape = (self.rows(), self.columns());
+        zip_rows_apply(self.iter_mut(), shape, &other, |a, b| *a &= *b);
     }
 }
-// MatrixSxS
+// ViewSxDMut
 // --------------------------------------------------
-impl<T: BitAndAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitAndAssign<MatrixSxS<T, ROWS, COLUMNS>> for MatrixSxS<T, ROWS, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    fn bitand_assign(&mut self, other: Self) {
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
+impl<'a, T: BitAndAssign + Copy, const ROWS: usize> BitAndAssign<T> for ViewSxDMut<'a, T, ROWS> {
+    fn bitand_assign(&mut self, x: T) {
+        for a in self.iter_mut() {
+            *a &= x;
         }
     }
 }
-impl<T: BitAndAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitAndAssign<MatrixDxS<T, COLUMNS>> for MatrixSxS<T, ROWS, COLUMNS>
+impl<'a, T: BitAndAssign + Copy, const ROWS: usize, R2: Dim, C2: Dim, L2: Layout>
+    BitAndAssign<Matrix<T, R2, C2, L2>> for ViewSxDMut<'a, T, ROWS>
 where
-    [(); ROWS * COLUMNS]:,
+    Static<ROWS>: Join<R2>,
+    Dynamic: Join<C2>,
+    (R2, C2, L2): Storage<T>,
 {
-    fn bitand_assign(&mut self, other: MatrixDxS<T, COLUMNS>) {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
-        }
+    fn bitand_assign(&mut self, other: Matrix<T, R2, C2, L2>) {
+        let shape = (self.rows(), self.columns());
+        zip_rows_apply(self.iter_mut(), shape, &other, |a, b| *a &= *b);
     }
 }
-impl<T: BitAndAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitAndAssign<MatrixSxD<T, ROWS>> for MatrixSxS<T, ROWS, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
+// ViewSxSMut
+// --------------------------------------------------
+impl<'a, T: BitAndAssign + Copy, const ROWS: usize, const COLUMNS: usize> BitAndAssign<T>
+    for ViewSxSMut<'a, T, ROWS, COLUMNS>
 {
-    fn bitand_assign(&mut self, other: MatrixSxD<T, ROWS>) {
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
+    fn bitand_assign(&mut self, x: T) {
+        for a in self.iter_mut() {
+            *a &= x;
         }
     }
 }
-impl<T: BitAndAssign + Copy, const ROWS: usize, const COLUMNS: usize> BitAndAssign<MatrixDxD<T>>
-    for MatrixSxS<T, ROWS, COLUMNS>
+impl<
+        'a,
+        T: BitAndAssign + Copy,
+        const ROWS: usize,
+        const COLUMNS: usize,
+        R2: Dim,
+        C2: Dim,
+        L2: Layout,
+    > BitAndAssign<Matrix<T, R2, C2, L2>> for ViewSxSMut<'a, T, ROWS, COLUMNS>
 where
-    [(); ROWS * COLUMNS]:,
+    Static<ROWS>: Join<R2>,
+    Static<COLUMNS>: Join<C2>,
+    (R2, C2, L2): Storage<T>,
 {
-    fn bitand_assign(&mut self, other: MatrixDxD<T>) {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a &= *b;
-        }
+    fn bitand_assign(&mut self, other: Matrix<T, R2, C2, L2>) {
+        let shape = (self.rows(), self.columns());
+        zip_rows_apply(self.iter_mut(), shape, &other, |a, b| *a &= *b);
     }
 }
-
 // Tests
 // --------------------------------------------------
 #[cfg(test)]
@@ -426,4 +318,372 @@ mod tests {
         let d = MatrixSxS::from([[false, true, false], [true, false, false]]);
         assert_eq!(a, d);
     }
+    // ViewDxDMut
+    // --------------------------------------------------
+    #[test]
+    fn t_view_dxd() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let mut c = a.slice_dxd_mut((0..2, 0..3));
+        c &= true;
+        let d = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxd_dxd() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b =
+            MatrixDxD::try_from(vec![vec![false, true, false], vec![true, false, true]]).unwrap();
+        let mut c = a.slice_dxd_mut((0..2, 0..3));
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxd_dxs() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b = MatrixDxS::from(vec![[false, true, false], [true, false, true]]);
+        let mut c = a.slice_dxd_mut((0..2, 0..3));
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxd_sxd() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b = MatrixSxD::try_from([vec![false, true, false], vec![true, false, true]]).unwrap();
+        let mut c = a.slice_dxd_mut((0..2, 0..3));
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxd_sxs() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b = MatrixSxS::from([[false, true, false], [true, false, true]]);
+        let mut c = a.slice_dxd_mut((0..2, 0..3));
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    // ViewDxSMut
+    // --------------------------------------------------
+    #[test]
+    fn t_view_dxs() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let mut c = a.slice_dxs_mut::<{ 0..3 }>(0..2);
+        c &= true;
+        let d = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxs_dxd() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b =
+            MatrixDxD::try_from(vec![vec![false, true, false], vec![true, false, true]]).unwrap();
+        let mut c = a.slice_dxs_mut::<{ 0..3 }>(0..2);
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxs_dxs() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b = MatrixDxS::from(vec![[false, true, false], [true, false, true]]);
+        let mut c = a.slice_dxs_mut::<{ 0..3 }>(0..2);
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxs_sxd() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b = MatrixSxD::try_from([vec![false, true, false], vec![true, false, true]]).unwrap();
+        let mut c = a.slice_dxs_mut::<{ 0..3 }>(0..2);
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxs_sxs() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b = MatrixSxS::from([[false, true, false], [true, false, true]]);
+        let mut c = a.slice_dxs_mut::<{ 0..3 }>(0..2);
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    // ViewSxDMut
+    // --------------------------------------------------
+    #[test]
+    fn t_view_sxd() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let mut c = a.slice_sxd_mut::<{ 0..2 }>(0..3);
+        c &= true;
+        let d = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxd_dxd() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b =
+            MatrixDxD::try_from(vec![vec![false, true, false], vec![true, false, true]]).unwrap();
+        let mut c = a.slice_sxd_mut::<{ 0..2 }>(0..3);
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxd_dxs() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b = MatrixDxS::from(vec![[false, true, false], [true, false, true]]);
+        let mut c = a.slice_sxd_mut::<{ 0..2 }>(0..3);
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxd_sxd() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b = MatrixSxD::try_from([vec![false, true, false], vec![true, false, true]]).unwrap();
+        let mut c = a.slice_sxd_mut::<{ 0..2 }>(0..3);
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxd_sxs() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b = MatrixSxS::from([[false, true, false], [true, false, true]]);
+        let mut c = a.slice_sxd_mut::<{ 0..2 }>(0..3);
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    // ViewSxSMut
+    // --------------------------------------------------
+    #[test]
+    fn t_view_sxs() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let mut c = a.slice_sxs_mut::<{ 0..2 }, { 0..3 }>();
+        c &= true;
+        let d = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxs_dxd() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b =
+            MatrixDxD::try_from(vec![vec![false, true, false], vec![true, false, true]]).unwrap();
+        let mut c = a.slice_sxs_mut::<{ 0..2 }, { 0..3 }>();
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxs_dxs() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b = MatrixDxS::from(vec![[false, true, false], [true, false, true]]);
+        let mut c = a.slice_sxs_mut::<{ 0..2 }, { 0..3 }>();
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxs_sxd() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b = MatrixSxD::try_from([vec![false, true, false], vec![true, false, true]]).unwrap();
+        let mut c = a.slice_sxs_mut::<{ 0..2 }, { 0..3 }>();
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxs_sxs() {
+        let mut a = MatrixSxS::from([
+            [true, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        let b = MatrixSxS::from([[false, true, false], [true, false, true]]);
+        let mut c = a.slice_sxs_mut::<{ 0..2 }, { 0..3 }>();
+        c &= b;
+        let d = MatrixSxS::from([
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+        ]);
+        assert_eq!(a, d);
+    }
+    // ColumnMajor
+    // --------------------------------------------------
+    #[test]
+    fn column_major() {
+        let mut a = MatrixSxS::from([[true, true, false], [false, true, false]]);
+        a &= MatrixDxS::from(vec![[true, true], [false, false], [true, false]]).transpose();
+        assert_eq!(
+            a,
+            MatrixSxS::from([[true, false, false], [false, false, false]])
+        );
+        let mut b = MatrixSxD::try_from([vec![true, true, false], vec![false, true, false]])
+            .unwrap()
+            .to_layout::<ColumnMajor>();
+        b &= true;
+        b &= a;
+        assert_eq!(
+            b,
+            MatrixSxS::from([[true, false, false], [false, false, false]])
+        );
+    }
 }
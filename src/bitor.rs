@@ -1,509 +1,146 @@
+use crate::iter::zip_rows;
 use crate::*;
 use std::ops::{BitOr, BitOrAssign};
 
 // T
 // --------------------------------------------------
-impl<T: BitOrAssign + Copy> BitOr<T> for MatrixDxD<T> {
-    type Output = Self;
-    fn bitor(mut self, x: T) -> Self::Output {
-        for a in self.data.iter_mut() {
-            *a |= x;
-        }
-        self
-    }
-}
-impl<T: BitOrAssign + Copy, const COLUMNS: usize> BitOr<T> for MatrixDxS<T, COLUMNS> {
-    type Output = Self;
-    fn bitor(mut self, x: T) -> Self::Output {
-        for a in self.data.iter_mut() {
-            *a |= x;
-        }
-        self
-    }
-}
-impl<T: BitOrAssign + Copy, const ROWS: usize> BitOr<T> for MatrixSxD<T, ROWS> {
-    type Output = Self;
-    fn bitor(mut self, x: T) -> Self::Output {
-        for a in self.data.iter_mut() {
-            *a |= x;
-        }
-        self
-    }
-}
-impl<T: BitOrAssign + Copy, const ROWS: usize, const COLUMNS: usize> BitOr<T>
-    for MatrixSxS<T, ROWS, COLUMNS>
+impl<T: BitOrAssign + Copy, R: Dim, C: Dim, L: Layout> BitOr<T> for Matrix<T, R, C, L>
 where
-    [(); ROWS * COLUMNS]:,
+    (R, C, L): Storage<T>,
 {
     type Output = Self;
     fn bitor(mut self, x: T) -> Self::Output {
-        for a in self.data.iter_mut() {
+        for a in self.data.as_mut().iter_mut() {
             *a |= x;
         }
         self
     }
 }
-// MatrixDxD
-// --------------------------------------------------
-impl<T: BitOrAssign + Copy> BitOr<MatrixDxD<T>> for MatrixDxD<T> {
-    type Output = Self;
-    fn bitor(mut self, other: Self) -> Self {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a |= *b;
-        }
-        self
-    }
-}
-impl<T: BitOrAssign + Copy, const COLUMNS: usize> BitOr<MatrixDxS<T, COLUMNS>> for MatrixDxD<T> {
-    type Output = MatrixDxS<T, COLUMNS>;
-    fn bitor(self, mut other: MatrixDxS<T, COLUMNS>) -> Self::Output {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-        for (a, b) in self.data.iter().zip(other.data.iter_mut()) {
-            *b |= *a;
-        }
-        other
-    }
-}
-impl<T: BitOrAssign + Copy, const ROWS: usize> BitOr<MatrixSxD<T, ROWS>> for MatrixDxD<T> {
-    type Output = MatrixSxD<T, ROWS>;
-    fn bitor(self, mut other: MatrixSxD<T, ROWS>) -> Self::Output {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter().zip(other.data.iter_mut()) {
-            *b |= *a;
-        }
-        other
-    }
-}
-impl<T: BitOrAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitOr<MatrixSxS<T, ROWS, COLUMNS>> for MatrixDxD<T>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn bitor(self, mut other: MatrixSxS<T, ROWS, COLUMNS>) -> Self::Output {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-        for (a, b) in self.data.iter().zip(other.data.iter_mut()) {
-            *b |= *a;
-        }
-        other
-    }
-}
-// MatrixDxS
-// --------------------------------------------------
-impl<T: BitOrAssign + Copy, const COLUMNS: usize> BitOr<MatrixDxS<T, COLUMNS>>
-    for MatrixDxS<T, COLUMNS>
-{
-    type Output = Self;
-    fn bitor(mut self, other: Self) -> Self {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a |= *b;
-        }
-        self
-    }
-}
-impl<T: BitOr<Output = T> + Copy + Default, const ROWS: usize, const COLUMNS: usize>
-    BitOr<MatrixSxD<T, ROWS>> for MatrixDxS<T, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn bitor(self, other: MatrixSxD<T, ROWS>) -> Self::Output {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-        let mut data = [Default::default(); ROWS * COLUMNS];
-        for i in 0..ROWS * COLUMNS {
-            data[i] = self.data[i] | other.data[i];
-        }
-        Self::Output { data }
-    }
-}
-impl<T: BitOrAssign + Copy, const COLUMNS: usize> BitOr<MatrixDxD<T>> for MatrixDxS<T, COLUMNS> {
-    type Output = Self;
-    fn bitor(mut self, other: MatrixDxD<T>) -> Self::Output {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a |= *b;
-        }
-        self
-    }
-}
-impl<T: BitOrAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitOr<MatrixSxS<T, ROWS, COLUMNS>> for MatrixDxS<T, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn bitor(self, mut other: MatrixSxS<T, ROWS, COLUMNS>) -> Self::Output {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        for (a, b) in self.data.iter().zip(other.data.iter_mut()) {
-            *b |= *a;
-        }
-        other
-    }
-}
-// MatrixSxD
-// --------------------------------------------------
-impl<T: BitOrAssign + Copy, const ROWS: usize> BitOr<MatrixSxD<T, ROWS>> for MatrixSxD<T, ROWS> {
-    type Output = Self;
-    fn bitor(mut self, other: Self) -> Self {
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a |= *b;
-        }
-        self
-    }
-}
-impl<T: BitOr<Output = T> + Copy + Default, const ROWS: usize, const COLUMNS: usize>
-    BitOr<MatrixDxS<T, COLUMNS>> for MatrixSxD<T, ROWS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn bitor(self, other: MatrixDxS<T, COLUMNS>) -> Self::Output {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-        let mut data = [Default::default(); ROWS * COLUMNS];
-        for i in 0..ROWS * COLUMNS {
-            data[i] = self.data[i] | other.data[i];
-        }
-        Self::Output { data }
-    }
-}
-impl<T: BitOrAssign + Copy, const ROWS: usize> BitOr<MatrixDxD<T>> for MatrixSxD<T, ROWS> {
-    type Output = Self;
-    fn bitor(mut self, other: MatrixDxD<T>) -> Self::Output {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a |= *b;
-        }
-        self
-    }
-}
-impl<T: BitOrAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitOr<MatrixSxS<T, ROWS, COLUMNS>> for MatrixSxD<T, ROWS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn bitor(self, mut other: MatrixSxS<T, ROWS, COLUMNS>) -> Self::Output {
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-        for (a, b) in self.data.iter().zip(other.data.iter_mut()) {
-            *b |= *a;
-        }
-        other
-    }
-}
-// MatrixSxS
+// Matrix
 // --------------------------------------------------
-impl<T: BitOrAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitOr<MatrixSxS<T, ROWS, COLUMNS>> for MatrixSxS<T, ROWS, COLUMNS>
+impl<T: BitOr<Output = T> + Copy, R: Dim, C: Dim, L: Layout, R2: Dim, C2: Dim, L2: Layout>
+    BitOr<Matrix<T, R2, C2, L2>> for Matrix<T, R, C, L>
 where
-    [(); ROWS * COLUMNS]:,
+    R: Join<R2>,
+    C: Join<C2>,
+    (R, C, L): Storage<T>,
+    (R2, C2, L2): Storage<T>,
+    (R::Output, C::Output, L): Storage<T>,
 {
-    type Output = Self;
-    fn bitor(mut self, other: Self) -> Self {
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a |= *b;
-        }
-        self
-    }
-}
-impl<T: BitOrAssign + Copy, const ROWS: usize, const COLUMNS: usize> BitOr<MatrixDxS<T, COLUMNS>>
-    for MatrixSxS<T, ROWS, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = Self;
-    fn bitor(mut self, other: MatrixDxS<T, COLUMNS>) -> Self::Output {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a |= *b;
-        }
-        self
-    }
-}
-impl<T: BitOrAssign + Copy, const ROWS: usize, const COLUMNS: usize> BitOr<MatrixSxD<T, ROWS>>
-    for MatrixSxS<T, ROWS, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = Self;
-    fn bitor(mut self, other: MatrixSxD<T, ROWS>) -> Self::Output {
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a |= *b;
-        }
-        self
-    }
-}
-impl<T: BitOrAssign + Copy, const ROWS: usize, const COLUMNS: usize> BitOr<MatrixDxD<T>>
-    for MatrixSxS<T, ROWS, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = Self;
-    fn bitor(mut self, other: MatrixDxD<T>) -> Self::Output {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a |= *b;
-        }
-        self
+    type Output = Matrix<T, R::Output, C::Output, L>;
+    fn bitor(self, other: Matrix<T, R2, C2, L2>) -> Self::Output {
+        self.zip_map(&other, |a, b| *a | *b)
     }
 }
 // TransposeDxD
 // --------------------------------------------------
-impl<'a, T: BitOrAssign + Copy> BitOr<MatrixDxD<T>> for TransposeDxD<'a, T> {
-    type Output = MatrixDxD<T>;
-    fn bitor(self, mut other: MatrixDxD<T>) -> Self::Output {
-        assert_eq!(self.rows(), other.rows, "Non-matching rows");
-        assert_eq!(self.columns(), other.columns, "Non-matching columns");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b |= *a;
-        }
-        other
-    }
-}
-impl<'a, T: BitOrAssign + Copy, const COLUMNS: usize> BitOr<MatrixDxS<T, COLUMNS>>
+impl<'a, T: BitOr<Output = T> + Copy, R2: Dim, C2: Dim, L2: Layout> BitOr<Matrix<T, R2, C2, L2>>
     for TransposeDxD<'a, T>
-{
-    type Output = MatrixDxS<T, COLUMNS>;
-    fn bitor(self, mut other: MatrixDxS<T, COLUMNS>) -> Self::Output {
-        assert_eq!(self.rows(), other.rows, "Non-matching rows");
-        assert_eq!(self.columns(), COLUMNS, "Non-matching columns");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b |= *a;
-        }
-        other
-    }
-}
-impl<'a, T: BitOrAssign + Copy, const ROWS: usize> BitOr<MatrixSxD<T, ROWS>>
-    for TransposeDxD<'a, T>
-{
-    type Output = MatrixSxD<T, ROWS>;
-    fn bitor(self, mut other: MatrixSxD<T, ROWS>) -> Self::Output {
-        assert_eq!(self.rows(), ROWS, "Non-matching rows");
-        assert_eq!(self.columns(), other.columns, "Non-matching columns");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b |= *a;
-        }
-        other
-    }
-}
-impl<'a, T: BitOrAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitOr<MatrixSxS<T, ROWS, COLUMNS>> for TransposeDxD<'a, T>
 where
-    [(); ROWS * COLUMNS]:,
+    Dynamic: Join<R2>,
+    Dynamic: Join<C2>,
+    (R2, C2, L2): Storage<T>,
+    (
+        <Dynamic as Join<R2>>::Output,
+        <Dynamic as Join<C2>>::Output,
+        RowMajor,
+    ): Storage<T>,
 {
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn bitor(self, mut other: MatrixSxS<T, ROWS, COLUMNS>) -> Self::Output {
-        assert_eq!(self.rows(), ROWS, "Non-matching rows");
-        assert_eq!(self.columns(), COLUMNS, "Non-matching columns");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b |= *a;
-        }
-        other
+    type Output = Matrix<T, <Dynamic as Join<R2>>::Output, <Dynamic as Join<C2>>::Output>;
+    fn bitor(self, other: Matrix<T, R2, C2, L2>) -> Self::Output {
+        zip_rows(
+            self.iter(),
+            (Dynamic, self.rows()),
+            (Dynamic, self.columns()),
+            &other,
+            |a, b| *a | *b,
+        )
     }
 }
 // TransposeDxS
 // --------------------------------------------------
-impl<'a, T: BitOr<Output = T> + Default + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitOr<MatrixDxS<T, COLUMNS>> for TransposeDxS<'a, T, ROWS>
+impl<'a, T: BitOr<Output = T> + Copy, const ROWS: usize, R2: Dim, C2: Dim, L2: Layout>
+    BitOr<Matrix<T, R2, C2, L2>> for TransposeDxS<'a, T, ROWS>
 where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn bitor(self, other: MatrixDxS<T, COLUMNS>) -> MatrixSxS<T, ROWS, COLUMNS> {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(self.columns(), COLUMNS, "Non-matching columns");
-
-        let mut data = [Default::default(); ROWS * COLUMNS];
-        for (c, (a, b)) in data.iter_mut().zip(self.iter().zip(other.data.iter())) {
-            *c = *a | *b;
-        }
-        Self::Output { data }
-    }
-}
-impl<'a, T: BitOrAssign + Copy, const ROWS: usize> BitOr<MatrixSxD<T, ROWS>>
-    for TransposeDxS<'a, T, ROWS>
+    Static<ROWS>: Join<R2>,
+    Dynamic: Join<C2>,
+    (R2, C2, L2): Storage<T>,
+    (
+        <Static<ROWS> as Join<R2>>::Output,
+        <Dynamic as Join<C2>>::Output,
+        RowMajor,
+    ): Storage<T>,
 {
-    type Output = MatrixSxD<T, ROWS>;
-    fn bitor(self, mut other: MatrixSxD<T, ROWS>) -> Self::Output {
-        assert_eq!(self.columns(), other.columns, "Non-matching columns");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b |= *a;
-        }
-        other
-    }
-}
-impl<'a, T: BitOr<Output = T> + Copy, const ROWS: usize> BitOr<MatrixDxD<T>>
-    for TransposeDxS<'a, T, ROWS>
-{
-    type Output = MatrixSxD<T, ROWS>;
-    fn bitor(self, other: MatrixDxD<T>) -> Self::Output {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(self.columns(), other.columns, "Non-matching columns");
-        Self::Output {
-            data: self
-                .iter()
-                .zip(other.data.iter())
-                .map(|(a, b)| *a | *b)
-                .collect(),
-            columns: self.columns(),
-        }
-    }
-}
-impl<'a, T: BitOrAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitOr<MatrixSxS<T, ROWS, COLUMNS>> for TransposeDxS<'a, T, ROWS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn bitor(self, mut other: MatrixSxS<T, ROWS, COLUMNS>) -> Self::Output {
-        assert_eq!(self.columns(), COLUMNS, "Non-matching columns");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b |= *a;
-        }
-        other
+    type Output = Matrix<T, <Static<ROWS> as Join<R2>>::Output, <Dynamic as Join<C2>>::Output>;
+    fn bitor(self, other: Matrix<T, R2, C2, L2>) -> Self::Output {
+        zip_rows(
+            self.iter(),
+            (Static::<ROWS>, ROWS),
+            (Dynamic, self.columns()),
+            &other,
+            |a, b| *a | *b,
+        )
     }
 }
 // TransposeSxD
 // --------------------------------------------------
-impl<'a, T: BitOr<Output = T> + Copy + Default, const ROWS: usize, const COLUMNS: usize>
-    BitOr<MatrixSxD<T, ROWS>> for TransposeSxD<'a, T, COLUMNS>
+impl<'a, T: BitOr<Output = T> + Copy, const COLUMNS: usize, R2: Dim, C2: Dim, L2: Layout>
+    BitOr<Matrix<T, R2, C2, L2>> for TransposeSxD<'a, T, COLUMNS>
 where
-    [(); ROWS * COLUMNS]:,
+    Dynamic: Join<R2>,
+    Static<COLUMNS>: Join<C2>,
+    (R2, C2, L2): Storage<T>,
+    (
+        <Dynamic as Join<R2>>::Output,
+        <Static<COLUMNS> as Join<C2>>::Output,
+        RowMajor,
+    ): Storage<T>,
 {
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn bitor(self, other: MatrixSxD<T, ROWS>) -> Self::Output {
-        assert_eq!(self.rows(), ROWS, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        let mut data = [Default::default(); ROWS * COLUMNS];
-        for (c, (a, b)) in data.iter_mut().zip(self.iter().zip(other.data.iter())) {
-            *c = *a | *b;
-        }
-        Self::Output { data }
-    }
-}
-impl<'a, T: BitOrAssign + Copy, const COLUMNS: usize> BitOr<MatrixDxS<T, COLUMNS>>
-    for TransposeSxD<'a, T, COLUMNS>
-{
-    type Output = MatrixDxS<T, COLUMNS>;
-    fn bitor(self, mut other: MatrixDxS<T, COLUMNS>) -> Self::Output {
-        assert_eq!(self.rows(), other.rows, "Non-matching rows");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b |= *a;
-        }
-        other
-    }
-}
-impl<'a, T: BitOr<Output = T> + Copy, const COLUMNS: usize> BitOr<MatrixDxD<T>>
-    for TransposeSxD<'a, T, COLUMNS>
-{
-    type Output = MatrixDxS<T, COLUMNS>;
-    fn bitor(self, other: MatrixDxD<T>) -> Self::Output {
-        assert_eq!(self.rows(), other.rows, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        Self::Output {
-            data: self
-                .iter()
-                .zip(other.data.iter())
-                .map(|(a, b)| *a | *b)
-                .collect(),
-            rows: self.rows(),
-        }
-    }
-}
-impl<'a, T: BitOrAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitOr<MatrixSxS<T, ROWS, COLUMNS>> for TransposeSxD<'a, T, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn bitor(self, mut other: MatrixSxS<T, ROWS, COLUMNS>) -> Self::Output {
-        assert_eq!(self.rows(), ROWS, "Non-matching columns");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b |= *a;
-        }
-        other
+    type Output = Matrix<T, <Dynamic as Join<R2>>::Output, <Static<COLUMNS> as Join<C2>>::Output>;
+    fn bitor(self, other: Matrix<T, R2, C2, L2>) -> Self::Output {
+        zip_rows(
+            self.iter(),
+            (Dynamic, self.rows()),
+            (Static::<COLUMNS>, COLUMNS),
+            &other,
+            |a, b| *a | *b,
+        )
     }
 }
 // TransposeSxS
 // --------------------------------------------------
-impl<'a, T: BitOrAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    BitOr<MatrixSxS<T, ROWS, COLUMNS>> for TransposeSxS<'a, T, COLUMNS, ROWS>
+impl<
+        'a,
+        T: BitOr<Output = T> + Copy,
+        const ROWS: usize,
+        const COLUMNS: usize,
+        R2: Dim,
+        C2: Dim,
+        L2: Layout,
+    > BitOr<Matrix<T, R2, C2, L2>> for TransposeSxS<'a, T, COLUMNS, ROWS>
 where
-    [(); ROWS * COLUMNS]:,
+    Static<ROWS>: Join<R2>,
+    Static<COLUMNS>: Join<C2>,
+    (R2, C2, L2): Storage<T>,
+    (
+        <Static<ROWS> as Join<R2>>::Output,
+        <Static<COLUMNS> as Join<C2>>::Output,
+        RowMajor,
+    ): Storage<T>,
     [(); COLUMNS * ROWS]:,
 {
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn bitor(self, mut other: MatrixSxS<T, ROWS, COLUMNS>) -> Self::Output {
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b |= *a;
-        }
-        other
+    type Output =
+        Matrix<T, <Static<ROWS> as Join<R2>>::Output, <Static<COLUMNS> as Join<C2>>::Output>;
+    fn bitor(self, other: Matrix<T, R2, C2, L2>) -> Self::Output {
+        zip_rows(
+            self.iter(),
+            (Static::<ROWS>, ROWS),
+            (Static::<COLUMNS>, COLUMNS),
+            &other,
+            |a, b| *a | *b,
+        )
     }
 }
-impl<'a, T: BitOr<Output = T> + Copy + Default, const ROWS: usize, const COLUMNS: usize>
-    BitOr<MatrixDxS<T, COLUMNS>> for TransposeSxS<'a, T, COLUMNS, ROWS>
-where
-    [(); COLUMNS * ROWS]:,
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn bitor(self, other: MatrixDxS<T, COLUMNS>) -> Self::Output {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        let mut data = [Default::default(); ROWS * COLUMNS];
-        for (c, (a, b)) in data.iter_mut().zip(self.iter().zip(other.data.iter())) {
-            *c = *a | *b;
-        }
-        Self::Output { data }
-    }
-}
-impl<'a, T: BitOr<Output = T> + Copy + Default, const ROWS: usize, const COLUMNS: usize>
-    BitOr<MatrixSxD<T, ROWS>> for TransposeSxS<'a, T, COLUMNS, ROWS>
-where
-    [(); COLUMNS * ROWS]:,
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn bitor(self, other: MatrixSxD<T, ROWS>) -> Self::Output {
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-        let mut data = [Default::default(); ROWS * COLUMNS];
-        for (c, (a, b)) in data.iter_mut().zip(self.iter().zip(other.data.iter())) {
-            *c = *a | *b;
-        }
-        Self::Output { data }
-    }
-}
-impl<'a, T: BitOr<Output = T> + Copy + Default, const ROWS: usize, const COLUMNS: usize>
-    BitOr<MatrixDxD<T>> for TransposeSxS<'a, T, COLUMNS, ROWS>
-where
-    [(); COLUMNS * ROWS]:,
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn bitor(self, other: MatrixDxD<T>) -> Self::Output {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-        let mut data = [Default::default(); ROWS * COLUMNS];
-        for (c, (a, b)) in data.iter_mut().zip(self.iter().zip(other.data.iter())) {
-            *c = *a | *b;
-        }
-        Self::Output { data }
-    }
-}
-
 // Tests
 // --------------------------------------------------
 #[cfg(test)]
@@ -855,4 +492,32 @@ mod tests {
         let d = MatrixSxS::from([[true, true, false], [true, false, true]]);
         assert_eq!(c, d);
     }
+    // ColumnMajor
+    // --------------------------------------------------
+    #[test]
+    fn column_major() {
+        let a = MatrixSxS::from([[true, true, false], [false, true, false]]);
+        let b = MatrixDxD::try_from(vec![
+            vec![true, true],
+            vec![false, false],
+            vec![true, false],
+        ])
+        .unwrap();
+        let c = a.clone() | b.transpose();
+        assert_eq!(
+            c,
+            MatrixSxS::from([[true, true, true], [true, true, false]])
+        );
+        let d = MatrixDxS::from(vec![[true, true], [false, false], [true, false]]).transpose()
+            | a.clone();
+        assert_eq!(
+            d,
+            MatrixSxS::from([[true, true, true], [true, true, false]])
+        );
+        let e = a.transpose() | false;
+        assert_eq!(
+            e,
+            MatrixSxS::from([[true, true, false], [false, true, false]]).transpose()
+        );
+    }
 }
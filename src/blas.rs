@@ -1,131 +1,890 @@
+#[cfg(not(feature = "cblas"))]
+use crate::native as cblas;
 use crate::*;
+use num_complex::Complex;
 fn t(x: bool) -> cblas::Transpose {
     match x {
         true => cblas::Transpose::Ordinary,
         false => cblas::Transpose::None,
     }
 }
+fn layout<T, X: Matrix<T>>(x: &X) -> cblas::Layout {
+    match x.column_major() {
+        true => cblas::Layout::ColumnMajor,
+        false => cblas::Layout::RowMajor,
+    }
+}
+/// Asserts the rows (or columns if column-major) of `x` are contiguous, such that its data can be
+///  treated as a vector.
+fn assert_contiguous<T, X: Matrix<T>>(x: &X) {
+    let len = match x.column_major() {
+        true => x.rows(),
+        false => x.columns(),
+    };
+    assert_eq!(x.leading_dimension(), len, "Non-contiguous matrix");
+}
+/// Asserts `x` fits within its data, as BLAS reads and writes through it unchecked.
+fn assert_matrix<T, X: Matrix<T>>(x: &X) {
+    let (rows, columns) = match x.column_major() {
+        true => (x.columns(), x.rows()),
+        false => (x.rows(), x.columns()),
+    };
+    crate::matmul::assert_fits(x.data().len(), rows, columns, x.leading_dimension());
+}
+/// Asserts the data of `x` and `y` hold their elements in the same order, which is always the
+///  case for vectors.
+fn assert_same_order<T, X: Matrix<T>, Y: Matrix<T>>(x: &X, y: &Y) {
+    let vector = |rows: usize, columns: usize| rows <= 1 || columns <= 1;
+    assert!(
+        vector(x.rows(), x.columns())
+            || vector(y.rows(), y.columns())
+            || x.column_major() == y.column_major(),
+        "Non-matching layouts"
+    );
+}
+fn part(lower: bool) -> cblas::Part {
+    match lower {
+        true => cblas::Part::Lower,
+        false => cblas::Part::Upper,
+    }
+}
+fn side(left: bool) -> cblas::Side {
+    match left {
+        true => cblas::Side::Left,
+        false => cblas::Side::Right,
+    }
+}
+fn diagonal(unit: bool) -> cblas::Diagonal {
+    match unit {
+        true => cblas::Diagonal::Unit,
+        false => cblas::Diagonal::Generic,
+    }
+}
+/// Length of the vector `x` and the distance between its consecutive elements in
+///  [`Matrix::data`].
+fn vector<T, X: Matrix<T>>(x: &X) -> (i32, i32) {
+    let (len, contiguous) = match (x.rows(), x.columns()) {
+        (rows, 1) => (rows, x.column_major()),
+        (1, columns) => (columns, !x.column_major()),
+        _ => panic!("Non-vector matrix"),
+    };
+    let inc = match contiguous {
+        true => 1,
+        false => x.leading_dimension(),
+    };
+    assert!(
+        len == 0 || x.data().len() > (len - 1) * inc,
+        "Vector exceeds data"
+    );
+    (len as i32, inc as i32)
+}
+/// Rows and columns of `x`, or of its transpose when `transpose`.
+fn op_shape<T, X: Matrix<T>>(x: &X, transpose: bool) -> (usize, usize) {
+    match transpose {
+        true => (x.columns(), x.rows()),
+        false => (x.rows(), x.columns()),
+    }
+}
+/// Number of elements of `x` and the distance between them in [`Matrix::data`], where `x` is a
+///  vector or a matrix with contiguous data.
+fn elements<T, X: Matrix<T>>(x: &X) -> (i32, i32) {
+    match x.rows() == 1 || x.columns() == 1 {
+        true => vector(x),
+        false => {
+            assert_contiguous(x);
+            assert_matrix(x);
+            ((x.rows() * x.columns()) as i32, 1)
+        }
+    }
+}
 /// A generic matrix.
 pub trait Matrix<T> {
     fn rows(&self) -> usize;
     fn columns(&self) -> usize;
+    /// Underlying data, starting from the first element.
     fn data(&self) -> &[T];
+    /// Distance between the starts of consecutive rows in [`Matrix::data`], or consecutive
+    ///  columns if [`Matrix::column_major`].
+    fn leading_dimension(&self) -> usize {
+        self.columns()
+    }
+    /// Whether [`Matrix::data`] is column-major rather than row-major.
+    fn column_major(&self) -> bool {
+        false
+    }
+}
+/// A generic matrix which can be written to.
+pub trait MatrixMut<T>: Matrix<T> {
     fn data_mut(&mut self) -> &mut [T];
 }
-impl<T> Matrix<T> for MatrixDxD<T> {
+impl<T, R: Dim, C: Dim, L: Layout> Matrix<T> for crate::Matrix<T, R, C, L>
+where
+    (R, C, L): Storage<T>,
+{
     fn rows(&self) -> usize {
-        self.rows
+        self.shape().0
     }
     fn columns(&self) -> usize {
-        self.columns
+        self.shape().1
     }
     fn data(&self) -> &[T] {
-        &self.data
+        self.as_slice()
+    }
+    fn leading_dimension(&self) -> usize {
+        match L::COLUMN_MAJOR {
+            true => self.shape().0,
+            false => self.shape().1,
+        }
     }
+    fn column_major(&self) -> bool {
+        L::COLUMN_MAJOR
+    }
+}
+impl<T, R: Dim, C: Dim, L: Layout> MatrixMut<T> for crate::Matrix<T, R, C, L>
+where
+    (R, C, L): Storage<T>,
+{
     fn data_mut(&mut self) -> &mut [T] {
-        &mut self.data
+        self.data.as_mut()
+    }
+}
+impl<'a, T> Matrix<T> for ViewDxD<'a, T> {
+    fn rows(&self) -> usize {
+        self.rows()
+    }
+    fn columns(&self) -> usize {
+        self.columns()
+    }
+    fn data(&self) -> &[T] {
+        assert_eq!(self.column_stride, 1, "Non-contiguous rows");
+        self.data
+    }
+    fn leading_dimension(&self) -> usize {
+        self.row_stride
+    }
+}
+impl<'a, T, const ROWS: usize> Matrix<T> for ViewSxD<'a, T, ROWS> {
+    fn rows(&self) -> usize {
+        self.rows()
+    }
+    fn columns(&self) -> usize {
+        self.columns()
+    }
+    fn data(&self) -> &[T] {
+        assert_eq!(self.column_stride, 1, "Non-contiguous rows");
+        self.data
+    }
+    fn leading_dimension(&self) -> usize {
+        self.row_stride
+    }
+}
+impl<'a, T, const COLUMNS: usize> Matrix<T> for ViewDxS<'a, T, COLUMNS> {
+    fn rows(&self) -> usize {
+        self.rows()
+    }
+    fn columns(&self) -> usize {
+        self.columns()
+    }
+    fn data(&self) -> &[T] {
+        assert_eq!(self.column_stride, 1, "Non-contiguous rows");
+        self.data
+    }
+    fn leading_dimension(&self) -> usize {
+        self.row_stride
+    }
+}
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> Matrix<T> for ViewSxS<'a, T, ROWS, COLUMNS> {
+    fn rows(&self) -> usize {
+        self.rows()
+    }
+    fn columns(&self) -> usize {
+        self.columns()
+    }
+    fn data(&self) -> &[T] {
+        assert_eq!(self.column_stride, 1, "Non-contiguous rows");
+        self.data
+    }
+    fn leading_dimension(&self) -> usize {
+        self.row_stride
     }
 }
-impl<T, const ROWS: usize> Matrix<T> for MatrixSxD<T, ROWS> {
+impl<'a, T> Matrix<T> for ViewDxDMut<'a, T> {
     fn rows(&self) -> usize {
-        ROWS
+        self.rows()
     }
     fn columns(&self) -> usize {
-        self.columns
+        self.columns()
     }
     fn data(&self) -> &[T] {
-        &self.data
+        assert_eq!(self.column_stride, 1, "Non-contiguous rows");
+        self.data
     }
+    fn leading_dimension(&self) -> usize {
+        self.row_stride
+    }
+}
+impl<'a, T> MatrixMut<T> for ViewDxDMut<'a, T> {
     fn data_mut(&mut self) -> &mut [T] {
-        &mut self.data
+        assert_eq!(self.column_stride, 1, "Non-contiguous rows");
+        self.data
     }
 }
-impl<T, const COLUMNS: usize> Matrix<T> for MatrixDxS<T, COLUMNS> {
+impl<'a, T, const ROWS: usize> Matrix<T> for ViewSxDMut<'a, T, ROWS> {
     fn rows(&self) -> usize {
-        self.rows
+        self.rows()
     }
     fn columns(&self) -> usize {
-        COLUMNS
+        self.columns()
     }
     fn data(&self) -> &[T] {
-        &self.data
+        assert_eq!(self.column_stride, 1, "Non-contiguous rows");
+        self.data
     }
+    fn leading_dimension(&self) -> usize {
+        self.row_stride
+    }
+}
+impl<'a, T, const ROWS: usize> MatrixMut<T> for ViewSxDMut<'a, T, ROWS> {
     fn data_mut(&mut self) -> &mut [T] {
-        &mut self.data
+        assert_eq!(self.column_stride, 1, "Non-contiguous rows");
+        self.data
     }
 }
-impl<T, const ROWS: usize, const COLUMNS: usize> Matrix<T> for MatrixSxS<T, ROWS, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
+impl<'a, T, const COLUMNS: usize> Matrix<T> for ViewDxSMut<'a, T, COLUMNS> {
+    fn rows(&self) -> usize {
+        self.rows()
+    }
+    fn columns(&self) -> usize {
+        self.columns()
+    }
+    fn data(&self) -> &[T] {
+        assert_eq!(self.column_stride, 1, "Non-contiguous rows");
+        self.data
+    }
+    fn leading_dimension(&self) -> usize {
+        self.row_stride
+    }
+}
+impl<'a, T, const COLUMNS: usize> MatrixMut<T> for ViewDxSMut<'a, T, COLUMNS> {
+    fn data_mut(&mut self) -> &mut [T] {
+        assert_eq!(self.column_stride, 1, "Non-contiguous rows");
+        self.data
+    }
+}
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> Matrix<T>
+    for ViewSxSMut<'a, T, ROWS, COLUMNS>
+{
+    fn rows(&self) -> usize {
+        self.rows()
+    }
+    fn columns(&self) -> usize {
+        self.columns()
+    }
+    fn data(&self) -> &[T] {
+        assert_eq!(self.column_stride, 1, "Non-contiguous rows");
+        self.data
+    }
+    fn leading_dimension(&self) -> usize {
+        self.row_stride
+    }
+}
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> MatrixMut<T>
+    for ViewSxSMut<'a, T, ROWS, COLUMNS>
 {
+    fn data_mut(&mut self) -> &mut [T] {
+        assert_eq!(self.column_stride, 1, "Non-contiguous rows");
+        self.data
+    }
+}
+
+/// A single row or column of a [`Matrix`] without copying, see [`row`] and [`column`].
+///
+/// Elements are `inc` apart, so a row is given as a column-major `1` by `len` matrix and a column
+///  as a row-major `len` by `1` matrix, each with a leading dimension of `inc`.
+#[derive(Debug, Clone, Copy)]
+pub struct Vector<'a, T> {
+    data: &'a [T],
+    len: usize,
+    inc: usize,
+    row: bool,
+}
+/// A single mutable row or column of a [`MatrixMut`], see [`row_mut`] and [`column_mut`].
+#[derive(Debug)]
+pub struct VectorMut<'a, T> {
+    data: &'a mut [T],
+    len: usize,
+    inc: usize,
+    row: bool,
+}
+impl<'a, T> Matrix<T> for Vector<'a, T> {
+    fn rows(&self) -> usize {
+        if self.row {
+            1
+        } else {
+            self.len
+        }
+    }
+    fn columns(&self) -> usize {
+        if self.row {
+            self.len
+        } else {
+            1
+        }
+    }
+    fn data(&self) -> &[T] {
+        self.data
+    }
+    fn leading_dimension(&self) -> usize {
+        self.inc
+    }
+    fn column_major(&self) -> bool {
+        self.row
+    }
+}
+impl<'a, T> Matrix<T> for VectorMut<'a, T> {
     fn rows(&self) -> usize {
-        ROWS
+        if self.row {
+            1
+        } else {
+            self.len
+        }
     }
     fn columns(&self) -> usize {
-        COLUMNS
+        if self.row {
+            self.len
+        } else {
+            1
+        }
     }
     fn data(&self) -> &[T] {
-        &self.data
+        self.data
+    }
+    fn leading_dimension(&self) -> usize {
+        self.inc
     }
+    fn column_major(&self) -> bool {
+        self.row
+    }
+}
+impl<'a, T> MatrixMut<T> for VectorMut<'a, T> {
     fn data_mut(&mut self) -> &mut [T] {
-        &mut self.data
+        self.data
+    }
+}
+/// Offset in [`Matrix::data`] of the first element, the number of elements and the distance
+///  between them of row `i` (or column `i` if `!row`) of `x`.
+fn line<T, X: Matrix<T>>(x: &X, i: usize, row: bool) -> (usize, usize, usize) {
+    let ld = x.leading_dimension();
+    match row {
+        true => assert!(i < x.rows(), "Row out of bounds"),
+        false => assert!(i < x.columns(), "Columns out of bounds"),
+    }
+    let len = if row { x.columns() } else { x.rows() };
+    // Lines along the layout are contiguous, lines across it are `ld` apart.
+    match row != x.column_major() {
+        true => (i * ld, len, 1),
+        false => (i, len, ld),
+    }
+}
+/// Row `i` of `x` as a vector.
+/// ```
+/// use static_la::{blas, MatrixDxD};
+/// use std::convert::TryFrom;
+/// let a = MatrixDxD::try_from(vec![vec![1., 2.], vec![3., 4.]]).unwrap();
+/// assert_eq!(blas::ddot(&blas::row(&a, 0), &blas::row(&a, 1)), 11.);
+/// ```
+pub fn row<T, X: Matrix<T>>(x: &X, i: usize) -> Vector<T> {
+    let (offset, len, inc) = line(x, i, true);
+    Vector {
+        data: &x.data()[offset..],
+        len,
+        inc,
+        row: true,
+    }
+}
+/// Column `i` of `x` as a vector.
+/// ```
+/// use static_la::{blas, MatrixDxD};
+/// use std::convert::TryFrom;
+/// let a = MatrixDxD::try_from(vec![vec![1., 2.], vec![3., 4.]]).unwrap();
+/// assert_eq!(blas::ddot(&blas::column(&a, 0), &blas::column(&a, 1)), 14.);
+/// ```
+pub fn column<T, X: Matrix<T>>(x: &X, i: usize) -> Vector<T> {
+    let (offset, len, inc) = line(x, i, false);
+    Vector {
+        data: &x.data()[offset..],
+        len,
+        inc,
+        row: false,
+    }
+}
+/// Row `i` of `x` as a mutable vector.
+pub fn row_mut<T, X: MatrixMut<T>>(x: &mut X, i: usize) -> VectorMut<T> {
+    let (offset, len, inc) = line(x, i, true);
+    VectorMut {
+        data: &mut x.data_mut()[offset..],
+        len,
+        inc,
+        row: true,
+    }
+}
+/// Column `i` of `x` as a mutable vector.
+pub fn column_mut<T, X: MatrixMut<T>>(x: &mut X, i: usize) -> VectorMut<T> {
+    let (offset, len, inc) = line(x, i, false);
+    VectorMut {
+        data: &mut x.data_mut()[offset..],
+        len,
+        inc,
+        row: false,
     }
 }
 
 // Level 1
 // --------------------------------------------------
+// Operands are either vectors, such as a [`row`] or [`column`] of a matrix, or whole matrices
+//  treated as their contiguous data.
 /// [saxpy](http://www.netlib.org/lapack/explore-html/d8/daf/saxpy_8f.html) BLAS operation.
-pub fn saxpy<X: Matrix<f32>, Y: Matrix<f32>>(alpha: f32, x: &X, y: &mut Y) {
-    assert_eq!(x.data().len(), y.data().len(), "Non-matching lengths");
+pub fn saxpy<X: Matrix<f32>, Y: MatrixMut<f32>>(alpha: f32, x: &X, y: &mut Y) {
+    let ((n, incx), (len, incy)) = (elements(x), elements(y));
+    assert_eq!(n, len, "Non-matching lengths");
+    assert_same_order(x, y);
     unsafe {
-        cblas::saxpy(x.data().len() as i32, alpha, x.data(), 0, y.data_mut(), 0);
+        cblas::saxpy(n, alpha, x.data(), incx, y.data_mut(), incy);
     }
 }
-/// [daxpy](http://www.netlib.org/lapack/explore-html/d9/dcd/daxpy_8f.html) BLAS operation.
-pub fn daxpy<X: Matrix<f64>, Y: Matrix<f64>>(alpha: f64, x: &X, y: &mut Y) {
-    assert_eq!(x.data().len(), y.data().len(), "Non-matching lengths");
+/// [sdot](http://www.netlib.org/lapack/explore-html/d0/d16/sdot_8f.html) BLAS operation.
+pub fn sdot<X: Matrix<f32>, Y: Matrix<f32>>(x: &X, y: &Y) -> f32 {
+    let ((n, incx), (len, incy)) = (elements(x), elements(y));
+    assert_eq!(n, len, "Non-matching lengths");
+    assert_same_order(x, y);
+    unsafe { cblas::sdot(n, x.data(), incx, y.data(), incy) }
+}
+/// [snrm2](http://www.netlib.org/lapack/explore-html/df/d28/group__single__blas__level1_gad179c1611098b5881f147d39afb009b8.html) BLAS operation.
+pub fn snrm2<X: Matrix<f32>>(x: &X) -> f32 {
+    let (n, incx) = elements(x);
+    unsafe { cblas::snrm2(n, x.data(), incx) }
+}
+/// [sasum](http://www.netlib.org/lapack/explore-html/df/d1f/sasum_8f.html) BLAS operation.
+pub fn sasum<X: Matrix<f32>>(x: &X) -> f32 {
+    let (n, incx) = elements(x);
+    unsafe { cblas::sasum(n, x.data(), incx) }
+}
+/// [isamax](http://www.netlib.org/lapack/explore-html/d6/d44/isamax_8f.html) BLAS operation.
+pub fn isamax<X: Matrix<f32>>(x: &X) -> usize {
+    let (n, incx) = elements(x);
+    unsafe { cblas::isamax(n, x.data(), incx) as usize }
+}
+/// [sscal](http://www.netlib.org/blas/sscal.f) BLAS operation.
+///
+/// `x = alpha·x`
+pub fn sscal<X: MatrixMut<f32>>(alpha: f32, x: &mut X) {
+    let (n, incx) = elements(x);
     unsafe {
-        cblas::daxpy(x.data().len() as i32, alpha, x.data(), 0, y.data_mut(), 0);
+        cblas::sscal(n, alpha, x.data_mut(), incx);
     }
 }
-/// [sdot](http://www.netlib.org/lapack/explore-html/d0/d16/sdot_8f.html) BLAS operation.
-pub fn sdot<X: Matrix<f32>, Y: Matrix<f32>>(x: &X, y: &Y) -> f32 {
-    assert_eq!(x.data().len(), y.data().len(), "Non-matching lengths");
-    unsafe { cblas::sdot(x.data().len() as i32, x.data(), 0, y.data(), 0) }
+/// [sswap](http://www.netlib.org/blas/sswap.f) BLAS operation.
+///
+/// Exchanges the elements of `x` and `y`.
+pub fn sswap<X: MatrixMut<f32>, Y: MatrixMut<f32>>(x: &mut X, y: &mut Y) {
+    let ((n, incx), (len, incy)) = (elements(x), elements(y));
+    assert_eq!(n, len, "Non-matching lengths");
+    assert_same_order(x, y);
+    unsafe {
+        cblas::sswap(n, x.data_mut(), incx, y.data_mut(), incy);
+    }
+}
+/// [scopy](http://www.netlib.org/blas/scopy.f) BLAS operation.
+///
+/// `y = x`
+pub fn scopy<X: Matrix<f32>, Y: MatrixMut<f32>>(x: &X, y: &mut Y) {
+    let ((n, incx), (len, incy)) = (elements(x), elements(y));
+    assert_eq!(n, len, "Non-matching lengths");
+    assert_same_order(x, y);
+    unsafe {
+        cblas::scopy(n, x.data(), incx, y.data_mut(), incy);
+    }
+}
+/// [srot](http://www.netlib.org/blas/srot.f) BLAS operation.
+///
+/// Applies the plane rotation `[[c, s], [-s, c]]` to each pair of elements of `x` and `y`.
+pub fn srot<X: MatrixMut<f32>, Y: MatrixMut<f32>>(x: &mut X, y: &mut Y, c: f32, s: f32) {
+    let ((n, incx), (len, incy)) = (elements(x), elements(y));
+    assert_eq!(n, len, "Non-matching lengths");
+    assert_same_order(x, y);
+    unsafe {
+        cblas::srot(n, x.data_mut(), incx, y.data_mut(), incy, c, s);
+    }
+}
+/// [srotg](http://www.netlib.org/blas/srotg.f) BLAS operation.
+///
+/// Returns `(c, s)` of the plane rotation zeroing `b` in `[[c, s], [-s, c]]·[a, b]ᵀ`, overwriting
+///  `a` with `r` and `b` with `z` from which the rotation can be reconstructed.
+pub fn srotg(a: &mut f32, b: &mut f32) -> (f32, f32) {
+    let (mut c, mut s) = (0., 0.);
+    unsafe {
+        cblas::srotg(
+            std::slice::from_mut(a),
+            std::slice::from_mut(b),
+            std::slice::from_mut(&mut c),
+            std::slice::from_mut(&mut s),
+        );
+    }
+    (c, s)
+}
+/// [daxpy](http://www.netlib.org/lapack/explore-html/d9/dcd/daxpy_8f.html) BLAS operation.
+pub fn daxpy<X: Matrix<f64>, Y: MatrixMut<f64>>(alpha: f64, x: &X, y: &mut Y) {
+    let ((n, incx), (len, incy)) = (elements(x), elements(y));
+    assert_eq!(n, len, "Non-matching lengths");
+    assert_same_order(x, y);
+    unsafe {
+        cblas::daxpy(n, alpha, x.data(), incx, y.data_mut(), incy);
+    }
 }
 /// [ddot](http://www.netlib.org/lapack/explore-html/d5/df6/ddot_8f.html) BLAS operation.
 pub fn ddot<X: Matrix<f64>, Y: Matrix<f64>>(x: &X, y: &Y) -> f64 {
-    assert_eq!(x.data().len(), y.data().len(), "Non-matching lengths");
-    unsafe { cblas::ddot(x.data().len() as i32, x.data(), 0, y.data(), 0) }
-}
-/// [snrm2](http://www.netlib.org/lapack/explore-html/df/d28/group__single__blas__level1_gad179c1611098b5881f147d39afb009b8.html) BLAS operation.
-pub fn snrm2<X: Matrix<f32>>(x: &X) -> f32 {
-    unsafe { cblas::snrm2(x.data().len() as i32, x.data(), 0) }
+    let ((n, incx), (len, incy)) = (elements(x), elements(y));
+    assert_eq!(n, len, "Non-matching lengths");
+    assert_same_order(x, y);
+    unsafe { cblas::ddot(n, x.data(), incx, y.data(), incy) }
 }
 /// [dnrm2](http://www.netlib.org/lapack/explore-html/df/d28/group__single__blas__level1_gab5393665c8f0e7d5de9bd1dd2ff0d9d0.html) BLAS operation.
 pub fn dnrm2<X: Matrix<f64>>(x: &X) -> f64 {
-    unsafe { cblas::dnrm2(x.data().len() as i32, x.data(), 0) }
-}
-/// [sasum](http://www.netlib.org/lapack/explore-html/df/d1f/sasum_8f.html) BLAS operation.
-pub fn sasum<X: Matrix<f32>>(x: &X) -> f32 {
-    unsafe { cblas::sasum(x.data().len() as i32, x.data(), 0) }
+    let (n, incx) = elements(x);
+    unsafe { cblas::dnrm2(n, x.data(), incx) }
 }
 /// [dasum](http://www.netlib.org/lapack/explore-html/de/d05/dasum_8f.html) BLAS operation.
 pub fn dasum<X: Matrix<f64>>(x: &X) -> f64 {
-    unsafe { cblas::dasum(x.data().len() as i32, x.data(), 0) }
-}
-/// [isamax](http://www.netlib.org/lapack/explore-html/d6/d44/isamax_8f.html) BLAS operation.
-pub fn isamax<X: Matrix<f32>>(x: &X) -> usize {
-    unsafe { cblas::isamax(x.data().len() as i32, x.data(), 0) as usize }
+    let (n, incx) = elements(x);
+    unsafe { cblas::dasum(n, x.data(), incx) }
 }
 /// [idamax](http://www.netlib.org/lapack/explore-html/dd/de0/idamax_8f.html) BLAS operation.
 pub fn idamax<X: Matrix<f64>>(x: &X) -> usize {
-    unsafe { cblas::idamax(x.data().len() as i32, x.data(), 0) as usize }
+    let (n, incx) = elements(x);
+    unsafe { cblas::idamax(n, x.data(), incx) as usize }
+}
+/// [dscal](http://www.netlib.org/blas/dscal.f) BLAS operation.
+///
+/// `x = alpha·x`
+pub fn dscal<X: MatrixMut<f64>>(alpha: f64, x: &mut X) {
+    let (n, incx) = elements(x);
+    unsafe {
+        cblas::dscal(n, alpha, x.data_mut(), incx);
+    }
+}
+/// [dswap](http://www.netlib.org/blas/dswap.f) BLAS operation.
+///
+/// Exchanges the elements of `x` and `y`.
+pub fn dswap<X: MatrixMut<f64>, Y: MatrixMut<f64>>(x: &mut X, y: &mut Y) {
+    let ((n, incx), (len, incy)) = (elements(x), elements(y));
+    assert_eq!(n, len, "Non-matching lengths");
+    assert_same_order(x, y);
+    unsafe {
+        cblas::dswap(n, x.data_mut(), incx, y.data_mut(), incy);
+    }
+}
+/// [dcopy](http://www.netlib.org/blas/dcopy.f) BLAS operation.
+///
+/// `y = x`
+pub fn dcopy<X: Matrix<f64>, Y: MatrixMut<f64>>(x: &X, y: &mut Y) {
+    let ((n, incx), (len, incy)) = (elements(x), elements(y));
+    assert_eq!(n, len, "Non-matching lengths");
+    assert_same_order(x, y);
+    unsafe {
+        cblas::dcopy(n, x.data(), incx, y.data_mut(), incy);
+    }
+}
+/// [drot](http://www.netlib.org/blas/drot.f) BLAS operation.
+///
+/// Applies the plane rotation `[[c, s], [-s, c]]` to each pair of elements of `x` and `y`.
+pub fn drot<X: MatrixMut<f64>, Y: MatrixMut<f64>>(x: &mut X, y: &mut Y, c: f64, s: f64) {
+    let ((n, incx), (len, incy)) = (elements(x), elements(y));
+    assert_eq!(n, len, "Non-matching lengths");
+    assert_same_order(x, y);
+    unsafe {
+        cblas::drot(n, x.data_mut(), incx, y.data_mut(), incy, c, s);
+    }
+}
+/// [drotg](http://www.netlib.org/blas/drotg.f) BLAS operation.
+///
+/// Returns `(c, s)` of the plane rotation zeroing `b` in `[[c, s], [-s, c]]·[a, b]ᵀ`, overwriting
+///  `a` with `r` and `b` with `z` from which the rotation can be reconstructed.
+pub fn drotg(a: &mut f64, b: &mut f64) -> (f64, f64) {
+    let (mut c, mut s) = (0., 0.);
+    unsafe {
+        cblas::drotg(
+            std::slice::from_mut(a),
+            std::slice::from_mut(b),
+            std::slice::from_mut(&mut c),
+            std::slice::from_mut(&mut s),
+        );
+    }
+    (c, s)
 }
 // Level 2
 // --------------------------------------------------
+/// [sgemv](http://www.netlib.org/blas/sgemv.f) BLAS operation.
+///
+/// `y = alpha·op(a)·x + beta·y` where `x` and `y` are vectors, such as [`ColumnVectorD`].
+pub fn sgemv<A: Matrix<f32>, X: Matrix<f32>, Y: MatrixMut<f32>>(
+    transpose_a: bool,
+    alpha: f32,
+    a: &A,
+    x: &X,
+    beta: f32,
+    y: &mut Y,
+) {
+    let (m, n) = (a.rows() as i32, a.columns() as i32);
+    let ((x_len, incx), (y_len, incy)) = (vector(x), vector(y));
+    let (columns, rows) = if transpose_a { (m, n) } else { (n, m) };
+    assert_eq!(x_len, columns, "Non-matching columns to rows");
+    assert_eq!(y_len, rows, "Non-matching rows");
+    assert_matrix(a);
+    unsafe {
+        cblas::sgemv(
+            layout(a),
+            t(transpose_a),
+            m,
+            n,
+            alpha,
+            a.data(),
+            a.leading_dimension() as i32,
+            x.data(),
+            incx,
+            beta,
+            y.data_mut(),
+            incy,
+        );
+    }
+}
+/// [sger](http://www.netlib.org/blas/sger.f) BLAS operation.
+///
+/// `a = alpha·x·yᵀ + a` where `x` and `y` are vectors.
+pub fn sger<X: Matrix<f32>, Y: Matrix<f32>, A: MatrixMut<f32>>(
+    alpha: f32,
+    x: &X,
+    y: &Y,
+    a: &mut A,
+) {
+    let (m, n) = (a.rows() as i32, a.columns() as i32);
+    let ((x_len, incx), (y_len, incy)) = (vector(x), vector(y));
+    assert_eq!(x_len, m, "Non-matching rows");
+    assert_eq!(y_len, n, "Non-matching columns");
+    let (layout, lda) = (layout(a), a.leading_dimension() as i32);
+    assert_matrix(a);
+    unsafe {
+        cblas::sger(
+            layout,
+            m,
+            n,
+            alpha,
+            x.data(),
+            incx,
+            y.data(),
+            incy,
+            a.data_mut(),
+            lda,
+        );
+    }
+}
+/// [ssymv](http://www.netlib.org/blas/ssymv.f) BLAS operation.
+///
+/// `y = alpha·a·x + beta·y` where `a` is symmetric and only its lower (or upper if `!lower`)
+///  triangle is read.
+pub fn ssymv<A: Matrix<f32>, X: Matrix<f32>, Y: MatrixMut<f32>>(
+    lower: bool,
+    alpha: f32,
+    a: &A,
+    x: &X,
+    beta: f32,
+    y: &mut Y,
+) {
+    assert_eq!(a.rows(), a.columns(), "Non-square matrix");
+    let n = a.rows() as i32;
+    let ((x_len, incx), (y_len, incy)) = (vector(x), vector(y));
+    assert_eq!(x_len, n, "Non-matching columns to rows");
+    assert_eq!(y_len, n, "Non-matching rows");
+    assert_matrix(a);
+    unsafe {
+        cblas::ssymv(
+            layout(a),
+            part(lower),
+            n,
+            alpha,
+            a.data(),
+            a.leading_dimension() as i32,
+            x.data(),
+            incx,
+            beta,
+            y.data_mut(),
+            incy,
+        );
+    }
+}
+/// [strsv](http://www.netlib.org/blas/strsv.f) BLAS operation.
+///
+/// Solves `op(a)·x = b` where `a` is lower (or upper if `!lower`) triangular, overwriting `b`
+///  with `x`. When `unit_diagonal` the diagonal of `a` is taken to be ones and not read.
+pub fn strsv<A: Matrix<f32>, X: MatrixMut<f32>>(
+    lower: bool,
+    transpose_a: bool,
+    unit_diagonal: bool,
+    a: &A,
+    b: &mut X,
+) {
+    assert_eq!(a.rows(), a.columns(), "Non-square matrix");
+    let n = a.rows() as i32;
+    let (len, inc) = vector(b);
+    assert_eq!(len, n, "Non-matching columns to rows");
+    assert_matrix(a);
+    unsafe {
+        cblas::strsv(
+            layout(a),
+            part(lower),
+            t(transpose_a),
+            diagonal(unit_diagonal),
+            n,
+            a.data(),
+            a.leading_dimension() as i32,
+            b.data_mut(),
+            inc,
+        );
+    }
+}
+/// [dgemv](http://www.netlib.org/blas/dgemv.f) BLAS operation.
+///
+/// `y = alpha·op(a)·x + beta·y` where `x` and `y` are vectors, such as [`ColumnVectorD`].
+pub fn dgemv<A: Matrix<f64>, X: Matrix<f64>, Y: MatrixMut<f64>>(
+    transpose_a: bool,
+    alpha: f64,
+    a: &A,
+    x: &X,
+    beta: f64,
+    y: &mut Y,
+) {
+    let (m, n) = (a.rows() as i32, a.columns() as i32);
+    let ((x_len, incx), (y_len, incy)) = (vector(x), vector(y));
+    let (columns, rows) = if transpose_a { (m, n) } else { (n, m) };
+    assert_eq!(x_len, columns, "Non-matching columns to rows");
+    assert_eq!(y_len, rows, "Non-matching rows");
+    assert_matrix(a);
+    unsafe {
+        cblas::dgemv(
+            layout(a),
+            t(transpose_a),
+            m,
+            n,
+            alpha,
+            a.data(),
+            a.leading_dimension() as i32,
+            x.data(),
+            incx,
+            beta,
+            y.data_mut(),
+            incy,
+        );
+    }
+}
+/// [dger](http://www.netlib.org/blas/dger.f) BLAS operation.
+///
+/// `a = alpha·x·yᵀ + a` where `x` and `y` are vectors.
+pub fn dger<X: Matrix<f64>, Y: Matrix<f64>, A: MatrixMut<f64>>(
+    alpha: f64,
+    x: &X,
+    y: &Y,
+    a: &mut A,
+) {
+    let (m, n) = (a.rows() as i32, a.columns() as i32);
+    let ((x_len, incx), (y_len, incy)) = (vector(x), vector(y));
+    assert_eq!(x_len, m, "Non-matching rows");
+    assert_eq!(y_len, n, "Non-matching columns");
+    let (layout, lda) = (layout(a), a.leading_dimension() as i32);
+    assert_matrix(a);
+    unsafe {
+        cblas::dger(
+            layout,
+            m,
+            n,
+            alpha,
+            x.data(),
+            incx,
+            y.data(),
+            incy,
+            a.data_mut(),
+            lda,
+        );
+    }
+}
+/// [dsymv](http://www.netlib.org/blas/dsymv.f) BLAS operation.
+///
+/// `y = alpha·a·x + beta·y` where `a` is symmetric and only its lower (or upper if `!lower`)
+///  triangle is read.
+pub fn dsymv<A: Matrix<f64>, X: Matrix<f64>, Y: MatrixMut<f64>>(
+    lower: bool,
+    alpha: f64,
+    a: &A,
+    x: &X,
+    beta: f64,
+    y: &mut Y,
+) {
+    assert_eq!(a.rows(), a.columns(), "Non-square matrix");
+    let n = a.rows() as i32;
+    let ((x_len, incx), (y_len, incy)) = (vector(x), vector(y));
+    assert_eq!(x_len, n, "Non-matching columns to rows");
+    assert_eq!(y_len, n, "Non-matching rows");
+    assert_matrix(a);
+    unsafe {
+        cblas::dsymv(
+            layout(a),
+            part(lower),
+            n,
+            alpha,
+            a.data(),
+            a.leading_dimension() as i32,
+            x.data(),
+            incx,
+            beta,
+            y.data_mut(),
+            incy,
+        );
+    }
+}
+/// [dtrsv](http://www.netlib.org/blas/dtrsv.f) BLAS operation.
+///
+/// Solves `op(a)·x = b` where `a` is lower (or upper if `!lower`) triangular, overwriting `b`
+///  with `x`. When `unit_diagonal` the diagonal of `a` is taken to be ones and not read.
+pub fn dtrsv<A: Matrix<f64>, X: MatrixMut<f64>>(
+    lower: bool,
+    transpose_a: bool,
+    unit_diagonal: bool,
+    a: &A,
+    b: &mut X,
+) {
+    assert_eq!(a.rows(), a.columns(), "Non-square matrix");
+    let n = a.rows() as i32;
+    let (len, inc) = vector(b);
+    assert_eq!(len, n, "Non-matching columns to rows");
+    assert_matrix(a);
+    unsafe {
+        cblas::dtrsv(
+            layout(a),
+            part(lower),
+            t(transpose_a),
+            diagonal(unit_diagonal),
+            n,
+            a.data(),
+            a.leading_dimension() as i32,
+            b.data_mut(),
+            inc,
+        );
+    }
+}
+// Level 3
+// --------------------------------------------------
 /// [sgemm](http://www.netlib.org/lapack/explore-html/d4/de2/sgemm_8f.html) BLAS operation.
-pub fn sgemm<A: Matrix<f32>, B: Matrix<f32>, C: Matrix<f32>>(
+///
+/// Operands may be in either layout, the operation is performed in the layout of `c`.
+pub fn sgemm<A: Matrix<f32>, B: Matrix<f32>, C: MatrixMut<f32>>(
     transpose_a: bool,
     transpose_b: bool,
     alpha: f32,
@@ -134,29 +893,208 @@ pub fn sgemm<A: Matrix<f32>, B: Matrix<f32>, C: Matrix<f32>>(
     beta: f32,
     c: &mut C,
 ) {
-    assert_eq!(a.columns(), b.rows(), "Non-matching columns to rows");
-    let (m, n, k) = (a.rows() as i32, b.columns() as i32, a.columns() as i32);
+    let (m, k) = op_shape(a, transpose_a);
+    let (rows, n) = op_shape(b, transpose_b);
+    assert_eq!(k, rows, "Non-matching columns to rows");
+    assert_eq!(m, c.rows(), "Non-matching rows");
+    assert_eq!(n, c.columns(), "Non-matching columns");
+    let (m, n, k) = (m as i32, n as i32, k as i32);
+    let (lda, ldb, ldc) = (
+        a.leading_dimension() as i32,
+        b.leading_dimension() as i32,
+        c.leading_dimension() as i32,
+    );
+    assert_matrix(a);
+    assert_matrix(b);
+    assert_matrix(c);
     unsafe {
         cblas::sgemm(
-            cblas::Layout::RowMajor,
-            t(transpose_a),
-            t(transpose_b),
+            layout(c),
+            t(transpose_a ^ (a.column_major() != c.column_major())),
+            t(transpose_b ^ (b.column_major() != c.column_major())),
             m,
             n,
             k,
             alpha,
             a.data(),
-            k,
+            lda,
             b.data(),
+            ldb,
+            beta,
+            c.data_mut(),
+            ldc,
+        );
+    }
+}
+/// [strsm](http://www.netlib.org/blas/strsm.f) BLAS operation.
+///
+/// Solves `op(a)·x = alpha·b` (or `x·op(a) = alpha·b` if `!left`) where `a` is lower (or upper
+///  if `!lower`) triangular, overwriting `b` with `x`. When `unit_diagonal` the diagonal of `a`
+///  is taken to be ones and not read.
+pub fn strsm<A: Matrix<f32>, B: MatrixMut<f32>>(
+    left: bool,
+    lower: bool,
+    transpose_a: bool,
+    unit_diagonal: bool,
+    alpha: f32,
+    a: &A,
+    b: &mut B,
+) {
+    assert_eq!(a.rows(), a.columns(), "Non-square matrix");
+    let (m, n) = (b.rows() as i32, b.columns() as i32);
+    assert_eq!(
+        a.rows() as i32,
+        if left { m } else { n },
+        "Non-matching columns to rows"
+    );
+    // `a` in the layout of `b` is its transpose, swapping the triangle read.
+    let flip = a.column_major() != b.column_major();
+    let (layout, ldb) = (layout(b), b.leading_dimension() as i32);
+    assert_matrix(a);
+    assert_matrix(b);
+    unsafe {
+        cblas::strsm(
+            layout,
+            side(left),
+            part(lower ^ flip),
+            t(transpose_a ^ flip),
+            diagonal(unit_diagonal),
+            m,
+            n,
+            alpha,
+            a.data(),
+            a.leading_dimension() as i32,
+            b.data_mut(),
+            ldb,
+        );
+    }
+}
+/// [strmm](http://www.netlib.org/blas/strmm.f) BLAS operation.
+///
+/// `b = alpha·op(a)·b` (or `b = alpha·b·op(a)` if `!left`) where `a` is lower (or upper if
+///  `!lower`) triangular. When `unit_diagonal` the diagonal of `a` is taken to be ones and not
+///  read.
+pub fn strmm<A: Matrix<f32>, B: MatrixMut<f32>>(
+    left: bool,
+    lower: bool,
+    transpose_a: bool,
+    unit_diagonal: bool,
+    alpha: f32,
+    a: &A,
+    b: &mut B,
+) {
+    assert_eq!(a.rows(), a.columns(), "Non-square matrix");
+    let (m, n) = (b.rows() as i32, b.columns() as i32);
+    assert_eq!(
+        a.rows() as i32,
+        if left { m } else { n },
+        "Non-matching columns to rows"
+    );
+    let flip = a.column_major() != b.column_major();
+    let (layout, ldb) = (layout(b), b.leading_dimension() as i32);
+    assert_matrix(a);
+    assert_matrix(b);
+    unsafe {
+        cblas::strmm(
+            layout,
+            side(left),
+            part(lower ^ flip),
+            t(transpose_a ^ flip),
+            diagonal(unit_diagonal),
+            m,
+            n,
+            alpha,
+            a.data(),
+            a.leading_dimension() as i32,
+            b.data_mut(),
+            ldb,
+        );
+    }
+}
+/// [ssyrk](http://www.netlib.org/blas/ssyrk.f) BLAS operation.
+///
+/// `c = alpha·op(a)·op(a)ᵀ + beta·c` where only the lower (or upper if `!lower`) triangle of
+///  the symmetric `c` is written. With `transpose_a` this forms `aᵀ·a` in about half the
+///  operations of [`Matmul`](crate::Matmul).
+pub fn ssyrk<A: Matrix<f32>, C: MatrixMut<f32>>(
+    lower: bool,
+    transpose_a: bool,
+    alpha: f32,
+    a: &A,
+    beta: f32,
+    c: &mut C,
+) {
+    assert_eq!(c.rows(), c.columns(), "Non-square matrix");
+    let (rows, k) = op_shape(a, transpose_a);
+    assert_eq!(rows, c.rows(), "Non-matching rows");
+    let (n, ldc) = (c.rows() as i32, c.leading_dimension() as i32);
+    assert_matrix(a);
+    assert_matrix(c);
+    unsafe {
+        cblas::ssyrk(
+            layout(c),
+            part(lower),
+            t(transpose_a ^ (a.column_major() != c.column_major())),
             n,
+            k as i32,
+            alpha,
+            a.data(),
+            a.leading_dimension() as i32,
             beta,
             c.data_mut(),
+            ldc,
+        );
+    }
+}
+/// [ssymm](http://www.netlib.org/blas/ssymm.f) BLAS operation.
+///
+/// `c = alpha·a·b + beta·c` (or `c = alpha·b·a + beta·c` if `!left`) where `a` is symmetric and
+///  only its lower (or upper if `!lower`) triangle is read. `b` and `c` must share a layout.
+pub fn ssymm<A: Matrix<f32>, B: Matrix<f32>, C: MatrixMut<f32>>(
+    left: bool,
+    lower: bool,
+    alpha: f32,
+    a: &A,
+    b: &B,
+    beta: f32,
+    c: &mut C,
+) {
+    assert_eq!(a.rows(), a.columns(), "Non-square matrix");
+    assert_eq!(b.rows(), c.rows(), "Non-matching rows");
+    assert_eq!(b.columns(), c.columns(), "Non-matching columns");
+    assert_eq!(b.column_major(), c.column_major(), "Non-matching layouts");
+    let (m, n) = (c.rows() as i32, c.columns() as i32);
+    assert_eq!(
+        a.rows() as i32,
+        if left { m } else { n },
+        "Non-matching columns to rows"
+    );
+    let ldc = c.leading_dimension() as i32;
+    assert_matrix(a);
+    assert_matrix(b);
+    assert_matrix(c);
+    unsafe {
+        cblas::ssymm(
+            layout(c),
+            side(left),
+            part(lower ^ (a.column_major() != c.column_major())),
+            m,
             n,
+            alpha,
+            a.data(),
+            a.leading_dimension() as i32,
+            b.data(),
+            b.leading_dimension() as i32,
+            beta,
+            c.data_mut(),
+            ldc,
         );
     }
 }
 /// [dgemm](http://www.netlib.org/lapack/explore-html/d7/d2b/dgemm_8f.html) BLAS operation.
-pub fn dgemm<A: Matrix<f64>, B: Matrix<f64>, C: Matrix<f64>>(
+///
+/// Operands may be in either layout, the operation is performed in the layout of `c`.
+pub fn dgemm<A: Matrix<f64>, B: Matrix<f64>, C: MatrixMut<f64>>(
     transpose_a: bool,
     transpose_b: bool,
     alpha: f64,
@@ -165,24 +1103,838 @@ pub fn dgemm<A: Matrix<f64>, B: Matrix<f64>, C: Matrix<f64>>(
     beta: f64,
     c: &mut C,
 ) {
-    assert_eq!(a.columns(), b.rows(), "Non-matching columns to rows");
-    let (m, n, k) = (a.rows() as i32, b.columns() as i32, a.columns() as i32);
+    let (m, k) = op_shape(a, transpose_a);
+    let (rows, n) = op_shape(b, transpose_b);
+    assert_eq!(k, rows, "Non-matching columns to rows");
+    assert_eq!(m, c.rows(), "Non-matching rows");
+    assert_eq!(n, c.columns(), "Non-matching columns");
+    let (m, n, k) = (m as i32, n as i32, k as i32);
+    let (lda, ldb, ldc) = (
+        a.leading_dimension() as i32,
+        b.leading_dimension() as i32,
+        c.leading_dimension() as i32,
+    );
+    assert_matrix(a);
+    assert_matrix(b);
+    assert_matrix(c);
     unsafe {
         cblas::dgemm(
-            cblas::Layout::RowMajor,
-            t(transpose_a),
-            t(transpose_b),
+            layout(c),
+            t(transpose_a ^ (a.column_major() != c.column_major())),
+            t(transpose_b ^ (b.column_major() != c.column_major())),
             m,
             n,
             k,
             alpha,
             a.data(),
-            k,
+            lda,
             b.data(),
+            ldb,
+            beta,
+            c.data_mut(),
+            ldc,
+        );
+    }
+}
+/// [dtrsm](http://www.netlib.org/blas/dtrsm.f) BLAS operation.
+///
+/// Solves `op(a)·x = alpha·b` (or `x·op(a) = alpha·b` if `!left`) where `a` is lower (or upper
+///  if `!lower`) triangular, overwriting `b` with `x`. When `unit_diagonal` the diagonal of `a`
+///  is taken to be ones and not read.
+pub fn dtrsm<A: Matrix<f64>, B: MatrixMut<f64>>(
+    left: bool,
+    lower: bool,
+    transpose_a: bool,
+    unit_diagonal: bool,
+    alpha: f64,
+    a: &A,
+    b: &mut B,
+) {
+    assert_eq!(a.rows(), a.columns(), "Non-square matrix");
+    let (m, n) = (b.rows() as i32, b.columns() as i32);
+    assert_eq!(
+        a.rows() as i32,
+        if left { m } else { n },
+        "Non-matching columns to rows"
+    );
+    // `a` in the layout of `b` is its transpose, swapping the triangle read.
+    let flip = a.column_major() != b.column_major();
+    let (layout, ldb) = (layout(b), b.leading_dimension() as i32);
+    assert_matrix(a);
+    assert_matrix(b);
+    unsafe {
+        cblas::dtrsm(
+            layout,
+            side(left),
+            part(lower ^ flip),
+            t(transpose_a ^ flip),
+            diagonal(unit_diagonal),
+            m,
+            n,
+            alpha,
+            a.data(),
+            a.leading_dimension() as i32,
+            b.data_mut(),
+            ldb,
+        );
+    }
+}
+/// [dtrmm](http://www.netlib.org/blas/dtrmm.f) BLAS operation.
+///
+/// `b = alpha·op(a)·b` (or `b = alpha·b·op(a)` if `!left`) where `a` is lower (or upper if
+///  `!lower`) triangular. When `unit_diagonal` the diagonal of `a` is taken to be ones and not
+///  read.
+pub fn dtrmm<A: Matrix<f64>, B: MatrixMut<f64>>(
+    left: bool,
+    lower: bool,
+    transpose_a: bool,
+    unit_diagonal: bool,
+    alpha: f64,
+    a: &A,
+    b: &mut B,
+) {
+    assert_eq!(a.rows(), a.columns(), "Non-square matrix");
+    let (m, n) = (b.rows() as i32, b.columns() as i32);
+    assert_eq!(
+        a.rows() as i32,
+        if left { m } else { n },
+        "Non-matching columns to rows"
+    );
+    let flip = a.column_major() != b.column_major();
+    let (layout, ldb) = (layout(b), b.leading_dimension() as i32);
+    assert_matrix(a);
+    assert_matrix(b);
+    unsafe {
+        cblas::dtrmm(
+            layout,
+            side(left),
+            part(lower ^ flip),
+            t(transpose_a ^ flip),
+            diagonal(unit_diagonal),
+            m,
+            n,
+            alpha,
+            a.data(),
+            a.leading_dimension() as i32,
+            b.data_mut(),
+            ldb,
+        );
+    }
+}
+/// [dsyrk](http://www.netlib.org/blas/dsyrk.f) BLAS operation.
+///
+/// `c = alpha·op(a)·op(a)ᵀ + beta·c` where only the lower (or upper if `!lower`) triangle of
+///  the symmetric `c` is written. With `transpose_a` this forms `aᵀ·a` in about half the
+///  operations of [`Matmul`](crate::Matmul).
+pub fn dsyrk<A: Matrix<f64>, C: MatrixMut<f64>>(
+    lower: bool,
+    transpose_a: bool,
+    alpha: f64,
+    a: &A,
+    beta: f64,
+    c: &mut C,
+) {
+    assert_eq!(c.rows(), c.columns(), "Non-square matrix");
+    let (rows, k) = op_shape(a, transpose_a);
+    assert_eq!(rows, c.rows(), "Non-matching rows");
+    let (n, ldc) = (c.rows() as i32, c.leading_dimension() as i32);
+    assert_matrix(a);
+    assert_matrix(c);
+    unsafe {
+        cblas::dsyrk(
+            layout(c),
+            part(lower),
+            t(transpose_a ^ (a.column_major() != c.column_major())),
             n,
+            k as i32,
+            alpha,
+            a.data(),
+            a.leading_dimension() as i32,
             beta,
             c.data_mut(),
+            ldc,
+        );
+    }
+}
+/// [dsymm](http://www.netlib.org/blas/dsymm.f) BLAS operation.
+///
+/// `c = alpha·a·b + beta·c` (or `c = alpha·b·a + beta·c` if `!left`) where `a` is symmetric and
+///  only its lower (or upper if `!lower`) triangle is read. `b` and `c` must share a layout.
+pub fn dsymm<A: Matrix<f64>, B: Matrix<f64>, C: MatrixMut<f64>>(
+    left: bool,
+    lower: bool,
+    alpha: f64,
+    a: &A,
+    b: &B,
+    beta: f64,
+    c: &mut C,
+) {
+    assert_eq!(a.rows(), a.columns(), "Non-square matrix");
+    assert_eq!(b.rows(), c.rows(), "Non-matching rows");
+    assert_eq!(b.columns(), c.columns(), "Non-matching columns");
+    assert_eq!(b.column_major(), c.column_major(), "Non-matching layouts");
+    let (m, n) = (c.rows() as i32, c.columns() as i32);
+    assert_eq!(
+        a.rows() as i32,
+        if left { m } else { n },
+        "Non-matching columns to rows"
+    );
+    let ldc = c.leading_dimension() as i32;
+    assert_matrix(a);
+    assert_matrix(b);
+    assert_matrix(c);
+    unsafe {
+        cblas::dsymm(
+            layout(c),
+            side(left),
+            part(lower ^ (a.column_major() != c.column_major())),
+            m,
             n,
+            alpha,
+            a.data(),
+            a.leading_dimension() as i32,
+            b.data(),
+            b.leading_dimension() as i32,
+            beta,
+            c.data_mut(),
+            ldc,
+        );
+    }
+}
+
+// Gemm
+// --------------------------------------------------
+/// The dimensions of a matrix at the type level.
+pub trait Shape {
+    type Rows: Dim;
+    type Columns: Dim;
+}
+/// An operand of [`Gemm`], possibly transposed by reference with `transpose_ref`.
+pub trait Operand<T>: Shape {
+    /// The matrix whose data is passed to BLAS.
+    type Stored: Matrix<T>;
+    /// Whether `self` is the transpose of [`Operand::stored`].
+    const TRANSPOSE: bool = false;
+    /// Whether `self` is the conjugate of [`Operand::stored`], only ever along with
+    ///  [`Operand::TRANSPOSE`].
+    const CONJUGATE: bool = false;
+    fn stored(&self) -> &Self::Stored;
+}
+/// A trait for [`sgemm`] and [`dgemm`], and `cgemm` and `zgemm` for complex elements, with compile
+///  time checking.
+///
+/// `c = alpha·a·b + beta·c` where transposing `a` or `b` is done by passing `a.transpose_ref()`
+///  or `b.transpose_ref()`, and conjugate transposing them by passing `a.conj_transpose_ref()` or
+///  `b.conj_transpose_ref()`. Static dimensions of `a`, `b` and `c` must agree at compile time,
+///  remaining dynamic dimensions are checked at runtime.
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// use static_la::{blas::Gemm, MatrixDxS, MatrixSxS};
+/// let a = MatrixSxS::<f32, 3, 2>::from([[1., 2.], [3., 4.], [5., 6.]]);
+/// let b = MatrixDxS::<f32, 2>::from(vec![[7., 10.], [8., 11.], [9., 12.]]);
+/// let mut c = MatrixSxS::<f32, 2, 2>::from([[0., 0.], [0., 0.]]);
+/// a.transpose_ref().gemm(1., &b, 0., &mut c);
+/// assert_eq!(c, MatrixSxS::from([[76., 103.], [100., 136.]]));
+/// ```
+/// Mismatched static dimensions fail to compile:
+/// ```compile_fail
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// use static_la::{blas::Gemm, MatrixSxS};
+/// let a = MatrixSxS::<f32, 2, 3>::from([[1., 2., 3.], [4., 5., 6.]]);
+/// let mut c = MatrixSxS::<f32, 2, 2>::from([[0., 0.], [0., 0.]]);
+/// a.gemm(1., &a, 0., &mut c);
+/// ```
+pub trait Gemm<T, B, C> {
+    fn gemm(&self, alpha: T, b: &B, beta: T, c: &mut C);
+}
+impl<A: Operand<f32>, B: Operand<f32>, C: Shape + MatrixMut<f32>> Gemm<f32, B, C> for A
+where
+    A::Rows: Join<C::Rows>,
+    A::Columns: Join<B::Rows>,
+    B::Columns: Join<C::Columns>,
+{
+    fn gemm(&self, alpha: f32, b: &B, beta: f32, c: &mut C) {
+        sgemm(
+            A::TRANSPOSE,
+            B::TRANSPOSE,
+            alpha,
+            self.stored(),
+            b.stored(),
+            beta,
+            c,
+        );
+    }
+}
+impl<A: Operand<f64>, B: Operand<f64>, C: Shape + MatrixMut<f64>> Gemm<f64, B, C> for A
+where
+    A::Rows: Join<C::Rows>,
+    A::Columns: Join<B::Rows>,
+    B::Columns: Join<C::Columns>,
+{
+    fn gemm(&self, alpha: f64, b: &B, beta: f64, c: &mut C) {
+        dgemm(
+            A::TRANSPOSE,
+            B::TRANSPOSE,
+            alpha,
+            self.stored(),
+            b.stored(),
+            beta,
+            c,
+        );
+    }
+}
+impl<A, B, C> Gemm<Complex<f32>, B, C> for A
+where
+    A: Operand<Complex<f32>>,
+    B: Operand<Complex<f32>>,
+    C: Shape + MatrixMut<Complex<f32>>,
+    A::Rows: Join<C::Rows>,
+    A::Columns: Join<B::Rows>,
+    B::Columns: Join<C::Columns>,
+{
+    fn gemm(&self, alpha: Complex<f32>, b: &B, beta: Complex<f32>, c: &mut C) {
+        matmul_into(self, b, c, alpha, beta);
+    }
+}
+impl<A, B, C> Gemm<Complex<f64>, B, C> for A
+where
+    A: Operand<Complex<f64>>,
+    B: Operand<Complex<f64>>,
+    C: Shape + MatrixMut<Complex<f64>>,
+    A::Rows: Join<C::Rows>,
+    A::Columns: Join<B::Rows>,
+    B::Columns: Join<C::Columns>,
+{
+    fn gemm(&self, alpha: Complex<f64>, b: &B, beta: Complex<f64>, c: &mut C) {
+        matmul_into(self, b, c, alpha, beta);
+    }
+}
+impl<T, R: Dim, C: Dim, L: Layout> Shape for crate::Matrix<T, R, C, L>
+where
+    (R, C, L): Storage<T>,
+{
+    type Rows = R;
+    type Columns = C;
+}
+impl<T, R: Dim, C: Dim, L: Layout> Operand<T> for crate::Matrix<T, R, C, L>
+where
+    (R, C, L): Storage<T>,
+{
+    type Stored = Self;
+    fn stored(&self) -> &Self {
+        self
+    }
+}
+impl<'a, T> Shape for ViewDxD<'a, T> {
+    type Rows = Dynamic;
+    type Columns = Dynamic;
+}
+impl<'a, T, const ROWS: usize> Shape for ViewSxD<'a, T, ROWS> {
+    type Rows = Static<ROWS>;
+    type Columns = Dynamic;
+}
+impl<'a, T, const COLUMNS: usize> Shape for ViewDxS<'a, T, COLUMNS> {
+    type Rows = Dynamic;
+    type Columns = Static<COLUMNS>;
+}
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> Shape for ViewSxS<'a, T, ROWS, COLUMNS> {
+    type Rows = Static<ROWS>;
+    type Columns = Static<COLUMNS>;
+}
+impl<'a, T> Shape for ViewDxDMut<'a, T> {
+    type Rows = Dynamic;
+    type Columns = Dynamic;
+}
+impl<'a, T, const ROWS: usize> Shape for ViewSxDMut<'a, T, ROWS> {
+    type Rows = Static<ROWS>;
+    type Columns = Dynamic;
+}
+impl<'a, T, const COLUMNS: usize> Shape for ViewDxSMut<'a, T, COLUMNS> {
+    type Rows = Dynamic;
+    type Columns = Static<COLUMNS>;
+}
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> Shape for ViewSxSMut<'a, T, ROWS, COLUMNS> {
+    type Rows = Static<ROWS>;
+    type Columns = Static<COLUMNS>;
+}
+impl<'a, T> Operand<T> for ViewDxD<'a, T> {
+    type Stored = Self;
+    fn stored(&self) -> &Self {
+        self
+    }
+}
+impl<'a, T, const ROWS: usize> Operand<T> for ViewSxD<'a, T, ROWS> {
+    type Stored = Self;
+    fn stored(&self) -> &Self {
+        self
+    }
+}
+impl<'a, T, const COLUMNS: usize> Operand<T> for ViewDxS<'a, T, COLUMNS> {
+    type Stored = Self;
+    fn stored(&self) -> &Self {
+        self
+    }
+}
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> Operand<T> for ViewSxS<'a, T, ROWS, COLUMNS> {
+    type Stored = Self;
+    fn stored(&self) -> &Self {
+        self
+    }
+}
+impl<'a, T> Shape for Vector<'a, T> {
+    type Rows = Dynamic;
+    type Columns = Dynamic;
+}
+impl<'a, T> Shape for VectorMut<'a, T> {
+    type Rows = Dynamic;
+    type Columns = Dynamic;
+}
+impl<'a, T> Operand<T> for Vector<'a, T> {
+    type Stored = Self;
+    fn stored(&self) -> &Self {
+        self
+    }
+}
+impl<'a, T> Shape for TransposeDxD<'a, T> {
+    type Rows = Dynamic;
+    type Columns = Dynamic;
+}
+impl<'a, T, const ROWS: usize> Shape for TransposeSxD<'a, T, ROWS> {
+    type Rows = Dynamic;
+    type Columns = Static<ROWS>;
+}
+impl<'a, T, const COLUMNS: usize> Shape for TransposeDxS<'a, T, COLUMNS> {
+    type Rows = Static<COLUMNS>;
+    type Columns = Dynamic;
+}
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> Shape for TransposeSxS<'a, T, ROWS, COLUMNS>
+where
+    [(); ROWS * COLUMNS]:,
+{
+    type Rows = Static<COLUMNS>;
+    type Columns = Static<ROWS>;
+}
+impl<'a, T> Operand<T> for TransposeDxD<'a, T> {
+    type Stored = MatrixDxD<T>;
+    const TRANSPOSE: bool = true;
+    fn stored(&self) -> &MatrixDxD<T> {
+        self.0
+    }
+}
+impl<'a, T, const ROWS: usize> Operand<T> for TransposeSxD<'a, T, ROWS> {
+    type Stored = MatrixSxD<T, ROWS>;
+    const TRANSPOSE: bool = true;
+    fn stored(&self) -> &MatrixSxD<T, ROWS> {
+        self.0
+    }
+}
+impl<'a, T, const COLUMNS: usize> Operand<T> for TransposeDxS<'a, T, COLUMNS> {
+    type Stored = MatrixDxS<T, COLUMNS>;
+    const TRANSPOSE: bool = true;
+    fn stored(&self) -> &MatrixDxS<T, COLUMNS> {
+        self.0
+    }
+}
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> Operand<T>
+    for TransposeSxS<'a, T, ROWS, COLUMNS>
+where
+    [(); ROWS * COLUMNS]:,
+{
+    type Stored = MatrixSxS<T, ROWS, COLUMNS>;
+    const TRANSPOSE: bool = true;
+    fn stored(&self) -> &MatrixSxS<T, ROWS, COLUMNS> {
+        self.0
+    }
+}
+
+impl<'a, T> Shape for ConjugateTransposeDxD<'a, T> {
+    type Rows = Dynamic;
+    type Columns = Dynamic;
+}
+impl<'a, T, const ROWS: usize> Shape for ConjugateTransposeSxD<'a, T, ROWS> {
+    type Rows = Dynamic;
+    type Columns = Static<ROWS>;
+}
+impl<'a, T, const COLUMNS: usize> Shape for ConjugateTransposeDxS<'a, T, COLUMNS> {
+    type Rows = Static<COLUMNS>;
+    type Columns = Dynamic;
+}
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> Shape
+    for ConjugateTransposeSxS<'a, T, ROWS, COLUMNS>
+where
+    [(); ROWS * COLUMNS]:,
+{
+    type Rows = Static<COLUMNS>;
+    type Columns = Static<ROWS>;
+}
+impl<'a, T> Operand<Complex<T>> for ConjugateTransposeDxD<'a, T> {
+    type Stored = MatrixDxD<Complex<T>>;
+    const TRANSPOSE: bool = true;
+    const CONJUGATE: bool = true;
+    fn stored(&self) -> &MatrixDxD<Complex<T>> {
+        self.0
+    }
+}
+impl<'a, T, const ROWS: usize> Operand<Complex<T>> for ConjugateTransposeSxD<'a, T, ROWS> {
+    type Stored = MatrixSxD<Complex<T>, ROWS>;
+    const TRANSPOSE: bool = true;
+    const CONJUGATE: bool = true;
+    fn stored(&self) -> &MatrixSxD<Complex<T>, ROWS> {
+        self.0
+    }
+}
+impl<'a, T, const COLUMNS: usize> Operand<Complex<T>> for ConjugateTransposeDxS<'a, T, COLUMNS> {
+    type Stored = MatrixDxS<Complex<T>, COLUMNS>;
+    const TRANSPOSE: bool = true;
+    const CONJUGATE: bool = true;
+    fn stored(&self) -> &MatrixDxS<Complex<T>, COLUMNS> {
+        self.0
+    }
+}
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> Operand<Complex<T>>
+    for ConjugateTransposeSxS<'a, T, ROWS, COLUMNS>
+where
+    [(); ROWS * COLUMNS]:,
+{
+    type Stored = MatrixSxS<Complex<T>, ROWS, COLUMNS>;
+    const TRANSPOSE: bool = true;
+    const CONJUGATE: bool = true;
+    fn stored(&self) -> &MatrixSxS<Complex<T>, ROWS, COLUMNS> {
+        self.0
+    }
+}
+
+// Tests
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use crate::{blas::Gemm, *};
+    use std::convert::TryFrom;
+
+    // Level 1
+    // --------------------------------------------------
+    #[test]
+    fn dot() {
+        let a = MatrixSxS::<f64, 2, 3>::from([[1., 2., 3.], [4., 5., 6.]]);
+        assert_eq!(blas::ddot(&a, &a), 91.);
+        assert_eq!(blas::ddot(&blas::row(&a, 0), &blas::row(&a, 1)), 32.);
+        assert_eq!(blas::ddot(&blas::column(&a, 0), &blas::column(&a, 2)), 27.);
+        // Rows against columns of a column-major matrix.
+        let b = a.clone().transpose();
+        assert_eq!(blas::ddot(&blas::row(&b, 1), &blas::column(&a, 0)), 22.);
+        assert_eq!(blas::ddot(&blas::column(&b, 1), &blas::row(&a, 1)), 77.);
+    }
+    #[test]
+    #[should_panic(expected = "Columns out of bounds")]
+    fn column_out_of_bounds() {
+        let a = MatrixSxS::<f32, 2, 3>::from([[1., 2., 3.], [4., 5., 6.]]);
+        let _ = blas::column(&a, 3);
+    }
+    #[test]
+    #[should_panic(expected = "Non-matching lengths")]
+    fn dot_lengths() {
+        let a = MatrixSxS::<f32, 2, 3>::from([[1., 2., 3.], [4., 5., 6.]]);
+        let _ = blas::sdot(&blas::row(&a, 0), &blas::column(&a, 0));
+    }
+    #[test]
+    fn nrm2_asum_iamax() {
+        let a = MatrixDxD::try_from(vec![vec![3., -1.], vec![-4., 2.]]).unwrap();
+        assert_eq!(blas::dnrm2(&blas::column(&a, 0)), 5.);
+        assert_eq!(blas::dasum(&blas::column(&a, 0)), 7.);
+        assert_eq!(blas::idamax(&blas::column(&a, 0)), 1);
+        assert_eq!(blas::idamax(&blas::row(&a, 0)), 0);
+        assert_eq!(blas::dasum(&a), 10.);
+    }
+    #[test]
+    fn axpy() {
+        let mut a = MatrixSxS::<f32, 2, 2>::from([[1., 2.], [3., 4.]]);
+        let b = MatrixSxS::<f32, 2, 2>::from([[1., 1.], [1., 1.]]);
+        blas::saxpy(2., &blas::row(&b, 0), &mut blas::column_mut(&mut a, 1));
+        assert_eq!(a, MatrixSxS::from([[1., 4.], [3., 6.]]));
+        blas::saxpy(1., &b, &mut a);
+        assert_eq!(a, MatrixSxS::from([[2., 5.], [4., 7.]]));
+    }
+    #[test]
+    fn scal_copy() {
+        let mut a = MatrixDxS::from(vec![[1., 2.], [3., 4.]]);
+        blas::dscal(2., &mut blas::column_mut(&mut a, 0));
+        assert_eq!(a, MatrixDxS::from(vec![[2., 2.], [6., 4.]]));
+        let b = MatrixDxS::from(vec![[7., 8.]]);
+        blas::dcopy(&blas::row(&b, 0), &mut blas::column_mut(&mut a, 1));
+        assert_eq!(a, MatrixDxS::from(vec![[2., 7.], [6., 8.]]));
+    }
+    #[test]
+    fn swap_rot() {
+        let mut a = MatrixSxS::<f64, 2, 2>::from([[1., 2.], [3., 4.]]);
+        let mut b = MatrixSxS::<f64, 2, 2>::from([[5., 6.], [7., 8.]]).transpose();
+        blas::dswap(
+            &mut blas::column_mut(&mut a, 0),
+            &mut blas::row_mut(&mut b, 1),
+        );
+        assert_eq!(a, MatrixSxS::from([[6., 2.], [8., 4.]]));
+        assert_eq!(b, MatrixSxS::from([[5., 7.], [1., 3.]]));
+        blas::drot(
+            &mut blas::row_mut(&mut a, 0),
+            &mut blas::column_mut(&mut b, 0),
+            0.,
+            1.,
+        );
+        assert_eq!(a, MatrixSxS::from([[5., 1.], [8., 4.]]));
+        assert_eq!(b, MatrixSxS::from([[-6., 7.], [-2., 3.]]));
+    }
+    #[test]
+    fn rotg() {
+        let (mut a, mut b) = (0f32, 2f32);
+        let (c, s) = blas::srotg(&mut a, &mut b);
+        assert_eq!((a, b, c, s), (2., 1., 0., 1.));
+    }
+    // Level 2
+    // --------------------------------------------------
+    #[test]
+    fn gemv() {
+        let a = MatrixSxS::<f32, 2, 3>::from([[1., 3., 5.], [2., 4., 6.]]);
+        let x = ColumnVectorS::<f32, 3>::from([[1.], [2.], [3.]]);
+        let mut y = ColumnVectorD::from(vec![[1.], [1.]]);
+        blas::sgemv(false, 1., &a, &x, 2., &mut y);
+        assert_eq!(y, ColumnVectorD::from(vec![[24.], [30.]]));
+
+        let mut y = RowVectorS::<f32, 3>::from([[0., 0., 0.]]);
+        blas::sgemv(true, 1., &a, &ColumnVectorS::from([[1.], [1.]]), 0., &mut y);
+        assert_eq!(y, RowVectorS::from([[3., 7., 11.]]));
+    }
+    #[test]
+    fn gemv_column_major() {
+        let a = MatrixDxD::try_from(vec![vec![1., 3., 5.], vec![2., 4., 6.]])
+            .unwrap()
+            .transpose();
+        let mut y = ColumnVectorD::from(vec![[0.], [0.], [0.]]);
+        blas::dgemv(
+            false,
+            1.,
+            &a,
+            &ColumnVectorS::from([[1.], [1.]]),
+            0.,
+            &mut y,
+        );
+        assert_eq!(y, ColumnVectorD::from(vec![[3.], [7.], [11.]]));
+    }
+    #[test]
+    #[should_panic(expected = "Non-matching columns to rows")]
+    fn gemv_columns_to_rows() {
+        let a = MatrixSxS::<f64, 2, 3>::from([[1., 3., 5.], [2., 4., 6.]]);
+        let mut y = ColumnVectorS::<f64, 2>::from([[0.], [0.]]);
+        blas::dgemv(
+            false,
+            1.,
+            &a,
+            &ColumnVectorS::from([[1.], [1.]]),
+            0.,
+            &mut y,
+        );
+    }
+    /// A matrix claiming more elements than its data holds.
+    struct Short {
+        rows: usize,
+        columns: usize,
+        data: Vec<f32>,
+    }
+    impl blas::Matrix<f32> for Short {
+        fn rows(&self) -> usize {
+            self.rows
+        }
+        fn columns(&self) -> usize {
+            self.columns
+        }
+        fn data(&self) -> &[f32] {
+            &self.data
+        }
+    }
+    #[test]
+    #[should_panic(expected = "Matrix exceeds data")]
+    fn gemv_matrix_exceeds_data() {
+        let a = Short {
+            rows: 2,
+            columns: 2,
+            data: vec![1., 2., 3.],
+        };
+        let mut y = ColumnVectorS::<f32, 2>::from([[0.], [0.]]);
+        blas::sgemv(
+            false,
+            1.,
+            &a,
+            &ColumnVectorS::from([[1.], [1.]]),
+            0.,
+            &mut y,
+        );
+    }
+    #[test]
+    #[should_panic(expected = "Vector exceeds data")]
+    fn gemv_vector_exceeds_data() {
+        let a = MatrixSxS::<f32, 2, 2>::from([[1., 2.], [3., 4.]]);
+        let x = Short {
+            rows: 2,
+            columns: 1,
+            data: vec![1.],
+        };
+        let mut y = ColumnVectorS::<f32, 2>::from([[0.], [0.]]);
+        blas::sgemv(false, 1., &a, &x, 0., &mut y);
+    }
+    #[test]
+    fn ger() {
+        let mut a = MatrixSxS::<f64, 2, 3>::from([[1., 1., 1.], [1., 1., 1.]]);
+        let x = ColumnVectorS::from([[1.], [2.]]);
+        let y = ColumnVectorD::from(vec![[1.], [2.], [3.]]);
+        blas::dger(2., &x, &y, &mut a);
+        assert_eq!(a, MatrixSxS::from([[3., 5., 7.], [5., 9., 13.]]));
+    }
+    #[test]
+    fn symv() {
+        // Only the lower triangle of `[[2, 1], [1, 3]]` is read.
+        let a = MatrixSxS::<f32, 2, 2>::from([[2., f32::NAN], [1., 3.]]);
+        let mut y = ColumnVectorS::from([[0.], [0.]]);
+        blas::ssymv(true, 1., &a, &ColumnVectorS::from([[1.], [1.]]), 0., &mut y);
+        assert_eq!(y, ColumnVectorS::from([[3.], [4.]]));
+    }
+    #[test]
+    fn trsv() {
+        let a = MatrixSxS::<f64, 2, 2>::from([[2., f64::NAN], [1., 4.]]);
+        let mut x = ColumnVectorS::from([[2.], [9.]]);
+        blas::dtrsv(true, false, false, &a, &mut x);
+        assert_eq!(x, ColumnVectorS::from([[1.], [2.]]));
+
+        let mut x = ColumnVectorD::from(vec![[4.], [8.]]);
+        blas::dtrsv(true, true, false, &a, &mut x);
+        assert_eq!(x, ColumnVectorD::from(vec![[1.], [2.]]));
+    }
+    // Level 3
+    // --------------------------------------------------
+    #[test]
+    fn trsm() {
+        let a = MatrixSxS::<f64, 2, 2>::from([[2., f64::NAN], [1., 4.]]);
+        let mut b = MatrixSxS::from([[2., 4.], [13., 18.]]);
+        blas::dtrsm(true, true, false, false, 1., &a, &mut b);
+        assert_eq!(b, MatrixSxS::from([[1., 2.], [3., 4.]]));
+
+        // `[[2, 0], [1, 4]]` read in the column-major layout of `b` is upper triangular.
+        let mut b = MatrixSxS::<f64, 2, 2>::from([[4., 10.], [8., 16.]]).transpose();
+        blas::dtrsm(false, true, false, false, 1., &a, &mut b);
+        assert_eq!(b, MatrixSxS::from([[1., 2.], [3., 4.]]));
+    }
+    #[test]
+    #[should_panic(expected = "Non-matching columns to rows")]
+    fn trsm_columns_to_rows() {
+        let a = MatrixSxS::<f32, 2, 2>::from([[1., 0.], [0., 1.]]);
+        let mut b = MatrixSxS::<f32, 3, 2>::from([[1., 2.], [3., 4.], [5., 6.]]);
+        blas::strsm(true, true, false, false, 1., &a, &mut b);
+    }
+    #[test]
+    fn trmm() {
+        let a = MatrixSxS::<f32, 2, 2>::from([[2., f32::NAN], [1., 4.]]);
+        let mut b = MatrixSxS::from([[1., 2.], [3., 4.]]);
+        blas::strmm(true, true, false, false, 1., &a, &mut b);
+        assert_eq!(b, MatrixSxS::from([[2., 4.], [13., 18.]]));
+
+        let mut b = MatrixDxS::from(vec![[1., 2.], [3., 4.]]);
+        blas::strmm(false, true, true, true, 2., &a, &mut b);
+        assert_eq!(b, MatrixDxS::from(vec![[2., 6.], [6., 14.]]));
+    }
+    #[test]
+    fn syrk() {
+        let a = MatrixSxS::<f64, 3, 2>::from([[1., 2.], [3., 4.], [5., 6.]]);
+        let mut c = MatrixSxS::from([[0., -1.], [0., 0.]]);
+        blas::dsyrk(true, true, 1., &a, 0., &mut c);
+        assert_eq!(c, MatrixSxS::from([[35., -1.], [44., 56.]]));
+
+        let mut c = MatrixDxD::try_from(vec![vec![0.; 3]; 3])
+            .unwrap()
+            .transpose();
+        blas::dsyrk(false, false, 1., &a, 0., &mut c);
+        assert_eq!(
+            c,
+            MatrixSxS::from([[5., 11., 17.], [0., 25., 39.], [0., 0., 61.]])
+        );
+    }
+    #[test]
+    fn symm() {
+        let a = MatrixSxS::<f32, 2, 2>::from([[2., f32::NAN], [1., 3.]]);
+        let b = MatrixSxS::from([[1., 2.], [3., 4.]]);
+        let mut c = MatrixSxS::from([[0., 0.], [0., 0.]]);
+        blas::ssymm(true, true, 1., &a, &b, 0., &mut c);
+        assert_eq!(c, MatrixSxS::from([[5., 8.], [10., 14.]]));
+        blas::ssymm(false, true, 1., &a, &b, 1., &mut c);
+        assert_eq!(c, MatrixSxS::from([[9., 15.], [20., 29.]]));
+    }
+    #[test]
+    #[should_panic(expected = "Non-matching layouts")]
+    fn symm_layouts() {
+        let a = MatrixSxS::<f64, 2, 2>::from([[2., 1.], [1., 3.]]);
+        let b = MatrixSxS::from([[1., 2.], [3., 4.]]).transpose();
+        let mut c = MatrixSxS::from([[0., 0.], [0., 0.]]);
+        blas::dsymm(true, true, 1., &a, &b, 0., &mut c);
+    }
+    #[test]
+    #[should_panic(expected = "Non-matching columns")]
+    fn gemm_output_shape() {
+        let a = MatrixSxS::<f32, 2, 3>::from([[1., 3., 5.], [2., 4., 6.]]);
+        let b = MatrixSxS::<f32, 3, 2>::from([[7., 10.], [8., 11.], [9., 12.]]);
+        let mut c = MatrixSxS::<f32, 2, 1>::from([[0.], [0.]]);
+        blas::sgemm(false, false, 1., &a, &b, 0., &mut c);
+    }
+    #[test]
+    fn gemm_transpose() {
+        let a = MatrixSxS::<f64, 3, 2>::from([[1., 2.], [3., 4.], [5., 6.]]);
+        let b = MatrixSxS::<f64, 3, 2>::from([[7., 10.], [8., 11.], [9., 12.]]);
+        let mut c = MatrixSxS::from([[0., 0.], [0., 0.]]);
+        blas::dgemm(true, false, 1., &a, &b, 0., &mut c);
+        assert_eq!(c, MatrixSxS::from([[76., 103.], [100., 136.]]));
+    }
+    // Gemm
+    // --------------------------------------------------
+    #[test]
+    fn gemm_trait() {
+        let a = MatrixSxS::<f64, 2, 3>::from([[1., 3., 5.], [2., 4., 6.]]);
+        let b = MatrixDxD::try_from(vec![vec![7., 10.], vec![8., 11.], vec![9., 12.]]).unwrap();
+        let mut c = MatrixSxD::<f64, 2>::from((2, 1.));
+        a.gemm(1., &b, 1., &mut c);
+        assert_eq!(c, MatrixSxS::from([[77., 104.], [101., 137.]]));
+
+        // `bᵀ·aᵀ = (a·b)ᵀ`
+        let mut c = MatrixDxS::<f64, 2>::from((2, 0.));
+        b.transpose_ref().gemm(1., &a.transpose_ref(), 0., &mut c);
+        assert_eq!(c, MatrixSxS::from([[76., 100.], [103., 136.]]));
+    }
+    #[test]
+    fn gemm_trait_views() {
+        let a = MatrixSxS::<f32, 2, 3>::from([[1., 3., 5.], [2., 4., 6.]]);
+        let b = MatrixSxS::<f32, 3, 3>::from([[0., 7., 10.], [0., 8., 11.], [0., 9., 12.]]);
+        let mut c = MatrixSxS::<f32, 2, 2>::from([[0., 0.], [0., 0.]]);
+        a.gemm(1., &b.slice_dxd((0..3, 1..3)), 0., &mut c);
+        assert_eq!(c, MatrixSxS::from([[76., 103.], [100., 136.]]));
+    }
+    #[test]
+    fn gemm_trait_conjugate() {
+        use num_complex::Complex;
+        let c = |re: f64, im: f64| Complex::new(re, im);
+        let a = MatrixDxD::try_from(vec![vec![c(1., 1.), c(2., 0.)], vec![c(0., 0.), c(0., 1.)]])
+            .unwrap();
+        let b = MatrixSxS::<_, 2, 2>::from([[c(1., 0.), c(0., 1.)], [c(1., -1.), c(1., 0.)]]);
+        let mut d = MatrixSxS::<_, 2, 2>::from([[c(1., 0.); 2]; 2]);
+        // `aᴴ·b + d`
+        a.conj_transpose_ref()
+            .gemm(c(1., 0.), &b, c(1., 0.), &mut d);
+        assert_eq!(
+            d,
+            MatrixSxS::from([[c(2., -1.), c(2., 1.)], [c(2., -1.), c(1., 1.)]])
         );
     }
+    #[test]
+    #[should_panic(expected = "Non-matching columns to rows")]
+    fn gemm_trait_dynamic() {
+        let a = MatrixSxD::<f32, 2>::try_from((2, vec![1., 2., 3., 4.])).unwrap();
+        let b = MatrixDxS::<f32, 2>::from(vec![[1., 2.], [3., 4.], [5., 6.]]);
+        let mut c = MatrixSxS::<f32, 2, 2>::from([[0., 0.], [0., 0.]]);
+        a.gemm(1., &b, 0., &mut c);
+    }
 }
@@ -0,0 +1,553 @@
+use crate::*;
+use num_traits::Float;
+
+/// Internal Cholesky decomposition trait.
+pub trait InternalCholesky: Sized {
+    /// Factors `a` such that `A = L·Lᵀ`, overwriting `a` with `L`.
+    fn cholesky(
+        // An `n` by `n` row-major symmetric matrix, only the lower triangle is read.
+        a: &mut [Self],
+        // Rows and columns of `a`.
+        n: usize,
+    ) -> Result<(), &'static str>;
+    /// Solves `L·Lᵀ·X = B` overwriting `b` with `X`.
+    fn cholesky_solve(
+        // An `n` by `n` row-major lower triangular matrix.
+        l: &[Self],
+        // An `n` by `k` row-major matrix.
+        b: &mut [Self],
+        // Rows and columns of `l` and rows of `b`.
+        n: usize,
+        // Columns of `b`.
+        k: usize,
+    );
+}
+/// Default Cholesky decomposition implementation (Cholesky–Banachiewicz).
+impl<T: Float> InternalCholesky for T {
+    default fn cholesky(a: &mut [T], n: usize) -> Result<(), &'static str> {
+        debug_assert_eq!(a.len(), n * n);
+
+        for i in 0..n {
+            for j in 0..=i {
+                let s = (0..j).fold(a[i * n + j], |s, k| s - a[i * n + k] * a[j * n + k]);
+                if i == j {
+                    // Negated so a `NaN` also fails.
+                    if !(s > T::zero()) {
+                        return Err("Non positive-definite matrix");
+                    }
+                    a[i * n + i] = s.sqrt();
+                } else {
+                    a[i * n + j] = s / a[j * n + j];
+                }
+            }
+            for j in i + 1..n {
+                a[i * n + j] = T::zero();
+            }
+        }
+        Ok(())
+    }
+    default fn cholesky_solve(l: &[T], b: &mut [T], n: usize, k: usize) {
+        debug_assert_eq!(l.len(), n * n);
+        debug_assert_eq!(b.len(), n * k);
+
+        // Forward substitution with `L`.
+        for i in 0..n {
+            for j in 0..i {
+                let x = l[i * n + j];
+                for c in 0..k {
+                    b[i * k + c] = b[i * k + c] - x * b[j * k + c];
+                }
+            }
+            let diagonal = l[i * n + i];
+            for c in 0..k {
+                b[i * k + c] = b[i * k + c] / diagonal;
+            }
+        }
+        // Backward substitution with `Lᵀ`.
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let x = l[j * n + i];
+                for c in 0..k {
+                    b[i * k + c] = b[i * k + c] - x * b[j * k + c];
+                }
+            }
+            let diagonal = l[i * n + i];
+            for c in 0..k {
+                b[i * k + c] = b[i * k + c] / diagonal;
+            }
+        }
+    }
+}
+/// `f32` Cholesky decomposition specialization.
+#[cfg(feature = "lapacke")]
+impl InternalCholesky for f32 {
+    fn cholesky(a: &mut [f32], n: usize) -> Result<(), &'static str> {
+        assert_eq!(a.len(), n * n);
+        let info =
+            unsafe { lapacke::spotrf(lapacke::Layout::RowMajor, b'L', n as i32, a, n as i32) };
+        assert!(info >= 0, "Illegal `spotrf` argument");
+        match info {
+            0 => {
+                upper_zero(a, n);
+                Ok(())
+            }
+            _ => Err("Non positive-definite matrix"),
+        }
+    }
+    fn cholesky_solve(l: &[f32], b: &mut [f32], n: usize, k: usize) {
+        assert_eq!(l.len(), n * n);
+        assert_eq!(b.len(), n * k);
+        let info = unsafe {
+            lapacke::spotrs(
+                lapacke::Layout::RowMajor,
+                b'L',
+                n as i32,
+                k as i32,
+                l,
+                n as i32,
+                b,
+                k as i32,
+            )
+        };
+        assert_eq!(info, 0, "Illegal `spotrs` argument");
+    }
+}
+/// `f64` Cholesky decomposition specialization.
+#[cfg(feature = "lapacke")]
+impl InternalCholesky for f64 {
+    fn cholesky(a: &mut [f64], n: usize) -> Result<(), &'static str> {
+        assert_eq!(a.len(), n * n);
+        let info =
+            unsafe { lapacke::dpotrf(lapacke::Layout::RowMajor, b'L', n as i32, a, n as i32) };
+        assert!(info >= 0, "Illegal `dpotrf` argument");
+        match info {
+            0 => {
+                upper_zero(a, n);
+                Ok(())
+            }
+            _ => Err("Non positive-definite matrix"),
+        }
+    }
+    fn cholesky_solve(l: &[f64], b: &mut [f64], n: usize, k: usize) {
+        assert_eq!(l.len(), n * n);
+        assert_eq!(b.len(), n * k);
+        let info = unsafe {
+            lapacke::dpotrs(
+                lapacke::Layout::RowMajor,
+                b'L',
+                n as i32,
+                k as i32,
+                l,
+                n as i32,
+                b,
+                k as i32,
+            )
+        };
+        assert_eq!(info, 0, "Illegal `dpotrs` argument");
+    }
+}
+/// Zeros the strictly upper triangle `potrf` leaves untouched.
+fn upper_zero<T: Float>(a: &mut [T], n: usize) {
+    for i in 0..n {
+        for j in i + 1..n {
+            a[i * n + j] = T::zero();
+        }
+    }
+}
+
+// MatrixDxD
+// --------------------------------------------------
+impl<T: Float> MatrixDxD<T> {
+    /// Computes the lower triangular Cholesky factor `L` such that `self = L·Lᵀ`, returning an
+    ///  error if `self` is not positive-definite.
+    ///
+    /// Only the lower triangle of `self` is read. Panics if `self` is not square.
+    /// ```
+    /// use static_la::MatrixDxD;
+    /// use std::convert::TryFrom;
+    /// let a = MatrixDxD::try_from(vec![vec![4., 2.], vec![2., 10.]]).unwrap();
+    /// let l = a.cholesky().unwrap();
+    /// assert_eq!(l, MatrixDxD::try_from(vec![vec![2., 0.], vec![1., 3.]]).unwrap());
+    /// ```
+    pub fn cholesky(&self) -> Result<MatrixDxD<T>, &'static str> {
+        assert_eq!(self.rows, self.columns, "Non-square matrix");
+
+        let mut data = self.data.clone();
+        InternalCholesky::cholesky(&mut data, self.rows)?;
+        Ok(MatrixDxD {
+            data,
+            rows: self.rows,
+            columns: self.columns,
+        })
+    }
+}
+// MatrixDxS
+// --------------------------------------------------
+impl<T: Float, const N: usize> MatrixDxS<T, N>
+where
+    [(); N * N]:,
+{
+    /// Computes the lower triangular Cholesky factor `L` such that `self = L·Lᵀ`, returning an
+    ///  error if `self` is not positive-definite.
+    ///
+    /// Since `self` must be square the factor is an `N` by `N` static matrix.
+    pub fn cholesky(&self) -> Result<MatrixSxS<T, N, N>, &'static str> {
+        assert_eq!(self.rows, N, "Non-square matrix");
+
+        let mut data = [T::zero(); N * N];
+        data.copy_from_slice(&self.data);
+        InternalCholesky::cholesky(&mut data, N)?;
+        Ok(MatrixSxS {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+// MatrixSxD
+// --------------------------------------------------
+impl<T: Float, const N: usize> MatrixSxD<T, N>
+where
+    [(); N * N]:,
+{
+    /// Computes the lower triangular Cholesky factor `L` such that `self = L·Lᵀ`, returning an
+    ///  error if `self` is not positive-definite.
+    ///
+    /// Since `self` must be square the factor is an `N` by `N` static matrix.
+    pub fn cholesky(&self) -> Result<MatrixSxS<T, N, N>, &'static str> {
+        assert_eq!(self.columns, N, "Non-square matrix");
+
+        let mut data = [T::zero(); N * N];
+        data.copy_from_slice(&self.data);
+        InternalCholesky::cholesky(&mut data, N)?;
+        Ok(MatrixSxS {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+// MatrixSxS
+// --------------------------------------------------
+impl<T: Float, const N: usize> MatrixSxS<T, N, N>
+where
+    [(); N * N]:,
+{
+    /// Computes the lower triangular Cholesky factor `L` such that `self = L·Lᵀ`, returning an
+    ///  error if `self` is not positive-definite.
+    /// ```
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use static_la::MatrixSxS;
+    /// let a = MatrixSxS::<f64, 2, 2>::from([[4., 2.], [2., 10.]]);
+    /// let l = a.cholesky().unwrap();
+    /// assert_eq!(l, MatrixSxS::from([[2., 0.], [1., 3.]]));
+    /// ```
+    pub fn cholesky(&self) -> Result<MatrixSxS<T, N, N>, &'static str> {
+        let mut data = self.data;
+        InternalCholesky::cholesky(&mut data, N)?;
+        Ok(MatrixSxS {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+
+/// A trait for solving systems of linear equations with a Cholesky factor.
+pub trait CholeskySolve<B> {
+    type Output;
+    /// Solves `L·Lᵀ·X = B` for `X` where `self` is the lower triangular factor `L` given by
+    ///  `cholesky()`.
+    ///
+    /// This allows one factorization to be reused across many right-hand sides.
+    /// ```text
+    /// ┌─────┐                ┌───────┐  ┌──────┐
+    /// │ 2 0 │.cholesky_solve(│  6  2 │)=│ 1  1 │
+    /// │ 1 3 │                │ 12 -8 │  │ 1 -1 │
+    /// └─────┘                └───────┘  └──────┘
+    /// ```
+    /// - N: Rows and columns of `self`, rows of `B` and rows of `Self::Output`.
+    /// - K: Columns of `B` and columns of `Self::Output`.
+    fn cholesky_solve(&self, b: &B) -> Self::Output;
+}
+
+// MatrixDxD
+// --------------------------------------------------
+impl<T: Float, const N: usize, const K: usize> CholeskySolve<MatrixSxS<T, N, K>> for MatrixDxD<T>
+where
+    [(); N * K]:,
+{
+    type Output = MatrixSxS<T, N, K>;
+    fn cholesky_solve(&self, b: &MatrixSxS<T, N, K>) -> Self::Output {
+        assert_eq!(self.rows, self.columns, "Non-square matrix");
+        assert_eq!(self.rows, N, "Non-matching rows");
+
+        let mut data = b.data;
+        InternalCholesky::cholesky_solve(&self.data, &mut data, N, K);
+        Self::Output {
+            data,
+            rows: Static,
+            columns: Static,
+        }
+    }
+}
+impl<T: Float, const K: usize> CholeskySolve<MatrixDxS<T, K>> for MatrixDxD<T> {
+    type Output = MatrixDxS<T, K>;
+    fn cholesky_solve(&self, b: &MatrixDxS<T, K>) -> Self::Output {
+        assert_eq!(self.rows, self.columns, "Non-square matrix");
+        assert_eq!(self.rows, b.rows, "Non-matching rows");
+
+        let mut data = b.data.clone();
+        InternalCholesky::cholesky_solve(&self.data, &mut data, b.rows, K);
+        Self::Output {
+            data,
+            rows: b.rows,
+            columns: Static,
+        }
+    }
+}
+impl<T: Float, const N: usize> CholeskySolve<MatrixSxD<T, N>> for MatrixDxD<T> {
+    type Output = MatrixSxD<T, N>;
+    fn cholesky_solve(&self, b: &MatrixSxD<T, N>) -> Self::Output {
+        assert_eq!(self.rows, self.columns, "Non-square matrix");
+        assert_eq!(self.rows, N, "Non-matching rows");
+
+        let mut data = b.data.clone();
+        InternalCholesky::cholesky_solve(&self.data, &mut data, N, b.columns);
+        Self::Output {
+            data,
+            columns: b.columns,
+            rows: Static,
+        }
+    }
+}
+impl<T: Float> CholeskySolve<MatrixDxD<T>> for MatrixDxD<T> {
+    type Output = MatrixDxD<T>;
+    fn cholesky_solve(&self, b: &MatrixDxD<T>) -> Self::Output {
+        assert_eq!(self.rows, self.columns, "Non-square matrix");
+        assert_eq!(self.rows, b.rows, "Non-matching rows");
+
+        let mut data = b.data.clone();
+        InternalCholesky::cholesky_solve(&self.data, &mut data, b.rows, b.columns);
+        Self::Output {
+            data,
+            rows: b.rows,
+            columns: b.columns,
+        }
+    }
+}
+// MatrixSxS
+// --------------------------------------------------
+impl<T: Float, const N: usize, const K: usize> CholeskySolve<MatrixSxS<T, N, K>>
+    for MatrixSxS<T, N, N>
+where
+    [(); N * N]:,
+    [(); N * K]:,
+{
+    type Output = MatrixSxS<T, N, K>;
+    fn cholesky_solve(&self, b: &MatrixSxS<T, N, K>) -> Self::Output {
+        let mut data = b.data;
+        InternalCholesky::cholesky_solve(&self.data, &mut data, N, K);
+        Self::Output {
+            data,
+            rows: Static,
+            columns: Static,
+        }
+    }
+}
+impl<T: Float, const N: usize, const K: usize> CholeskySolve<MatrixDxS<T, K>> for MatrixSxS<T, N, N>
+where
+    [(); N * N]:,
+    [(); N * K]:,
+{
+    type Output = MatrixSxS<T, N, K>;
+    fn cholesky_solve(&self, b: &MatrixDxS<T, K>) -> Self::Output {
+        assert_eq!(N, b.rows, "Non-matching rows");
+
+        let mut data = [T::zero(); N * K];
+        data.copy_from_slice(&b.data);
+        InternalCholesky::cholesky_solve(&self.data, &mut data, N, K);
+        Self::Output {
+            data,
+            rows: Static,
+            columns: Static,
+        }
+    }
+}
+impl<T: Float, const N: usize> CholeskySolve<MatrixSxD<T, N>> for MatrixSxS<T, N, N>
+where
+    [(); N * N]:,
+{
+    type Output = MatrixSxD<T, N>;
+    fn cholesky_solve(&self, b: &MatrixSxD<T, N>) -> Self::Output {
+        let mut data = b.data.clone();
+        InternalCholesky::cholesky_solve(&self.data, &mut data, N, b.columns);
+        Self::Output {
+            data,
+            columns: b.columns,
+            rows: Static,
+        }
+    }
+}
+impl<T: Float, const N: usize> CholeskySolve<MatrixDxD<T>> for MatrixSxS<T, N, N>
+where
+    [(); N * N]:,
+{
+    type Output = MatrixSxD<T, N>;
+    fn cholesky_solve(&self, b: &MatrixDxD<T>) -> Self::Output {
+        assert_eq!(N, b.rows, "Non-matching rows");
+
+        let mut data = b.data.clone();
+        InternalCholesky::cholesky_solve(&self.data, &mut data, N, b.columns);
+        Self::Output {
+            data,
+            columns: b.columns,
+            rows: Static,
+        }
+    }
+}
+
+// Tests
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::convert::TryFrom;
+
+    // `A = L·Lᵀ` and `A·X = B`.
+    const A: [[f64; 3]; 3] = [[4., 2., -2.], [2., 10., 2.], [-2., 2., 6.]];
+    const L: [f64; 9] = [2., 0., 0., 1., 3., 0., -1., 1., 2.];
+    const B: [[f64; 2]; 3] = [[2., 4.], [4., 8.], [4., -4.]];
+    const X: [f64; 6] = [1., 0., 0., 1., 1., -1.];
+
+    // f32
+    // --------------------------------------------------
+    #[test]
+    fn f32() {
+        let a = MatrixSxS::<f32, 3, 3>::from(A.map(|r| r.map(|x| x as f32)));
+        let l = a.cholesky().unwrap();
+        assert_approx_eq(&l.data, &L.map(|x| x as f32));
+        let b = MatrixSxS::<f32, 3, 2>::from(B.map(|r| r.map(|x| x as f32)));
+        assert_approx_eq(&l.cholesky_solve(&b).data, &X.map(|x| x as f32));
+    }
+    #[test]
+    fn f32_non_positive_definite() {
+        let a = MatrixSxS::<f32, 2, 2>::from([[1., 2.], [2., 1.]]);
+        assert_eq!(a.cholesky(), Err("Non positive-definite matrix"));
+    }
+    // f64
+    // --------------------------------------------------
+    #[test]
+    fn f64_non_positive_definite() {
+        let a = MatrixDxD::<f64>::try_from(vec![vec![1., 2.], vec![2., 1.]]).unwrap();
+        assert_eq!(a.cholesky(), Err("Non positive-definite matrix"));
+    }
+    // f16
+    // --------------------------------------------------
+    #[test]
+    fn f16() {
+        let f = half::f16::from_f64;
+        let a = MatrixSxS::<half::f16, 3, 3>::from(A.map(|r| r.map(f)));
+        let l = a.cholesky().unwrap();
+        assert_approx_eq(&l.data, &L.map(f));
+        let b = MatrixSxS::<half::f16, 3, 2>::from(B.map(|r| r.map(f)));
+        assert_approx_eq(&l.cholesky_solve(&b).data, &X.map(f));
+    }
+    #[test]
+    fn f16_non_positive_definite() {
+        let f = half::f16::from_f64;
+        let a = MatrixSxS::<half::f16, 2, 2>::from([[f(1.), f(2.)], [f(2.), f(1.)]]);
+        assert_eq!(a.cholesky(), Err("Non positive-definite matrix"));
+    }
+    // Decomposition
+    // --------------------------------------------------
+    #[test]
+    fn dxd() {
+        let a = MatrixDxD::try_from(A.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let l: MatrixDxD<f64> = a.cholesky().unwrap();
+        assert_approx_eq(&l.data, &L);
+    }
+    #[test]
+    fn dxs() {
+        let a = MatrixDxS::from(A.to_vec());
+        let l: MatrixSxS<f64, 3, 3> = a.cholesky().unwrap();
+        assert_approx_eq(&l.data, &L);
+    }
+    #[test]
+    #[should_panic(expected = "Non-square matrix")]
+    fn dxs_non_square() {
+        let a = MatrixDxS::from(vec![[1., 0.]]);
+        let _ = a.cholesky();
+    }
+    #[test]
+    fn sxd() {
+        let a = MatrixSxD::try_from(A.map(|r| r.to_vec())).unwrap();
+        let l: MatrixSxS<f64, 3, 3> = a.cholesky().unwrap();
+        assert_approx_eq(&l.data, &L);
+    }
+    #[test]
+    fn sxs() {
+        let a = MatrixSxS::from(A);
+        let l: MatrixSxS<f64, 3, 3> = a.cholesky().unwrap();
+        assert_approx_eq(&l.data, &L);
+    }
+    // MatrixDxD
+    // --------------------------------------------------
+    fn l_dxd() -> MatrixDxD<f64> {
+        MatrixDxD::try_from((3, 3, L.to_vec())).unwrap()
+    }
+    #[test]
+    fn dxd_dxd() {
+        let b = MatrixDxD::try_from(B.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let c: MatrixDxD<f64> = l_dxd().cholesky_solve(&b);
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn dxd_dxs() {
+        let b = MatrixDxS::from(B.to_vec());
+        let c: MatrixDxS<f64, 2> = l_dxd().cholesky_solve(&b);
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn dxd_sxd() {
+        let b = MatrixSxD::try_from(B.map(|r| r.to_vec())).unwrap();
+        let c: MatrixSxD<f64, 3> = l_dxd().cholesky_solve(&b);
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn dxd_sxs() {
+        let b = MatrixSxS::from(B);
+        let c: MatrixSxS<f64, 3, 2> = l_dxd().cholesky_solve(&b);
+        assert_approx_eq(&c.data, &X);
+    }
+    // MatrixSxS
+    // --------------------------------------------------
+    fn l_sxs() -> MatrixSxS<f64, 3, 3> {
+        MatrixSxS::from(L)
+    }
+    #[test]
+    fn sxs_dxd() {
+        let b = MatrixDxD::try_from(B.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let c: MatrixSxD<f64, 3> = l_sxs().cholesky_solve(&b);
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn sxs_dxs() {
+        let b = MatrixDxS::from(B.to_vec());
+        let c: MatrixSxS<f64, 3, 2> = l_sxs().cholesky_solve(&b);
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn sxs_sxd() {
+        let b = MatrixSxD::try_from(B.map(|r| r.to_vec())).unwrap();
+        let c: MatrixSxD<f64, 3> = l_sxs().cholesky_solve(&b);
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn sxs_sxs() {
+        let b = MatrixSxS::from(B);
+        let c: MatrixSxS<f64, 3, 2> = l_sxs().cholesky_solve(&b);
+        assert_approx_eq(&c.data, &X);
+    }
+}
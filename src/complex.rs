@@ -0,0 +1,241 @@
+use crate::*;
+use num_complex::Complex;
+use num_traits::Num;
+use std::ops::Neg;
+
+// MatrixDxD
+// --------------------------------------------------
+impl<T: Clone + Num + Neg<Output = T>> MatrixDxD<Complex<T>> {
+    /// Returns the elementwise complex conjugate of `self`.
+    /// ```
+    /// use num_complex::Complex;
+    /// use static_la::MatrixDxD;
+    /// use std::convert::TryFrom;
+    /// let a = MatrixDxD::try_from(vec![vec![Complex::new(1, 2), Complex::new(3, -4)]]).unwrap();
+    /// let b = MatrixDxD::try_from(vec![vec![Complex::new(1, -2), Complex::new(3, 4)]]).unwrap();
+    /// assert_eq!(a.conj(), b);
+    /// ```
+    pub fn conj(&self) -> MatrixDxD<Complex<T>> {
+        MatrixDxD {
+            data: self.data.iter().map(Complex::conj).collect(),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+}
+impl<T: Clone> MatrixDxD<Complex<T>> {
+    /// Returns the elementwise real part of `self`.
+    /// ```
+    /// use num_complex::Complex;
+    /// use static_la::MatrixDxD;
+    /// use std::convert::TryFrom;
+    /// let a = MatrixDxD::try_from(vec![vec![Complex::new(1, 2), Complex::new(3, -4)]]).unwrap();
+    /// assert_eq!(a.re(), MatrixDxD::try_from(vec![vec![1, 3]]).unwrap());
+    /// ```
+    pub fn re(&self) -> MatrixDxD<T> {
+        MatrixDxD {
+            data: self.data.iter().map(|x| x.re.clone()).collect(),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+    /// Returns the elementwise imaginary part of `self`.
+    /// ```
+    /// use num_complex::Complex;
+    /// use static_la::MatrixDxD;
+    /// use std::convert::TryFrom;
+    /// let a = MatrixDxD::try_from(vec![vec![Complex::new(1, 2), Complex::new(3, -4)]]).unwrap();
+    /// assert_eq!(a.im(), MatrixDxD::try_from(vec![vec![2, -4]]).unwrap());
+    /// ```
+    pub fn im(&self) -> MatrixDxD<T> {
+        MatrixDxD {
+            data: self.data.iter().map(|x| x.im.clone()).collect(),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+}
+// MatrixDxS
+// --------------------------------------------------
+impl<T: Clone + Num + Neg<Output = T>, const COLUMNS: usize> MatrixDxS<Complex<T>, COLUMNS> {
+    /// Returns the elementwise complex conjugate of `self`.
+    pub fn conj(&self) -> MatrixDxS<Complex<T>, COLUMNS> {
+        MatrixDxS {
+            data: self.data.iter().map(Complex::conj).collect(),
+            rows: self.rows,
+            columns: Static,
+        }
+    }
+}
+impl<T: Clone, const COLUMNS: usize> MatrixDxS<Complex<T>, COLUMNS> {
+    /// Returns the elementwise real part of `self`.
+    pub fn re(&self) -> MatrixDxS<T, COLUMNS> {
+        MatrixDxS {
+            data: self.data.iter().map(|x| x.re.clone()).collect(),
+            rows: self.rows,
+            columns: Static,
+        }
+    }
+    /// Returns the elementwise imaginary part of `self`.
+    pub fn im(&self) -> MatrixDxS<T, COLUMNS> {
+        MatrixDxS {
+            data: self.data.iter().map(|x| x.im.clone()).collect(),
+            rows: self.rows,
+            columns: Static,
+        }
+    }
+}
+// MatrixSxD
+// --------------------------------------------------
+impl<T: Clone + Num + Neg<Output = T>, const ROWS: usize> MatrixSxD<Complex<T>, ROWS> {
+    /// Returns the elementwise complex conjugate of `self`.
+    pub fn conj(&self) -> MatrixSxD<Complex<T>, ROWS> {
+        MatrixSxD {
+            data: self.data.iter().map(Complex::conj).collect(),
+            columns: self.columns,
+            rows: Static,
+        }
+    }
+}
+impl<T: Clone, const ROWS: usize> MatrixSxD<Complex<T>, ROWS> {
+    /// Returns the elementwise real part of `self`.
+    pub fn re(&self) -> MatrixSxD<T, ROWS> {
+        MatrixSxD {
+            data: self.data.iter().map(|x| x.re.clone()).collect(),
+            columns: self.columns,
+            rows: Static,
+        }
+    }
+    /// Returns the elementwise imaginary part of `self`.
+    pub fn im(&self) -> MatrixSxD<T, ROWS> {
+        MatrixSxD {
+            data: self.data.iter().map(|x| x.im.clone()).collect(),
+            columns: self.columns,
+            rows: Static,
+        }
+    }
+}
+// MatrixSxS
+// --------------------------------------------------
+impl<T: Clone + Num + Neg<Output = T>, const ROWS: usize, const COLUMNS: usize>
+    MatrixSxS<Complex<T>, ROWS, COLUMNS>
+where
+    [(); ROWS * COLUMNS]:,
+{
+    /// Returns the elementwise complex conjugate of `self`.
+    /// ```
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use num_complex::Complex;
+    /// use static_la::MatrixSxS;
+    /// let a = MatrixSxS::from([[Complex::new(1, 2), Complex::new(3, -4)]]);
+    /// assert_eq!(a.conj(), MatrixSxS::from([[Complex::new(1, -2), Complex::new(3, 4)]]));
+    /// ```
+    pub fn conj(&self) -> MatrixSxS<Complex<T>, ROWS, COLUMNS> {
+        MatrixSxS {
+            data: self.data.each_ref().map(Complex::conj),
+            rows: Static,
+            columns: Static,
+        }
+    }
+}
+impl<T: Clone, const ROWS: usize, const COLUMNS: usize> MatrixSxS<Complex<T>, ROWS, COLUMNS>
+where
+    [(); ROWS * COLUMNS]:,
+{
+    /// Returns the elementwise real part of `self`.
+    pub fn re(&self) -> MatrixSxS<T, ROWS, COLUMNS> {
+        MatrixSxS {
+            data: self.data.each_ref().map(|x| x.re.clone()),
+            rows: Static,
+            columns: Static,
+        }
+    }
+    /// Returns the elementwise imaginary part of `self`.
+    pub fn im(&self) -> MatrixSxS<T, ROWS, COLUMNS> {
+        MatrixSxS {
+            data: self.data.each_ref().map(|x| x.im.clone()),
+            rows: Static,
+            columns: Static,
+        }
+    }
+}
+
+// Tests
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use num_complex::Complex;
+    use std::convert::TryFrom;
+
+    const A: [[Complex<i32>; 2]; 2] = [
+        [Complex::new(1, 2), Complex::new(3, -4)],
+        [Complex::new(-5, 0), Complex::new(0, 6)],
+    ];
+    const CONJ: [[Complex<i32>; 2]; 2] = [
+        [Complex::new(1, -2), Complex::new(3, 4)],
+        [Complex::new(-5, 0), Complex::new(0, -6)],
+    ];
+    const RE: [[i32; 2]; 2] = [[1, 3], [-5, 0]];
+    const IM: [[i32; 2]; 2] = [[2, -4], [0, 6]];
+
+    // MatrixDxD
+    // --------------------------------------------------
+    #[test]
+    fn dxd() {
+        let a = MatrixDxD::try_from(A.map(|r| r.to_vec()).to_vec()).unwrap();
+        assert_eq!(
+            a.conj(),
+            MatrixDxD::try_from(CONJ.map(|r| r.to_vec()).to_vec()).unwrap()
+        );
+        assert_eq!(
+            a.re(),
+            MatrixDxD::try_from(RE.map(|r| r.to_vec()).to_vec()).unwrap()
+        );
+        assert_eq!(
+            a.im(),
+            MatrixDxD::try_from(IM.map(|r| r.to_vec()).to_vec()).unwrap()
+        );
+    }
+    // MatrixDxS
+    // --------------------------------------------------
+    #[test]
+    fn dxs() {
+        let a = MatrixDxS::from(A.to_vec());
+        assert_eq!(a.conj(), MatrixDxS::from(CONJ.to_vec()));
+        assert_eq!(a.re(), MatrixDxS::from(RE.to_vec()));
+        assert_eq!(a.im(), MatrixDxS::from(IM.to_vec()));
+    }
+    // MatrixSxD
+    // --------------------------------------------------
+    #[test]
+    fn sxd() {
+        let a = MatrixSxD::try_from(A.map(|r| r.to_vec())).unwrap();
+        assert_eq!(
+            a.conj(),
+            MatrixSxD::try_from(CONJ.map(|r| r.to_vec())).unwrap()
+        );
+        assert_eq!(a.re(), MatrixSxD::try_from(RE.map(|r| r.to_vec())).unwrap());
+        assert_eq!(a.im(), MatrixSxD::try_from(IM.map(|r| r.to_vec())).unwrap());
+    }
+    // MatrixSxS
+    // --------------------------------------------------
+    #[test]
+    fn sxs() {
+        let a = MatrixSxS::from(A);
+        assert_eq!(a.conj(), MatrixSxS::from(CONJ));
+        assert_eq!(a.re(), MatrixSxS::from(RE));
+        assert_eq!(a.im(), MatrixSxS::from(IM));
+    }
+    #[test]
+    fn conj_transpose() {
+        let a = MatrixSxS::<_, 2, 2>::from(A);
+        let b = MatrixSxS::<_, 2, 2>::from(CONJ).transpose();
+        assert_eq!(a.conj_transpose(), b);
+        assert!(a
+            .conj_transpose_ref()
+            .iter()
+            .eq(b.to_layout::<RowMajor>().data.iter().cloned()));
+    }
+}
@@ -1,4 +1,133 @@
 use crate::*;
+use std::fmt::Debug;
+
+/// A dimension of a [`Matrix`], either [`Static`] or [`Dynamic`].
+pub trait Dim {
+    /// The value a matrix stores for this dimension, a zero-sized marker when static.
+    type Value: Copy + Debug + Eq;
+    /// The size of the dimension given its stored value.
+    fn size(value: Self::Value) -> usize;
+    /// The stored value for a dimension of `size`.
+    ///
+    /// Panics if `size` does not match a static dimension.
+    fn value(size: usize) -> Self::Value;
+}
+/// A dimension known at compile time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Static<const N: usize>;
+/// A dimension known only at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dynamic;
+impl<const N: usize> Dim for Static<N> {
+    type Value = Static<N>;
+    fn size(_: Static<N>) -> usize {
+        N
+    }
+    fn value(size: usize) -> Static<N> {
+        assert_eq!(size, N, "Non-matching static dimension");
+        Static
+    }
+}
+impl Dim for Dynamic {
+    type Value = usize;
+    fn size(value: usize) -> usize {
+        value
+    }
+    fn value(size: usize) -> usize {
+        size
+    }
+}
+
+/// Type-level join of two dimensions which must be equal, picking the more static of the two.
+///
+/// E.g. adding a `MatrixSxD<T, 2>` and a `MatrixDxS<T, 3>` gives a `MatrixSxS<T, 2, 3>`.
+pub trait Join<Other: Dim>: Dim {
+    type Output: Dim;
+}
+impl<const N: usize> Join<Static<N>> for Static<N> {
+    type Output = Static<N>;
+}
+impl<const N: usize> Join<Dynamic> for Static<N> {
+    type Output = Static<N>;
+}
+impl<const N: usize> Join<Static<N>> for Dynamic {
+    type Output = Static<N>;
+}
+impl Join<Dynamic> for Dynamic {
+    type Output = Dynamic;
+}
+
+/// Type-level sum of two dimensions, static only when both are.
+///
+/// E.g. adding the rows of a `MatrixDxS<T, 3>` to a `MatrixSxS<T, 2, 3>` gives a
+///  `MatrixDxS<T, 3>`.
+pub trait Concat<Other: Dim>: Dim {
+    type Output: Dim;
+}
+impl<const N: usize, const M: usize> Concat<Static<M>> for Static<N>
+where
+    [(); N + M]:,
+{
+    type Output = Static<{ N + M }>;
+}
+impl<const N: usize> Concat<Dynamic> for Static<N> {
+    type Output = Dynamic;
+}
+impl<const N: usize> Concat<Static<N>> for Dynamic {
+    type Output = Dynamic;
+}
+impl Concat<Dynamic> for Dynamic {
+    type Output = Dynamic;
+}
+
+/// The buffer of a matrix with `(rows, columns, layout)`.
+pub trait Storage<T> {
+    type Buffer: AsRef<[T]> + AsMut<[T]>;
+    /// Collects elements, in the order of the layout, into a buffer.
+    ///
+    /// Panics if there are too few elements to fill a static buffer.
+    fn collect<I: Iterator<Item = T>>(iter: I) -> Self::Buffer;
+}
+impl<T, L: Layout> Storage<T> for (Dynamic, Dynamic, L) {
+    type Buffer = Vec<T>;
+    fn collect<I: Iterator<Item = T>>(iter: I) -> Vec<T> {
+        iter.collect()
+    }
+}
+impl<T, L: Layout, const COLUMNS: usize> Storage<T> for (Dynamic, Static<COLUMNS>, L) {
+    type Buffer = Vec<T>;
+    fn collect<I: Iterator<Item = T>>(iter: I) -> Vec<T> {
+        iter.collect()
+    }
+}
+impl<T, L: Layout, const ROWS: usize> Storage<T> for (Static<ROWS>, Dynamic, L) {
+    type Buffer = Vec<T>;
+    fn collect<I: Iterator<Item = T>>(iter: I) -> Vec<T> {
+        iter.collect()
+    }
+}
+impl<T, const ROWS: usize, const COLUMNS: usize> Storage<T>
+    for (Static<ROWS>, Static<COLUMNS>, RowMajor)
+where
+    [(); ROWS * COLUMNS]:,
+{
+    type Buffer = [T; ROWS * COLUMNS];
+    fn collect<I: Iterator<Item = T>>(mut iter: I) -> [T; ROWS * COLUMNS] {
+        std::array::from_fn(|_| iter.next().expect("Too few elements"))
+    }
+}
+/// Sized `COLUMNS * ROWS` such that the buffer of the transpose of a row-major matrix is the same
+///  type.
+impl<T, const ROWS: usize, const COLUMNS: usize> Storage<T>
+    for (Static<ROWS>, Static<COLUMNS>, ColumnMajor)
+where
+    [(); COLUMNS * ROWS]:,
+{
+    type Buffer = [T; COLUMNS * ROWS];
+    fn collect<I: Iterator<Item = T>>(mut iter: I) -> [T; COLUMNS * ROWS] {
+        std::array::from_fn(|_| iter.next().expect("Too few elements"))
+    }
+}
 
 impl<T> MatrixDxD<T> {
     /// Number of elements in matrix.
@@ -59,3 +188,22 @@ where
         COLUMNS
     }
 }
+// ColumnMajor
+// --------------------------------------------------
+impl<T, R: Dim, C: Dim> Matrix<T, R, C, ColumnMajor>
+where
+    (R, C, ColumnMajor): Storage<T>,
+{
+    /// Number of elements in matrix.
+    pub fn len(&self) -> usize {
+        self.data.as_ref().len()
+    }
+    /// Number of rows.
+    pub fn rows(&self) -> usize {
+        R::size(self.rows)
+    }
+    /// Number of columns.
+    pub fn columns(&self) -> usize {
+        C::size(self.columns)
+    }
+}
@@ -181,3 +181,96 @@ impl<T: fmt::Display, const COLUMNS: usize> fmt::Display for MatrixDxS<T, COLUMN
         )
     }
 }
+impl<T: fmt::Display, R: Dim, C: Dim> fmt::Display for Matrix<T, R, C, ColumnMajor>
+where
+    (R, C, ColumnMajor): Storage<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (rows, columns) = self.shape();
+        let rows = (0..rows)
+            .map(|i| (0..columns).map(|j| self.get(i, j).to_string()).collect())
+            .collect();
+        boxed(f, rows)
+    }
+}
+/// Formats rows of element strings within a box.
+fn boxed(f: &mut fmt::Formatter<'_>, rows: Vec<Vec<String>>) -> fmt::Result {
+    // Maximum width of element string
+    let max_char_width = rows
+        .iter()
+        .flatten()
+        .map(|s| s.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut width = Default::default();
+    let mut middle = rows
+        .iter()
+        .map(|r| {
+            let t = format!(
+                "│ {}",
+                r.iter()
+                    .map(|s| format!("{}{} ", " ".repeat(max_char_width - s.chars().count()), s))
+                    .collect::<String>()
+            );
+            width = std::cmp::max(t.chars().count(), width);
+            t
+        })
+        .collect::<Vec<_>>();
+    for row in middle.iter_mut() {
+        row.push_str(&format!("{}│\n", " ".repeat(width - row.chars().count())));
+    }
+
+    let bar = " ".repeat(width - 1);
+    let top = format!("┌{}┐\n", bar);
+    let bottom = format!("└{}┘", bar);
+    write!(
+        f,
+        "\n{}{}{}",
+        top,
+        middle.into_iter().collect::<String>(),
+        bottom
+    )
+}
+impl<'a, T: fmt::Display> fmt::Display for ViewDxD<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = (0..self.rows())
+            .map(|i| {
+                (0..self.columns())
+                    .map(|j| self.get(i, j).to_string())
+                    .collect()
+            })
+            .collect();
+        boxed(f, rows)
+    }
+}
+impl<'a, T: fmt::Display, const COLUMNS: usize> fmt::Display for ViewDxS<'a, T, COLUMNS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = (0..self.rows())
+            .map(|i| (0..COLUMNS).map(|j| self.get(i, j).to_string()).collect())
+            .collect();
+        boxed(f, rows)
+    }
+}
+impl<'a, T: fmt::Display, const ROWS: usize> fmt::Display for ViewSxD<'a, T, ROWS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = (0..ROWS)
+            .map(|i| {
+                (0..self.columns())
+                    .map(|j| self.get(i, j).to_string())
+                    .collect()
+            })
+            .collect();
+        boxed(f, rows)
+    }
+}
+impl<'a, T: fmt::Display, const ROWS: usize, const COLUMNS: usize> fmt::Display
+    for ViewSxS<'a, T, ROWS, COLUMNS>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = (0..ROWS)
+            .map(|i| (0..COLUMNS).map(|j| self.get(i, j).to_string()).collect())
+            .collect();
+        boxed(f, rows)
+    }
+}
@@ -0,0 +1,377 @@
+use crate::*;
+use num_complex::Complex;
+use num_traits::Float;
+
+/// Internal general eigendecomposition trait.
+pub trait InternalEig: Sized {
+    /// Computes the eigenvalues of `a` into `values` and, if given, the matching right
+    ///  eigenvectors as the columns of `vectors`, overwriting `a`.
+    fn eig(
+        // An `n` by `n` row-major matrix.
+        a: &mut [Self],
+        // An `n` element vector.
+        values: &mut [Complex<Self>],
+        // An `n` by `n` row-major matrix.
+        vectors: Option<&mut [Complex<Self>]>,
+        // Rows and columns of `a`.
+        n: usize,
+    );
+}
+/// `f32` general eigendecomposition implementation.
+impl InternalEig for f32 {
+    fn eig(
+        a: &mut [f32],
+        values: &mut [Complex<f32>],
+        vectors: Option<&mut [Complex<f32>]>,
+        n: usize,
+    ) {
+        assert_eq!(a.len(), n * n);
+        assert_eq!(values.len(), n);
+        let (mut wr, mut wi) = (vec![Default::default(); n], vec![Default::default(); n]);
+        let (jobvr, mut vr) = match vectors {
+            Some(_) => (b'V', vec![Default::default(); n * n]),
+            None => (b'N', vec![Default::default(); 1]),
+        };
+        let info = unsafe {
+            lapacke::sgeev(
+                lapacke::Layout::RowMajor,
+                b'N',
+                jobvr,
+                n as i32,
+                a,
+                n as i32,
+                &mut wr,
+                &mut wi,
+                &mut [],
+                1,
+                &mut vr,
+                n.max(1) as i32,
+            )
+        };
+        assert!(info >= 0, "Illegal `sgeev` argument");
+        assert_eq!(info, 0, "Eigendecomposition failed to converge");
+        unpack(&wr, &wi, &vr, values, vectors, n);
+    }
+}
+/// `f64` general eigendecomposition implementation.
+impl InternalEig for f64 {
+    fn eig(
+        a: &mut [f64],
+        values: &mut [Complex<f64>],
+        vectors: Option<&mut [Complex<f64>]>,
+        n: usize,
+    ) {
+        assert_eq!(a.len(), n * n);
+        assert_eq!(values.len(), n);
+        let (mut wr, mut wi) = (vec![Default::default(); n], vec![Default::default(); n]);
+        let (jobvr, mut vr) = match vectors {
+            Some(_) => (b'V', vec![Default::default(); n * n]),
+            None => (b'N', vec![Default::default(); 1]),
+        };
+        let info = unsafe {
+            lapacke::dgeev(
+                lapacke::Layout::RowMajor,
+                b'N',
+                jobvr,
+                n as i32,
+                a,
+                n as i32,
+                &mut wr,
+                &mut wi,
+                &mut [],
+                1,
+                &mut vr,
+                n.max(1) as i32,
+            )
+        };
+        assert!(info >= 0, "Illegal `dgeev` argument");
+        assert_eq!(info, 0, "Eigendecomposition failed to converge");
+        unpack(&wr, &wi, &vr, values, vectors, n);
+    }
+}
+/// Combines the real and imaginary parts `geev` returns.
+///
+/// For a complex conjugate pair of eigenvalues, where `wi[j] > 0`, `vr` holds the real part of
+///  their eigenvector in column `j` and the imaginary part in column `j + 1`.
+fn unpack<T: Float>(
+    wr: &[T],
+    wi: &[T],
+    vr: &[T],
+    values: &mut [Complex<T>],
+    vectors: Option<&mut [Complex<T>]>,
+    n: usize,
+) {
+    for (value, (re, im)) in values.iter_mut().zip(wr.iter().zip(wi.iter())) {
+        *value = Complex::new(*re, *im);
+    }
+    if let Some(vectors) = vectors {
+        let mut j = 0;
+        while j < n {
+            if wi[j] == T::zero() {
+                for k in 0..n {
+                    vectors[k * n + j] = Complex::new(vr[k * n + j], T::zero());
+                }
+                j += 1;
+            } else {
+                for k in 0..n {
+                    let v = Complex::new(vr[k * n + j], vr[k * n + j + 1]);
+                    vectors[k * n + j] = v;
+                    vectors[k * n + j + 1] = v.conj();
+                }
+                j += 2;
+            }
+        }
+    }
+}
+
+/// An eigendecomposition of a general matrix such that `A·V = V·diag(values)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Eig<V, M> {
+    /// Eigenvalues, complex conjugate pairs appear consecutively with the positive imaginary
+    ///  part first.
+    pub values: V,
+    /// Right eigenvectors normalized to unit length, where column `i` corresponds to
+    ///  `values[i]`.
+    pub vectors: Option<M>,
+}
+
+// MatrixDxD
+// --------------------------------------------------
+impl<T: Float + InternalEig> MatrixDxD<T> {
+    /// Computes the eigenvalues and, if `vectors`, the right eigenvectors of `self`.
+    ///
+    /// Panics if `self` is not square.
+    /// ```
+    /// use num_complex::Complex;
+    /// use static_la::MatrixDxD;
+    /// use std::convert::TryFrom;
+    /// let a = MatrixDxD::try_from(vec![vec![0., -1.], vec![1., 0.]]).unwrap();
+    /// let eig = a.eig(false);
+    /// assert_eq!(eig.values[(0, 0)], Complex::new(0f64, 1.));
+    /// assert_eq!(eig.values[(1, 0)], Complex::new(0f64, -1.));
+    /// assert_eq!(eig.vectors, None);
+    /// ```
+    pub fn eig(&self, vectors: bool) -> Eig<ColumnVectorD<Complex<T>>, MatrixDxD<Complex<T>>> {
+        assert_eq!(self.rows, self.columns, "Non-square matrix");
+
+        let n = self.rows;
+        let mut a = self.data.clone();
+        let mut values = vec![Complex::new(T::zero(), T::zero()); n];
+        let mut data = vec![Complex::new(T::zero(), T::zero()); if vectors { n * n } else { 0 }];
+        InternalEig::eig(
+            &mut a,
+            &mut values,
+            Some(data.as_mut_slice()).filter(|_| vectors),
+            n,
+        );
+        Eig {
+            values: ColumnVectorD {
+                data: values,
+                rows: n,
+                columns: Static,
+            },
+            vectors: Some(MatrixDxD {
+                data,
+                rows: n,
+                columns: n,
+            })
+            .filter(|_| vectors),
+        }
+    }
+}
+// MatrixDxS
+// --------------------------------------------------
+impl<T: Float + InternalEig, const N: usize> MatrixDxS<T, N>
+where
+    [(); N * N]:,
+    [(); N * 1]:,
+{
+    /// Computes the eigenvalues and, if `vectors`, the right eigenvectors of `self`.
+    ///
+    /// Since `self` must be square the outputs are static.
+    pub fn eig(
+        &self,
+        vectors: bool,
+    ) -> Eig<ColumnVectorS<Complex<T>, N>, MatrixSxS<Complex<T>, N, N>> {
+        assert_eq!(self.rows, N, "Non-square matrix");
+
+        let mut data = [T::zero(); N * N];
+        data.copy_from_slice(&self.data);
+        eig_sxs(data, vectors)
+    }
+}
+// MatrixSxD
+// --------------------------------------------------
+impl<T: Float + InternalEig, const N: usize> MatrixSxD<T, N>
+where
+    [(); N * N]:,
+    [(); N * 1]:,
+{
+    /// Computes the eigenvalues and, if `vectors`, the right eigenvectors of `self`.
+    ///
+    /// Since `self` must be square the outputs are static.
+    pub fn eig(
+        &self,
+        vectors: bool,
+    ) -> Eig<ColumnVectorS<Complex<T>, N>, MatrixSxS<Complex<T>, N, N>> {
+        assert_eq!(self.columns, N, "Non-square matrix");
+
+        let mut data = [T::zero(); N * N];
+        data.copy_from_slice(&self.data);
+        eig_sxs(data, vectors)
+    }
+}
+// MatrixSxS
+// --------------------------------------------------
+impl<T: Float + InternalEig, const N: usize> MatrixSxS<T, N, N>
+where
+    [(); N * N]:,
+    [(); N * 1]:,
+{
+    /// Computes the eigenvalues and, if `vectors`, the right eigenvectors of `self`.
+    /// ```
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use static_la::MatrixSxS;
+    /// // A discrete linear system `x' = A·x` is stable if all eigenvalues lie in the unit circle.
+    /// let a = MatrixSxS::<f64, 2, 2>::from([[0.5, -0.5], [0.5, 0.5]]);
+    /// let eig = a.eig(true);
+    /// assert!((0..2).all(|i| eig.values[(i, 0)].norm() < 1.));
+    /// ```
+    pub fn eig(
+        &self,
+        vectors: bool,
+    ) -> Eig<ColumnVectorS<Complex<T>, N>, MatrixSxS<Complex<T>, N, N>> {
+        eig_sxs(self.data, vectors)
+    }
+}
+/// Computes the eigendecomposition of an `N` by `N` array into static matrices.
+fn eig_sxs<T: Float + InternalEig, const N: usize>(
+    mut a: [T; N * N],
+    vectors: bool,
+) -> Eig<ColumnVectorS<Complex<T>, N>, MatrixSxS<Complex<T>, N, N>>
+where
+    [(); N * N]:,
+    [(); N * 1]:,
+{
+    let mut values = [Complex::new(T::zero(), T::zero()); N * 1];
+    let mut data = [Complex::new(T::zero(), T::zero()); N * N];
+    InternalEig::eig(
+        &mut a,
+        &mut values,
+        Some(data.as_mut_slice()).filter(|_| vectors),
+        N,
+    );
+    Eig {
+        values: ColumnVectorS {
+            data: values,
+            rows: Static,
+            columns: Static,
+        },
+        vectors: Some(MatrixSxS {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+        .filter(|_| vectors),
+    }
+}
+
+// Tests
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use num_complex::Complex;
+    use std::convert::TryFrom;
+
+    /// Checks `A·v = λ·v` for each eigenpair.
+    fn check<T: num_traits::Float + std::fmt::Debug>(
+        a: &[T],
+        values: &[Complex<T>],
+        vectors: &[Complex<T>],
+        n: usize,
+    ) {
+        for (j, value) in values.iter().enumerate() {
+            for i in 0..n {
+                let av = (0..n).fold(Complex::new(T::zero(), T::zero()), |s, k| {
+                    s + vectors[k * n + j] * a[i * n + k]
+                });
+                let lv = vectors[i * n + j] * value;
+                assert_approx_eq(&[av.re, av.im], &[lv.re, lv.im]);
+            }
+        }
+    }
+    /// Eigenvalues `1`, `1+2i` and `1-2i`.
+    const A: [[f64; 3]; 3] = [[1., 0., 0.], [0., 1., -2.], [0., 2., 1.]];
+
+    // f32
+    // --------------------------------------------------
+    #[test]
+    fn f32() {
+        let a = MatrixSxS::<f32, 3, 3>::from(A.map(|r| r.map(|x| x as f32)));
+        let eig = a.eig(true);
+        check(&a.data, &eig.values.data, &eig.vectors.unwrap().data, 3);
+    }
+    // MatrixDxD
+    // --------------------------------------------------
+    #[test]
+    fn dxd() {
+        let a = MatrixDxD::try_from(A.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let eig = a.eig(true);
+        let values = eig
+            .values
+            .data
+            .iter()
+            .flat_map(|x| [x.re, x.im])
+            .collect::<Vec<_>>();
+        assert_approx_eq(&values, &[1., 0., 1., 2., 1., -2.]);
+        check(&a.data, &eig.values.data, &eig.vectors.unwrap().data, 3);
+    }
+    #[test]
+    fn dxd_values() {
+        let a = MatrixDxD::try_from(A.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let eig = a.eig(false);
+        assert_eq!(eig.values.rows(), 3);
+        assert_eq!(eig.vectors, None);
+    }
+    #[test]
+    #[should_panic(expected = "Non-square matrix")]
+    fn dxd_non_square() {
+        let a = MatrixDxD::try_from(vec![vec![1., 0.]]).unwrap();
+        let _ = a.eig(false);
+    }
+    // MatrixDxS
+    // --------------------------------------------------
+    #[test]
+    fn dxs() {
+        let a = MatrixDxS::from(A.to_vec());
+        let eig = a.eig(true);
+        let _: &Option<MatrixSxS<Complex<f64>, 3, 3>> = &eig.vectors;
+        check(&a.data, &eig.values.data, &eig.vectors.unwrap().data, 3);
+    }
+    // MatrixSxD
+    // --------------------------------------------------
+    #[test]
+    fn sxd() {
+        let a = MatrixSxD::try_from(A.map(|r| r.to_vec())).unwrap();
+        let eig = a.eig(true);
+        check(&a.data, &eig.values.data, &eig.vectors.unwrap().data, 3);
+    }
+    // MatrixSxS
+    // --------------------------------------------------
+    #[test]
+    fn sxs() {
+        let a = MatrixSxS::from(A);
+        let eig = a.eig(true);
+        let _: &ColumnVectorS<Complex<f64>, 3> = &eig.values;
+        check(&a.data, &eig.values.data, &eig.vectors.unwrap().data, 3);
+    }
+    #[test]
+    fn sxs_real() {
+        let a = MatrixSxS::<f64, 2, 2>::from([[2., 1.], [0., 3.]]);
+        let eig = a.eig(true);
+        assert!(eig.values.data.iter().all(|x| x.im == 0.));
+        check(&a.data, &eig.values.data, &eig.vectors.unwrap().data, 2);
+    }
+}
@@ -0,0 +1,309 @@
+use crate::*;
+use num_traits::Float;
+
+/// Internal symmetric eigendecomposition trait.
+pub trait InternalEigh: Sized {
+    /// Computes the eigenvalues of `a` in ascending order into `w`, overwriting `a` with the
+    ///  matching orthonormal eigenvectors as columns.
+    fn eigh(
+        // An `n` by `n` row-major symmetric matrix, only the lower triangle is read.
+        a: &mut [Self],
+        // An `n` element vector.
+        w: &mut [Self],
+        // Rows and columns of `a`.
+        n: usize,
+    );
+}
+/// Default symmetric eigendecomposition implementation (cyclic Jacobi).
+impl<T: Float> InternalEigh for T {
+    default fn eigh(a: &mut [T], w: &mut [T], n: usize) {
+        debug_assert_eq!(a.len(), n * n);
+        debug_assert_eq!(w.len(), n);
+
+        // Mirrors the lower triangle so only it is read, as with `syevd`.
+        for i in 0..n {
+            for j in i + 1..n {
+                a[i * n + j] = a[j * n + i];
+            }
+        }
+        let mut v = (0..n * n)
+            .map(|i| {
+                if i % (n + 1) == 0 {
+                    T::one()
+                } else {
+                    T::zero()
+                }
+            })
+            .collect::<Vec<_>>();
+        let two = T::one() + T::one();
+        let norm = a.iter().fold(T::zero(), |s, x| s + *x * *x);
+        for _ in 0..JACOBI_SWEEPS {
+            let off = (0..n * n)
+                .filter(|i| i % (n + 1) != 0)
+                .fold(T::zero(), |s, i| s + a[i] * a[i]);
+            if off <= T::epsilon() * T::epsilon() * norm {
+                break;
+            }
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[p * n + q] == T::zero() {
+                        continue;
+                    }
+                    // The rotation zeroing `a[p][q]`.
+                    let theta = (a[q * n + q] - a[p * n + p]) / (two * a[p * n + q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                    let c = T::one() / (t * t + T::one()).sqrt();
+                    let s = t * c;
+                    for k in 0..n {
+                        let (x, y) = (a[k * n + p], a[k * n + q]);
+                        a[k * n + p] = c * x - s * y;
+                        a[k * n + q] = s * x + c * y;
+                    }
+                    for k in 0..n {
+                        let (x, y) = (a[p * n + k], a[q * n + k]);
+                        a[p * n + k] = c * x - s * y;
+                        a[q * n + k] = s * x + c * y;
+                    }
+                    for k in 0..n {
+                        let (x, y) = (v[k * n + p], v[k * n + q]);
+                        v[k * n + p] = c * x - s * y;
+                        v[k * n + q] = s * x + c * y;
+                    }
+                }
+            }
+        }
+        // Sorts the eigenpairs by ascending eigenvalue.
+        let mut order = (0..n).collect::<Vec<_>>();
+        order.sort_by(|&i, &j| {
+            a[i * n + i]
+                .partial_cmp(&a[j * n + j])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        for (i, &o) in order.iter().enumerate() {
+            w[i] = a[o * n + o];
+        }
+        for k in 0..n {
+            for (i, &o) in order.iter().enumerate() {
+                a[k * n + i] = v[k * n + o];
+            }
+        }
+    }
+}
+/// Maximum number of Jacobi sweeps, convergence is quadratic so this is rarely approached.
+const JACOBI_SWEEPS: usize = 64;
+/// `f32` symmetric eigendecomposition specialization.
+#[cfg(feature = "lapacke")]
+impl InternalEigh for f32 {
+    fn eigh(a: &mut [f32], w: &mut [f32], n: usize) {
+        assert_eq!(a.len(), n * n);
+        assert_eq!(w.len(), n);
+        let info = unsafe {
+            lapacke::ssyevd(
+                lapacke::Layout::RowMajor,
+                b'V',
+                b'L',
+                n as i32,
+                a,
+                n as i32,
+                w,
+            )
+        };
+        assert!(info >= 0, "Illegal `ssyevd` argument");
+        assert_eq!(info, 0, "Eigendecomposition failed to converge");
+    }
+}
+/// `f64` symmetric eigendecomposition specialization.
+#[cfg(feature = "lapacke")]
+impl InternalEigh for f64 {
+    fn eigh(a: &mut [f64], w: &mut [f64], n: usize) {
+        assert_eq!(a.len(), n * n);
+        assert_eq!(w.len(), n);
+        let info = unsafe {
+            lapacke::dsyevd(
+                lapacke::Layout::RowMajor,
+                b'V',
+                b'L',
+                n as i32,
+                a,
+                n as i32,
+                w,
+            )
+        };
+        assert!(info >= 0, "Illegal `dsyevd` argument");
+        assert_eq!(info, 0, "Eigendecomposition failed to converge");
+    }
+}
+
+/// An eigendecomposition of a symmetric matrix such that `A = V·diag(values)·Vᵀ`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Eigh<V, M> {
+    /// Eigenvalues in ascending order.
+    pub values: V,
+    /// Orthonormal eigenvectors, where column `i` corresponds to `values[i]`.
+    pub vectors: M,
+}
+
+// MatrixDxD
+// --------------------------------------------------
+impl<T: Float> MatrixDxD<T> {
+    /// Computes the eigenvalues and eigenvectors of the symmetric matrix `self`.
+    ///
+    /// Only the lower triangle of `self` is read. Panics if `self` is not square.
+    /// ```
+    /// use static_la::MatrixDxD;
+    /// use std::convert::TryFrom;
+    /// let a = MatrixDxD::try_from(vec![vec![2., 1.], vec![1., 2.]]).unwrap();
+    /// let eigh = a.eigh();
+    /// assert!((eigh.values[(0, 0)] - 1f64).abs() < 1e-12);
+    /// assert!((eigh.values[(1, 0)] - 3f64).abs() < 1e-12);
+    /// ```
+    pub fn eigh(&self) -> Eigh<ColumnVectorD<T>, MatrixDxD<T>> {
+        assert_eq!(self.rows, self.columns, "Non-square matrix");
+
+        let n = self.rows;
+        let mut data = self.data.clone();
+        let mut values = vec![T::zero(); n];
+        InternalEigh::eigh(&mut data, &mut values, n);
+        Eigh {
+            values: ColumnVectorD {
+                data: values,
+                rows: n,
+                columns: Static,
+            },
+            vectors: MatrixDxD {
+                data,
+                rows: n,
+                columns: n,
+            },
+        }
+    }
+}
+// MatrixSxS
+// --------------------------------------------------
+impl<T: Float, const N: usize> MatrixSxS<T, N, N>
+where
+    [(); N * N]:,
+    [(); N * 1]:,
+{
+    /// Computes the eigenvalues and eigenvectors of the symmetric matrix `self`.
+    ///
+    /// Only the lower triangle of `self` is read.
+    /// ```
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use static_la::MatrixSxS;
+    /// let a = MatrixSxS::<f64, 2, 2>::from([[2., 1.], [1., 2.]]);
+    /// // `Eigh<ColumnVectorS<f64, 2>, MatrixSxS<f64, 2, 2>>`
+    /// let eigh = a.eigh();
+    /// assert!((eigh.values[(0, 0)] - 1.).abs() < 1e-12);
+    /// assert!((eigh.values[(1, 0)] - 3.).abs() < 1e-12);
+    /// ```
+    pub fn eigh(&self) -> Eigh<ColumnVectorS<T, N>, MatrixSxS<T, N, N>> {
+        let mut data = self.data;
+        let mut values = [T::zero(); N * 1];
+        InternalEigh::eigh(&mut data, &mut values, N);
+        Eigh {
+            values: ColumnVectorS {
+                data: values,
+                rows: Static,
+                columns: Static,
+            },
+            vectors: MatrixSxS {
+                data,
+                rows: Static,
+                columns: Static,
+            },
+        }
+    }
+}
+
+// Tests
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::convert::TryFrom;
+
+    /// Checks `A·V = V·diag(w)`, `Vᵀ·V = I` and that `w` is ascending.
+    fn check<T: num_traits::Float + std::fmt::Debug>(a: &[T], w: &[T], v: &[T], n: usize) {
+        let mut av = vec![T::zero(); n * n];
+        let mut vw = vec![T::zero(); n * n];
+        let mut vtv = vec![T::zero(); n * n];
+        for i in 0..n {
+            for j in 0..n {
+                av[i * n + j] = (0..n).fold(T::zero(), |s, k| s + a[i * n + k] * v[k * n + j]);
+                vw[i * n + j] = v[i * n + j] * w[j];
+                vtv[i * n + j] = (0..n).fold(T::zero(), |s, k| s + v[k * n + i] * v[k * n + j]);
+            }
+        }
+        let identity = (0..n * n)
+            .map(|i| {
+                if i % (n + 1) == 0 {
+                    T::one()
+                } else {
+                    T::zero()
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_approx_eq(&av, &vw);
+        assert_approx_eq(&vtv, &identity);
+        assert!(w.windows(2).all(|x| x[0] <= x[1]));
+    }
+    /// Eigenvalues `2-√2`, `2` and `2+√2`.
+    const A: [[f64; 3]; 3] = [[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]];
+    fn values() -> [f64; 3] {
+        [2. - 2f64.sqrt(), 2., 2. + 2f64.sqrt()]
+    }
+
+    // f32
+    // --------------------------------------------------
+    #[test]
+    fn f32() {
+        let a = MatrixSxS::<f32, 3, 3>::from(A.map(|r| r.map(|x| x as f32)));
+        let eigh = a.eigh();
+        check(&a.data, &eigh.values.data, &eigh.vectors.data, 3);
+        assert_approx_eq(&eigh.values.data, &values().map(|x| x as f32));
+    }
+    // f16
+    // --------------------------------------------------
+    #[test]
+    fn f16() {
+        let f = half::f16::from_f64;
+        let a = MatrixSxS::<half::f16, 3, 3>::from(A.map(|r| r.map(f)));
+        let eigh = a.eigh();
+        check(&a.data, &eigh.values.data, &eigh.vectors.data, 3);
+        assert_approx_eq(&eigh.values.data, &values().map(f));
+    }
+    // MatrixDxD
+    // --------------------------------------------------
+    #[test]
+    fn dxd() {
+        let a = MatrixDxD::try_from(A.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let eigh = a.eigh();
+        assert_eq!(eigh.values.rows(), 3);
+        check(&a.data, &eigh.values.data, &eigh.vectors.data, 3);
+        assert_approx_eq(&eigh.values.data, &values());
+    }
+    #[test]
+    #[should_panic(expected = "Non-square matrix")]
+    fn dxd_non_square() {
+        let a = MatrixDxD::try_from(vec![vec![1., 0.]]).unwrap();
+        let _ = a.eigh();
+    }
+    // MatrixSxS
+    // --------------------------------------------------
+    #[test]
+    fn sxs() {
+        let a = MatrixSxS::from(A);
+        let eigh = a.eigh();
+        check(&a.data, &eigh.values.data, &eigh.vectors.data, 3);
+        assert_approx_eq(&eigh.values.data, &values());
+    }
+    #[test]
+    fn sxs_lower() {
+        // Only the lower triangle is read.
+        let a = MatrixSxS::<f64, 2, 2>::from([[2., 0.], [1., 2.]]);
+        let eigh = a.eigh();
+        assert_approx_eq(&eigh.values.data, &[1., 3.]);
+    }
+}
@@ -27,6 +27,8 @@ where
                 .collect::<Vec<_>>()
                 .try_into()
                 .unwrap(),
+            rows: Static,
+            columns: Static,
         }
     }
 }
@@ -37,6 +39,8 @@ where
     fn from(x: T) -> Self {
         Self {
             data: [x; ROWS * COLUMNS],
+            rows: Static,
+            columns: Static,
         }
     }
 }
@@ -46,14 +50,22 @@ where
     [(); ROWS * COLUMNS]:,
 {
     fn from(data: [T; ROWS * COLUMNS]) -> Self {
-        Self { data }
+        Self {
+            data,
+            rows: Static,
+            columns: Static,
+        }
     }
 }
 impl<T: Clone, const ROWS: usize, const COLUMNS: usize> From<&[T; ROWS * COLUMNS]>
     for MatrixSxS<T, ROWS, COLUMNS>
 {
     fn from(data: &[T; ROWS * COLUMNS]) -> Self {
-        Self { data: data.clone() }
+        Self {
+            data: data.clone(),
+            rows: Static,
+            columns: Static,
+        }
     }
 }
 // MatrixDxS
@@ -64,6 +76,7 @@ impl<T, const COLUMNS: usize> From<Vec<[T; COLUMNS]>> for MatrixDxS<T, COLUMNS>
         Self {
             data: data.into_iter().flatten().collect::<Vec<_>>(),
             rows: len,
+            columns: Static,
         }
     }
 }
@@ -72,6 +85,7 @@ impl<T: Copy, const COLUMNS: usize> From<(usize, T)> for MatrixDxS<T, COLUMNS> {
         Self {
             data: vec![x; rows * COLUMNS],
             rows,
+            columns: Static,
         }
     }
 }
@@ -82,6 +96,7 @@ impl<T: Copy, const ROWS: usize> From<(usize, T)> for MatrixSxD<T, ROWS> {
         Self {
             data: vec![x; ROWS * columns],
             columns,
+            rows: Static,
         }
     }
 }
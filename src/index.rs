@@ -77,6 +77,144 @@ where
         &mut self.data[row * COLUMNS + column]
     }
 }
+// ColumnMajor
+// --------------------------------------------------
+impl<T, R: Dim, C: Dim> Index<Pair> for Matrix<T, R, C, ColumnMajor>
+where
+    (R, C, ColumnMajor): Storage<T>,
+{
+    type Output = T;
+    fn index<'a>(&'a self, (row, column): (usize, usize)) -> &'a Self::Output {
+        let (rows, columns) = self.shape();
+        assert!(row < rows, "Row out of bounds");
+        assert!(column < columns, "Columns out of bounds");
+        &self.data.as_ref()[column * rows + row]
+    }
+}
+impl<T, R: Dim, C: Dim> IndexMut<Pair> for Matrix<T, R, C, ColumnMajor>
+where
+    (R, C, ColumnMajor): Storage<T>,
+{
+    fn index_mut<'a>(&'a mut self, (row, column): (usize, usize)) -> &'a mut T {
+        let (rows, columns) = self.shape();
+        assert!(row < rows, "Row out of bounds");
+        assert!(column < columns, "Columns out of bounds");
+        &mut self.data.as_mut()[column * rows + row]
+    }
+}
+// ViewDxD
+// --------------------------------------------------
+impl<'a, T> Index<Pair> for ViewDxD<'a, T> {
+    type Output = T;
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        assert!(row < self.rows(), "Row out of bounds");
+        assert!(column < self.columns(), "Columns out of bounds");
+        self.get(row, column)
+    }
+}
+// ViewDxS
+// --------------------------------------------------
+impl<'a, T, const COLUMNS: usize> Index<Pair> for ViewDxS<'a, T, COLUMNS> {
+    type Output = T;
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        assert!(row < self.rows(), "Row out of bounds");
+        assert!(column < COLUMNS, "Columns out of bounds");
+        self.get(row, column)
+    }
+}
+// ViewSxD
+// --------------------------------------------------
+impl<'a, T, const ROWS: usize> Index<Pair> for ViewSxD<'a, T, ROWS> {
+    type Output = T;
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        assert!(row < ROWS, "Row out of bounds");
+        assert!(column < self.columns(), "Columns out of bounds");
+        self.get(row, column)
+    }
+}
+// ViewSxS
+// --------------------------------------------------
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> Index<Pair> for ViewSxS<'a, T, ROWS, COLUMNS> {
+    type Output = T;
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        assert!(row < ROWS, "Row out of bounds");
+        assert!(column < COLUMNS, "Columns out of bounds");
+        self.get(row, column)
+    }
+}
+
+// ViewDxDMut
+// --------------------------------------------------
+impl<'a, T> Index<Pair> for ViewDxDMut<'a, T> {
+    type Output = T;
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        assert!(row < self.rows(), "Row out of bounds");
+        assert!(column < self.columns(), "Columns out of bounds");
+        &self.data[row * self.row_stride + column * self.column_stride]
+    }
+}
+impl<'a, T> IndexMut<Pair> for ViewDxDMut<'a, T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        assert!(row < self.rows(), "Row out of bounds");
+        assert!(column < self.columns(), "Columns out of bounds");
+        self.get_mut(row, column)
+    }
+}
+// ViewDxSMut
+// --------------------------------------------------
+impl<'a, T, const COLUMNS: usize> Index<Pair> for ViewDxSMut<'a, T, COLUMNS> {
+    type Output = T;
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        assert!(row < self.rows(), "Row out of bounds");
+        assert!(column < COLUMNS, "Columns out of bounds");
+        &self.data[row * self.row_stride + column * self.column_stride]
+    }
+}
+impl<'a, T, const COLUMNS: usize> IndexMut<Pair> for ViewDxSMut<'a, T, COLUMNS> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        assert!(row < self.rows(), "Row out of bounds");
+        assert!(column < COLUMNS, "Columns out of bounds");
+        self.get_mut(row, column)
+    }
+}
+// ViewSxDMut
+// --------------------------------------------------
+impl<'a, T, const ROWS: usize> Index<Pair> for ViewSxDMut<'a, T, ROWS> {
+    type Output = T;
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        assert!(row < ROWS, "Row out of bounds");
+        assert!(column < self.columns(), "Columns out of bounds");
+        &self.data[row * self.row_stride + column * self.column_stride]
+    }
+}
+impl<'a, T, const ROWS: usize> IndexMut<Pair> for ViewSxDMut<'a, T, ROWS> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        assert!(row < ROWS, "Row out of bounds");
+        assert!(column < self.columns(), "Columns out of bounds");
+        self.get_mut(row, column)
+    }
+}
+// ViewSxSMut
+// --------------------------------------------------
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> Index<Pair>
+    for ViewSxSMut<'a, T, ROWS, COLUMNS>
+{
+    type Output = T;
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        assert!(row < ROWS, "Row out of bounds");
+        assert!(column < COLUMNS, "Columns out of bounds");
+        &self.data[row * self.row_stride + column * self.column_stride]
+    }
+}
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> IndexMut<Pair>
+    for ViewSxSMut<'a, T, ROWS, COLUMNS>
+{
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        assert!(row < ROWS, "Row out of bounds");
+        assert!(column < COLUMNS, "Columns out of bounds");
+        self.get_mut(row, column)
+    }
+}
 // Tests
 // --------------------------------------------------
 #[cfg(test)]
@@ -131,4 +269,41 @@ mod tests {
         assert_eq!(a[(1, 1)], 5);
         assert_eq!(a[(1, 2)], 6);
     }
+    // Views
+    // --------------------------------------------------
+    #[test]
+    fn views() {
+        let a = MatrixSxS::<i32, 3, 4>::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+        let b = a.slice_dxd((1..2, 1..3));
+        assert_eq!(b[(0, 0)], 6);
+        assert_eq!(b[(0, 1)], 7);
+        let c = a.slice_sxs::<{ 0..2 }, { 2..3 }>();
+        assert_eq!(c[(0, 0)], 3);
+        assert_eq!(c[(1, 0)], 7);
+    }
+    #[test]
+    fn views_mut() {
+        let mut a = MatrixSxS::<i32, 3, 4>::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+        let mut b = a.slice_sxd_mut::<{ 1..2 }>(1..3);
+        b[(0, 1)] = 0;
+        assert_eq!(b[(0, 0)], 6);
+        assert_eq!(b[(0, 1)], 0);
+        assert_eq!(a[(1, 2)], 0);
+    }
+    // ColumnMajor
+    // --------------------------------------------------
+    #[test]
+    fn column_major() {
+        let mut a = MatrixDxS::from(vec![[1, 2, 3], [4, 5, 6]]).to_layout::<ColumnMajor>();
+        assert_eq!(a[(0, 2)], 3);
+        assert_eq!(a[(1, 0)], 4);
+        a[(1, 2)] = 7;
+        assert_eq!(a.as_slice(), &[1, 4, 2, 5, 3, 7]);
+    }
+    #[test]
+    #[should_panic(expected = "Row out of bounds")]
+    fn column_major_row_out_of_bounds() {
+        let a = MatrixSxS::from([[1, 2, 3], [4, 5, 6]]).transpose();
+        let _ = a[(3, 0)];
+    }
 }
@@ -0,0 +1,392 @@
+use crate::lu::InternalLu;
+use crate::solve::InternalSolve;
+use crate::*;
+use num_traits::Float;
+
+/// Computes the determinant of an `n` by `n` row-major matrix.
+///
+/// Matrices up to 4x4 use closed-form expressions, larger matrices use an LU factorization.
+fn determinant<T: Float>(a: &[T], n: usize) -> T {
+    debug_assert_eq!(a.len(), n * n);
+    match n {
+        0 => T::one(),
+        1 => a[0],
+        2 => a[0] * a[3] - a[1] * a[2],
+        3 => {
+            a[0] * (a[4] * a[8] - a[5] * a[7]) - a[1] * (a[3] * a[8] - a[5] * a[6])
+                + a[2] * (a[3] * a[7] - a[4] * a[6])
+        }
+        4 => {
+            let (s, c) = minors_4x4(a);
+            s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+        }
+        _ => {
+            let mut lu = Vec::from(a);
+            let mut permutation = vec![0; n];
+            InternalLu::lu(&mut lu, &mut permutation, n);
+            let mut determinant = (0..n).fold(T::one(), |d, i| d * lu[i * n + i]);
+            // Every cycle of even length in the permutation is an odd number of row swaps.
+            let mut visited = vec![false; n];
+            for i in 0..n {
+                let mut length = 0;
+                let mut j = i;
+                while !visited[j] {
+                    visited[j] = true;
+                    j = permutation[j];
+                    length += 1;
+                }
+                if length % 2 == 0 && length > 0 {
+                    determinant = -determinant;
+                }
+            }
+            determinant
+        }
+    }
+}
+/// Computes the inverse of an `n` by `n` row-major matrix `a` into `b`.
+///
+/// Matrices up to 4x4 use closed-form expressions, larger matrices use an LU factorization.
+fn inverse<T: Float>(a: &[T], b: &mut [T], n: usize) -> Result<(), &'static str> {
+    debug_assert_eq!(a.len(), n * n);
+    debug_assert_eq!(b.len(), n * n);
+    let determinant = match n {
+        0 => return Ok(()),
+        1 => {
+            b[0] = T::one();
+            a[0]
+        }
+        2 => {
+            b.copy_from_slice(&[a[3], -a[1], -a[2], a[0]]);
+            a[0] * a[3] - a[1] * a[2]
+        }
+        3 => {
+            b.copy_from_slice(&[
+                a[4] * a[8] - a[5] * a[7],
+                a[2] * a[7] - a[1] * a[8],
+                a[1] * a[5] - a[2] * a[4],
+                a[5] * a[6] - a[3] * a[8],
+                a[0] * a[8] - a[2] * a[6],
+                a[2] * a[3] - a[0] * a[5],
+                a[3] * a[7] - a[4] * a[6],
+                a[1] * a[6] - a[0] * a[7],
+                a[0] * a[4] - a[1] * a[3],
+            ]);
+            a[0] * b[0] + a[1] * b[3] + a[2] * b[6]
+        }
+        4 => {
+            let (s, c) = minors_4x4(a);
+            b.copy_from_slice(&[
+                a[5] * c[5] - a[6] * c[4] + a[7] * c[3],
+                -a[1] * c[5] + a[2] * c[4] - a[3] * c[3],
+                a[13] * s[5] - a[14] * s[4] + a[15] * s[3],
+                -a[9] * s[5] + a[10] * s[4] - a[11] * s[3],
+                -a[4] * c[5] + a[6] * c[2] - a[7] * c[1],
+                a[0] * c[5] - a[2] * c[2] + a[3] * c[1],
+                -a[12] * s[5] + a[14] * s[2] - a[15] * s[1],
+                a[8] * s[5] - a[10] * s[2] + a[11] * s[1],
+                a[4] * c[4] - a[5] * c[2] + a[7] * c[0],
+                -a[0] * c[4] + a[1] * c[2] - a[3] * c[0],
+                a[12] * s[4] - a[13] * s[2] + a[15] * s[0],
+                -a[8] * s[4] + a[9] * s[2] - a[11] * s[0],
+                -a[4] * c[3] + a[5] * c[1] - a[6] * c[0],
+                a[0] * c[3] - a[1] * c[1] + a[2] * c[0],
+                -a[12] * s[3] + a[13] * s[1] - a[14] * s[0],
+                a[8] * s[3] - a[9] * s[1] + a[10] * s[0],
+            ]);
+            s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+        }
+        _ => {
+            for (i, x) in b.iter_mut().enumerate() {
+                *x = if i % (n + 1) == 0 {
+                    T::one()
+                } else {
+                    T::zero()
+                };
+            }
+            let mut lu = Vec::from(a);
+            return InternalSolve::solve(&mut lu, b, n, n);
+        }
+    };
+    if determinant == T::zero() {
+        return Err("Singular matrix");
+    }
+    for x in b.iter_mut() {
+        *x = *x / determinant;
+    }
+    Ok(())
+}
+/// The 2x2 minors of the top 2 rows (`s`) and bottom 2 rows (`c`) of a 4x4 row-major matrix.
+fn minors_4x4<T: Float>(a: &[T]) -> ([T; 6], [T; 6]) {
+    (
+        [
+            a[0] * a[5] - a[4] * a[1],
+            a[0] * a[6] - a[4] * a[2],
+            a[0] * a[7] - a[4] * a[3],
+            a[1] * a[6] - a[5] * a[2],
+            a[1] * a[7] - a[5] * a[3],
+            a[2] * a[7] - a[6] * a[3],
+        ],
+        [
+            a[8] * a[13] - a[12] * a[9],
+            a[8] * a[14] - a[12] * a[10],
+            a[8] * a[15] - a[12] * a[11],
+            a[9] * a[14] - a[13] * a[10],
+            a[9] * a[15] - a[13] * a[11],
+            a[10] * a[15] - a[14] * a[11],
+        ],
+    )
+}
+
+// MatrixDxD
+// --------------------------------------------------
+impl<T: Float> MatrixDxD<T> {
+    /// Computes the determinant of `self`, returning an error if `self` is not square.
+    /// ```
+    /// use static_la::MatrixDxD;
+    /// use std::convert::TryFrom;
+    /// let a = MatrixDxD::try_from(vec![vec![4., 7.], vec![2., 6.]]).unwrap();
+    /// assert_eq!(a.determinant(), Ok(10.));
+    /// ```
+    pub fn determinant(&self) -> Result<T, &'static str> {
+        if self.rows != self.columns {
+            return Err("Non-square matrix");
+        }
+        Ok(determinant(&self.data, self.rows))
+    }
+    /// Computes the inverse of `self`, returning an error if `self` is not square or is singular.
+    /// ```
+    /// use static_la::MatrixDxD;
+    /// use std::convert::TryFrom;
+    /// let a = MatrixDxD::try_from(vec![vec![4., 7.], vec![2., 6.]]).unwrap();
+    /// let b = MatrixDxD::try_from(vec![vec![0.6, -0.7], vec![-0.2, 0.4]]).unwrap();
+    /// assert_eq!(a.inverse(), Ok(b));
+    /// ```
+    pub fn inverse(&self) -> Result<MatrixDxD<T>, &'static str> {
+        if self.rows != self.columns {
+            return Err("Non-square matrix");
+        }
+        let mut data = vec![T::zero(); self.data.len()];
+        inverse(&self.data, &mut data, self.rows)?;
+        Ok(MatrixDxD {
+            data,
+            rows: self.rows,
+            columns: self.columns,
+        })
+    }
+}
+// MatrixDxS
+// --------------------------------------------------
+impl<T: Float, const N: usize> MatrixDxS<T, N>
+where
+    [(); N * N]:,
+{
+    /// Computes the determinant of `self`, returning an error if `self` is not square.
+    pub fn determinant(&self) -> Result<T, &'static str> {
+        if self.rows != N {
+            return Err("Non-square matrix");
+        }
+        Ok(determinant(&self.data, N))
+    }
+    /// Computes the inverse of `self`, returning an error if `self` is not square or is singular.
+    ///
+    /// Since `self` must be square the inverse is an `N` by `N` static matrix.
+    pub fn inverse(&self) -> Result<MatrixSxS<T, N, N>, &'static str> {
+        if self.rows != N {
+            return Err("Non-square matrix");
+        }
+        let mut data = [T::zero(); N * N];
+        inverse(&self.data, &mut data, N)?;
+        Ok(MatrixSxS {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+// MatrixSxD
+// --------------------------------------------------
+impl<T: Float, const N: usize> MatrixSxD<T, N>
+where
+    [(); N * N]:,
+{
+    /// Computes the determinant of `self`, returning an error if `self` is not square.
+    pub fn determinant(&self) -> Result<T, &'static str> {
+        if self.columns != N {
+            return Err("Non-square matrix");
+        }
+        Ok(determinant(&self.data, N))
+    }
+    /// Computes the inverse of `self`, returning an error if `self` is not square or is singular.
+    ///
+    /// Since `self` must be square the inverse is an `N` by `N` static matrix.
+    pub fn inverse(&self) -> Result<MatrixSxS<T, N, N>, &'static str> {
+        if self.columns != N {
+            return Err("Non-square matrix");
+        }
+        let mut data = [T::zero(); N * N];
+        inverse(&self.data, &mut data, N)?;
+        Ok(MatrixSxS {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+// MatrixSxS
+// --------------------------------------------------
+impl<T: Float, const N: usize> MatrixSxS<T, N, N>
+where
+    [(); N * N]:,
+{
+    /// Computes the determinant of `self`.
+    /// ```
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use static_la::MatrixSxS;
+    /// let a = MatrixSxS::<f64, 2, 2>::from([[4., 7.], [2., 6.]]);
+    /// assert_eq!(a.determinant(), 10.);
+    /// ```
+    pub fn determinant(&self) -> T {
+        determinant(&self.data, N)
+    }
+    /// Computes the inverse of `self`, returning an error if `self` is singular.
+    /// ```
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use static_la::MatrixSxS;
+    /// let a = MatrixSxS::<f64, 2, 2>::from([[4., 7.], [2., 6.]]);
+    /// let b = MatrixSxS::<f64, 2, 2>::from([[0.6, -0.7], [-0.2, 0.4]]);
+    /// assert_eq!(a.inverse(), Ok(b));
+    /// ```
+    pub fn inverse(&self) -> Result<MatrixSxS<T, N, N>, &'static str> {
+        let mut data = [T::zero(); N * N];
+        inverse(&self.data, &mut data, N)?;
+        Ok(MatrixSxS {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+
+// Tests
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::convert::TryFrom;
+
+    /// Checks `a·b` is the identity.
+    fn check<const N: usize>(a: &MatrixSxS<f64, N, N>, b: &MatrixSxS<f64, N, N>)
+    where
+        [(); N * N]:,
+    {
+        let identity = (0..N * N)
+            .map(|i| if i % (N + 1) == 0 { 1. } else { 0. })
+            .collect::<Vec<_>>();
+        assert_approx_eq(&a.matmul(b).data, &identity);
+    }
+    // MatrixDxD
+    // --------------------------------------------------
+    #[test]
+    fn dxd() {
+        let a = MatrixDxD::try_from(vec![vec![4., 7.], vec![2., 6.]]).unwrap();
+        assert_eq!(a.determinant(), Ok(10.));
+        let b = MatrixDxD::try_from(vec![vec![0.6, -0.7], vec![-0.2, 0.4]]).unwrap();
+        assert_eq!(a.inverse(), Ok(b));
+    }
+    #[test]
+    fn dxd_non_square() {
+        let a = MatrixDxD::try_from(vec![vec![4., 7., 1.], vec![2., 6., 1.]]).unwrap();
+        assert_eq!(a.determinant(), Err("Non-square matrix"));
+        assert_eq!(a.inverse(), Err("Non-square matrix"));
+    }
+    // MatrixDxS
+    // --------------------------------------------------
+    #[test]
+    fn dxs() {
+        let a = MatrixDxS::from(vec![[4., 7.], [2., 6.]]);
+        assert_eq!(a.determinant(), Ok(10.));
+        let b = MatrixSxS::from([[0.6, -0.7], [-0.2, 0.4]]);
+        assert_eq!(a.inverse(), Ok(b));
+    }
+    #[test]
+    fn dxs_non_square() {
+        let a = MatrixDxS::from(vec![[4., 7.]]);
+        assert_eq!(a.determinant(), Err("Non-square matrix"));
+        assert_eq!(a.inverse(), Err("Non-square matrix"));
+    }
+    // MatrixSxD
+    // --------------------------------------------------
+    #[test]
+    fn sxd() {
+        let a = MatrixSxD::try_from([vec![4., 7.], vec![2., 6.]]).unwrap();
+        assert_eq!(a.determinant(), Ok(10.));
+        let b = MatrixSxS::from([[0.6, -0.7], [-0.2, 0.4]]);
+        assert_eq!(a.inverse(), Ok(b));
+    }
+    #[test]
+    fn sxd_non_square() {
+        let a = MatrixSxD::try_from([vec![4.], vec![2.]]).unwrap();
+        assert_eq!(a.determinant(), Err("Non-square matrix"));
+        assert_eq!(a.inverse(), Err("Non-square matrix"));
+    }
+    // MatrixSxS
+    // --------------------------------------------------
+    #[test]
+    fn sxs_1x1() {
+        let a = MatrixSxS::<f64, 1, 1>::from([[4.]]);
+        assert_eq!(a.determinant(), 4.);
+        assert_eq!(a.inverse(), Ok(MatrixSxS::from([[0.25]])));
+    }
+    #[test]
+    fn sxs_2x2() {
+        let a = MatrixSxS::<f64, 2, 2>::from([[4., 7.], [2., 6.]]);
+        assert_eq!(a.determinant(), 10.);
+        check(&a, &a.inverse().unwrap());
+    }
+    #[test]
+    fn sxs_3x3() {
+        let a = MatrixSxS::<f64, 3, 3>::from([[6., 1., 1.], [4., -2., 5.], [2., 8., 7.]]);
+        assert_eq!(a.determinant(), -306.);
+        check(&a, &a.inverse().unwrap());
+    }
+    #[test]
+    fn sxs_4x4() {
+        let a = MatrixSxS::<f64, 4, 4>::from([
+            [1., 0., 2., -1.],
+            [3., 0., 0., 5.],
+            [2., 1., 4., -3.],
+            [1., 0., 5., 0.],
+        ]);
+        assert_eq!(a.determinant(), 30.);
+        check(&a, &a.inverse().unwrap());
+    }
+    #[test]
+    fn sxs_5x5() {
+        let a = MatrixSxS::<f64, 5, 5>::from([
+            [2., 1., 0., 0., 3.],
+            [1., 4., 1., 0., 0.],
+            [0., 1., 5., 2., 0.],
+            [1., 0., 2., 6., 1.],
+            [3., 0., 0., 1., 7.],
+        ]);
+        assert_approx_eq(&[a.determinant()], &[330.]);
+        check(&a, &a.inverse().unwrap());
+    }
+    #[test]
+    fn sxs_singular() {
+        let a = MatrixSxS::<f64, 3, 3>::from([[1., 2., 3.], [2., 4., 6.], [1., 1., 1.]]);
+        assert_eq!(a.determinant(), 0.);
+        assert_eq!(a.inverse(), Err("Singular matrix"));
+
+        let a = MatrixSxS::<f64, 5, 5>::from([
+            [1., 2., 3., 4., 5.],
+            [2., 4., 6., 8., 10.],
+            [1., 0., 0., 0., 0.],
+            [0., 1., 0., 0., 0.],
+            [0., 0., 1., 0., 0.],
+        ]);
+        assert_eq!(a.inverse(), Err("Singular matrix"));
+    }
+}
@@ -14,6 +14,195 @@ fn ref_arr<T, const N: usize>(x: &[T; N]) -> [&T; N] {
     // let ref_arr: [&T;COLUMNS] = r.iter().collect::<Vec<_>>().try_into().unwrap();
 }
 
+// Matrix
+// --------------------------------------------------
+impl<T, R: Dim, C: Dim, L: Layout> Matrix<T, R, C, L>
+where
+    (R, C, L): Storage<T>,
+{
+    /// Applies `f` to each element, returning a matrix of the same dimensions and layout.
+    /// ```
+    /// use static_la::MatrixDxS;
+    /// let a = MatrixDxS::from(vec![[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a.map(|x| x % 2 == 0), MatrixDxS::from(vec![[false, true, false], [true, false, true]]));
+    /// ```
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Matrix<U, R, C, L>
+    where
+        (R, C, L): Storage<U>,
+    {
+        Matrix {
+            data: <(R, C, L)>::collect(self.data.as_ref().iter().map(f)),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+    /// Applies `f` to each pair of elements of `self` and `other`, returning a matrix with the
+    ///  [`Join`] of their dimensions in the layout of `self`.
+    /// ```
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use static_la::*;
+    /// let a = MatrixSxD::try_from([vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// let b = MatrixDxS::from(vec![[1, 1, 1], [2, 2, 2]]);
+    /// // `Static<2>` joined with `Dynamic` and `Dynamic` joined with `Static<3>`.
+    /// let c: MatrixSxS<i32, 2, 3> = a.zip_map(&b, |x, y| x * y);
+    /// assert_eq!(c, MatrixSxS::from([[1, 2, 3], [8, 10, 12]]));
+    /// ```
+    pub fn zip_map<U, V, R2: Dim, C2: Dim, L2: Layout, F: FnMut(&T, &U) -> V>(
+        &self,
+        other: &Matrix<U, R2, C2, L2>,
+        mut f: F,
+    ) -> Matrix<V, R::Output, C::Output, L>
+    where
+        R: Join<R2>,
+        C: Join<C2>,
+        (R2, C2, L2): Storage<U>,
+        (R::Output, C::Output, L): Storage<V>,
+    {
+        let (rows, columns) = self.shape();
+        assert_eq!(rows, R2::size(other.rows), "Non-matching rows");
+        assert_eq!(columns, C2::size(other.columns), "Non-matching columns");
+        let (a, b) = (self.data.as_ref(), other.data.as_ref());
+        let data = (0..a.len()).map(|i| f(&a[i], &b[reindex::<L, L2>(i, rows, columns)]));
+        Matrix {
+            data: <(R::Output, C::Output, L)>::collect(data),
+            rows: <R::Output>::value(rows),
+            columns: <C::Output>::value(columns),
+        }
+    }
+    /// Applies `f` to each element of `self` paired with the matching element of `other`.
+    pub(crate) fn zip_apply<U, R2: Dim, C2: Dim, L2: Layout, F: FnMut(&mut T, &U)>(
+        &mut self,
+        other: &Matrix<U, R2, C2, L2>,
+        mut f: F,
+    ) where
+        (R2, C2, L2): Storage<U>,
+    {
+        let (rows, columns) = self.shape();
+        assert_eq!(rows, R2::size(other.rows), "Non-matching rows");
+        assert_eq!(columns, C2::size(other.columns), "Non-matching columns");
+        let b = other.data.as_ref();
+        for (i, a) in self.data.as_mut().iter_mut().enumerate() {
+            f(a, &b[reindex::<L, L2>(i, rows, columns)]);
+        }
+    }
+}
+/// Position in a `rows` by `columns` matrix stored in `L2` of the element at position `index`
+///  when stored in `L`.
+fn reindex<L: Layout, L2: Layout>(index: usize, rows: usize, columns: usize) -> usize {
+    if L::COLUMN_MAJOR == L2::COLUMN_MAJOR {
+        index
+    } else {
+        let (row, column) = L::position(index, rows, columns);
+        L2::index(row, column, rows, columns)
+    }
+}
+/// Applies `f` to each element of `a`, a `rows` by `columns` sequence in row-major order, paired
+///  with the matching element of `b`, returning a row-major matrix with the [`Join`] of their
+///  dimensions.
+///
+/// The dimensions of `a` are given as markers alongside their sizes, as transposes and views do
+///  not carry them as types.
+pub(crate) fn zip_rows<'a, T: 'a, U, V, R, C, R2: Dim, C2: Dim, L2: Layout>(
+    a: impl Iterator<Item = &'a T>,
+    (_, rows): (R, usize),
+    (_, columns): (C, usize),
+    b: &Matrix<U, R2, C2, L2>,
+    mut f: impl FnMut(&T, &U) -> V,
+) -> Matrix<V, R::Output, C::Output>
+where
+    R: Join<R2>,
+    C: Join<C2>,
+    (R2, C2, L2): Storage<U>,
+    (R::Output, C::Output, RowMajor): Storage<V>,
+{
+    assert_eq!(rows, R2::size(b.rows), "Non-matching rows");
+    assert_eq!(columns, C2::size(b.columns), "Non-matching columns");
+    let b = b.data.as_ref();
+    let data = a
+        .enumerate()
+        .map(|(i, x)| f(x, &b[reindex::<RowMajor, L2>(i, rows, columns)]));
+    Matrix {
+        data: <(R::Output, C::Output, RowMajor)>::collect(data),
+        rows: <R::Output>::value(rows),
+        columns: <C::Output>::value(columns),
+    }
+}
+/// Applies `f` to each element of `a`, a mutable `rows` by `columns` sequence in row-major order,
+///  paired with the matching element of `b`.
+pub(crate) fn zip_rows_apply<'a, T: 'a, U, R2: Dim, C2: Dim, L2: Layout>(
+    a: impl Iterator<Item = &'a mut T>,
+    (rows, columns): (usize, usize),
+    b: &Matrix<U, R2, C2, L2>,
+    mut f: impl FnMut(&mut T, &U),
+) where
+    (R2, C2, L2): Storage<U>,
+{
+    assert_eq!(rows, R2::size(b.rows), "Non-matching rows");
+    assert_eq!(columns, C2::size(b.columns), "Non-matching columns");
+    let b = b.data.as_ref();
+    for (i, x) in a.enumerate() {
+        f(x, &b[reindex::<RowMajor, L2>(i, rows, columns)]);
+    }
+}
+// ColumnMajor
+// --------------------------------------------------
+impl<'a, T, R: Dim, C: Dim> Matrix<T, R, C, ColumnMajor>
+where
+    (R, C, ColumnMajor): Storage<T>,
+{
+    /// An iterator over all elements, column by column.
+    pub fn iter(&'a self) -> impl Iterator<Item = &'a T> {
+        self.data.as_ref().iter()
+    }
+    /// A mutable iterator over all elements, column by column.
+    pub fn iter_mut(&'a mut self) -> impl Iterator<Item = &'a mut T> {
+        self.data.as_mut().iter_mut()
+    }
+    /// An iterator over rows.
+    pub fn rows_iter(&'a self) -> impl Iterator<Item = Matrix<&'a T, Static<1>, C>>
+    where
+        (Static<1>, C, RowMajor): Storage<&'a T>,
+    {
+        let (rows, columns) = self.shape();
+        (0..rows).map(move |i| Matrix {
+            data: <(Static<1>, C, RowMajor)>::collect(
+                self.data
+                    .as_ref()
+                    .iter()
+                    .skip(i)
+                    .step_by(rows)
+                    .take(columns),
+            ),
+            rows: Static,
+            columns: self.columns,
+        })
+    }
+    /// An iterator over columns, each of which is contiguous.
+    /// ```
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use static_la::*;
+    /// let a = MatrixDxS::from(vec![[1, 2], [3, 4]]).transpose();
+    /// let mut iter = a.columns_iter();
+    /// assert_eq!(iter.next(), Some(ColumnVectorS::from([[&1], [&2]])));
+    /// assert_eq!(iter.next(), Some(ColumnVectorS::from([[&3], [&4]])));
+    /// ```
+    pub fn columns_iter(&'a self) -> impl Iterator<Item = Matrix<&'a T, R, Static<1>>>
+    where
+        (R, Static<1>, RowMajor): Storage<&'a T>,
+    {
+        let rows = self.shape().0;
+        self.data
+            .as_ref()
+            .chunks_exact(rows.max(1))
+            .map(move |c| Matrix {
+                data: <(R, Static<1>, RowMajor)>::collect(c.iter()),
+                rows: self.rows,
+                columns: Static,
+            })
+    }
+}
 // TODO: Add mutable iterators over columns and rows (e.g. `RowVectorD<&'a mut T>` and `ColumnVectorD<&'a mut T>`).
 impl<'a, T> MatrixDxD<T> {
     /// An iterator over all elements.
@@ -146,6 +335,43 @@ where
 mod tests {
     use crate::*;
     use std::convert::TryFrom;
+    // Matrix
+    // --------------------------------------------------
+    #[test]
+    fn map() {
+        let a = MatrixSxD::try_from([vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let b = a.map(|x| x * 2);
+        assert_eq!(b.columns(), 3);
+        assert_eq!(
+            b,
+            MatrixSxD::try_from([vec![2, 4, 6], vec![8, 10, 12]]).unwrap()
+        );
+    }
+    #[test]
+    fn zip_map() {
+        let a = MatrixDxD::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let b = MatrixDxS::from(vec![[1, 1, 1], [2, 2, 2]]);
+        let c: MatrixDxS<i32, 3> = a.zip_map(&b, |x, y| x + y);
+        assert_eq!(c, MatrixDxS::from(vec![[2, 3, 4], [6, 7, 8]]));
+        let d: MatrixSxS<i32, 2, 3> = c.zip_map(&MatrixSxS::from([[0; 3]; 2]), |x, y| x + y);
+        assert_eq!(d, MatrixSxS::from([[2, 3, 4], [6, 7, 8]]));
+    }
+    #[test]
+    fn zip_map_layouts() {
+        let a = MatrixSxS::from([[1, 2, 3], [4, 5, 6]]);
+        let b = MatrixDxD::try_from(vec![vec![1, 4], vec![2, 5], vec![3, 6]]).unwrap();
+        let c = a.zip_map(&b.transpose(), |x, y| x - y);
+        assert_eq!(c, MatrixSxS::from([[0; 3]; 2]));
+        let d = b.transpose().zip_map(&a, |x, y| x + y);
+        assert_eq!(d.as_slice(), &[2, 8, 4, 10, 6, 12]);
+    }
+    #[test]
+    #[should_panic(expected = "Non-matching rows")]
+    fn zip_map_rows() {
+        let a = MatrixDxD::try_from(vec![vec![1, 2, 3]]).unwrap();
+        let b = MatrixDxS::from(vec![[1, 1, 1], [2, 2, 2]]);
+        let _ = a.zip_map(&b, |x, y| x + y);
+    }
     // MatrixDxD
     // --------------------------------------------------
     #[test]
@@ -234,4 +460,41 @@ mod tests {
         assert_eq!(Some(ColumnVectorS::from([[&9], [&12]])), iter.next());
         assert_eq!(None, iter.next());
     }
+    // ColumnMajor
+    // --------------------------------------------------
+    #[test]
+    fn column_major() {
+        let a = MatrixSxD::try_from([vec![1, 2, 3], vec![4, 5, 6]])
+            .unwrap()
+            .to_layout::<ColumnMajor>();
+        assert!(a.iter().eq([1, 4, 2, 5, 3, 6].iter()));
+        let mut columns = a.columns_iter();
+        assert_eq!(columns.next(), Some(ColumnVectorS::from([[&1], [&4]])));
+        assert_eq!(columns.next(), Some(ColumnVectorS::from([[&2], [&5]])));
+        assert_eq!(columns.next(), Some(ColumnVectorS::from([[&3], [&6]])));
+        assert_eq!(columns.next(), None);
+        let mut rows = a.rows_iter();
+        assert_eq!(
+            rows.next(),
+            Some(RowVectorD::try_from([vec![&1, &2, &3]]).unwrap())
+        );
+        assert_eq!(
+            rows.next(),
+            Some(RowVectorD::try_from([vec![&4, &5, &6]]).unwrap())
+        );
+        assert_eq!(rows.next(), None);
+    }
+    #[test]
+    fn column_major_iter_mut() {
+        let mut a = MatrixSxD::try_from([vec![1, 2, 3], vec![4, 5, 6]])
+            .unwrap()
+            .to_layout::<ColumnMajor>();
+        for x in a.iter_mut() {
+            *x += 1;
+        }
+        assert_eq!(
+            a,
+            MatrixSxD::try_from([vec![2, 3, 4], vec![5, 6, 7]]).unwrap()
+        );
+    }
 }
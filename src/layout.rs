@@ -0,0 +1,134 @@
+use crate::*;
+
+/// The order in which the elements of a [`Matrix`] are stored.
+pub trait Layout {
+    /// The layout in which the same data describes the transpose.
+    type Transposed: Layout;
+    /// Whether elements within a column, rather than within a row, are contiguous.
+    const COLUMN_MAJOR: bool;
+    /// Position in the underlying data of the element at `row` and `column` of a `rows` by
+    ///  `columns` matrix.
+    fn index(row: usize, column: usize, rows: usize, columns: usize) -> usize;
+    /// Row and column of the element at position `index` in the underlying data of a `rows` by
+    ///  `columns` matrix.
+    fn position(index: usize, rows: usize, columns: usize) -> (usize, usize);
+}
+/// Elements within a row are contiguous, the default layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RowMajor;
+/// Elements within a column are contiguous, as in Fortran and LAPACK.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnMajor;
+impl Layout for RowMajor {
+    type Transposed = ColumnMajor;
+    const COLUMN_MAJOR: bool = false;
+    fn index(row: usize, column: usize, _rows: usize, columns: usize) -> usize {
+        row * columns + column
+    }
+    fn position(index: usize, _rows: usize, columns: usize) -> (usize, usize) {
+        (index / columns, index % columns)
+    }
+}
+impl Layout for ColumnMajor {
+    type Transposed = RowMajor;
+    const COLUMN_MAJOR: bool = true;
+    fn index(row: usize, column: usize, rows: usize, _columns: usize) -> usize {
+        column * rows + row
+    }
+    fn position(index: usize, rows: usize, _columns: usize) -> (usize, usize) {
+        (index % rows, index / rows)
+    }
+}
+
+// Matrix
+// --------------------------------------------------
+impl<T, R: Dim, C: Dim, L: Layout> Matrix<T, R, C, L>
+where
+    (R, C, L): Storage<T>,
+{
+    /// Rows and columns.
+    pub(crate) fn shape(&self) -> (usize, usize) {
+        (R::size(self.rows), C::size(self.columns))
+    }
+    /// Gets the element at `row` and `column` without bounds checking against the shape.
+    pub(crate) fn get(&self, row: usize, column: usize) -> &T {
+        let (rows, columns) = self.shape();
+        &self.data.as_ref()[L::index(row, column, rows, columns)]
+    }
+    /// Underlying data in the order of the layout `L`.
+    ///
+    /// This can be passed directly to libraries expecting the same layout.
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_ref()
+    }
+    /// Returns the elements of `self` stored in the layout `L2`.
+    ///
+    /// Unlike [`Matrix::transpose`] this reorders the underlying data.
+    /// ```
+    /// use static_la::*;
+    /// let a = MatrixDxS::from(vec![[1, 2, 3], [4, 5, 6]]);
+    /// let b = a.to_layout::<ColumnMajor>();
+    /// assert_eq!(b.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn to_layout<L2: Layout>(&self) -> Matrix<T, R, C, L2>
+    where
+        T: Clone,
+        (R, C, L2): Storage<T>,
+    {
+        let (rows, columns) = self.shape();
+        let data = (0..rows * columns).map(|i| {
+            let (row, column) = L2::position(i, rows, columns);
+            self.get(row, column).clone()
+        });
+        Matrix {
+            data: <(R, C, L2)>::collect(data),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+}
+
+// Tests
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn index() {
+        assert_eq!(RowMajor::index(1, 2, 2, 3), 5);
+        assert_eq!(ColumnMajor::index(1, 2, 2, 3), 5);
+        assert_eq!(RowMajor::index(1, 0, 2, 3), 3);
+        assert_eq!(ColumnMajor::index(1, 0, 2, 3), 1);
+        for i in 0..6 {
+            let (r, c) = ColumnMajor::position(i, 2, 3);
+            assert_eq!(ColumnMajor::index(r, c, 2, 3), i);
+            let (r, c) = RowMajor::position(i, 2, 3);
+            assert_eq!(RowMajor::index(r, c, 2, 3), i);
+        }
+    }
+    #[test]
+    fn to_layout() {
+        let a = MatrixSxS::from([[1, 2, 3], [4, 5, 6]]);
+        let b = a.to_layout::<ColumnMajor>();
+        assert_eq!(b.as_slice(), &[1, 4, 2, 5, 3, 6]);
+        assert_eq!((b.rows(), b.columns()), (2, 3));
+        assert_eq!(b[(1, 0)], 4);
+        assert_eq!(b.to_layout::<RowMajor>(), a);
+
+        let c = MatrixDxD::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert_eq!(c.to_layout::<ColumnMajor>().as_slice(), b.as_slice());
+    }
+    #[test]
+    fn fortran() {
+        // A 2 by 3 matrix in column-major order is the row-major data of its 3 by 2 transpose.
+        let data = vec![1., 4., 2., 5., 3., 6.];
+        let a = MatrixDxD::try_from((3, 2, data.clone()))
+            .unwrap()
+            .transpose();
+        assert_eq!(a, MatrixSxS::from([[1., 2., 3.], [4., 5., 6.]]));
+        assert_eq!(a.as_slice(), &data[..]);
+    }
+}
@@ -144,11 +144,22 @@
 //! use std::convert::TryFrom;
 //! use static_la::*;
 //! let a = MatrixDxD::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
-//! assert_eq!(a.slice_dxd((0..1, 0..2)), MatrixDxD::try_from(vec![vec![&1, &2]]).unwrap());
-//! assert_eq!(a.slice_dxs::<{ 0..2 }>(0..1), MatrixDxS::from(vec![[&1, &2]]));
-//! assert_eq!(a.slice_sxd::<{ 0..1 }>(0..2), MatrixSxD::try_from([vec![&1, &2]]).unwrap());
-//! assert_eq!(a.slice_sxs::<{ 0..1 }, { 0..2 }>(), MatrixSxS::from([[&1, &2]]));
-//! assert_eq!(a.slice_sxs::<{ 0..1 }, { 0..2 }>(), MatrixDxD::try_from(vec![vec![&1, &2]]).unwrap());
+//! // Slices are strided views over `a` which do not copy its data.
+//! let b = a.slice_sxs::<{ 0..1 }, { 0..2 }>();
+//! assert_eq!(b.to_matrix(), MatrixSxS::from([[1, 2]]));
+//! assert_eq!(a.slice_dxd((0..1, 0..2)).to_matrix(), MatrixDxD::try_from(vec![vec![1, 2]]).unwrap());
+//! assert_eq!(a.slice_dxs::<{ 0..2 }>(0..1).to_matrix(), MatrixDxS::from(vec![[1, 2]]));
+//! assert_eq!(a.slice_sxd::<{ 0..1 }>(0..2).to_matrix(), MatrixSxD::try_from([vec![1, 2]]).unwrap());
+//! // Views can be used in arithmetic and matrix multiplication.
+//! assert_eq!(b + MatrixSxS::from([[1, 1]]), MatrixSxS::from([[2, 3]]));
+//! assert_eq!(b.matmul(&MatrixSxS::from([[1], [1]])), MatrixSxS::from([[3]]));
+//!
+//! // Mutable slices write through to `c`, a mutable slice has to be bound before assigning to it.
+//! let mut c = MatrixSxS::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+//! let mut d = c.slice_sxs_mut::<{ 0..2 }, { 1..2 }>();
+//! d += MatrixSxS::from([[10], [20]]);
+//! d *= 2;
+//! assert_eq!(c, MatrixSxS::from([[1, 24, 3], [4, 50, 6], [7, 8, 9]]));
 //! ```
 //! ### Transpose
 //! ```
@@ -169,7 +180,36 @@
 //! static_la::blas::sgemm(false, false, 1., &a, &b, 1., &mut c);
 //! assert_eq!(c, MatrixSxS::<f32, 2, 2>::from([[76., 103.], [100., 136.]]));
 //! ```
-//! The basic matrix multiply for `f32`s and `f64`s uses `sgemm` and `dgemm`.
+//! The basic matrix multiply for `f32`s and `f64`s uses `sgemm` and `dgemm`, and for
+//!  `Complex<f32>`s and `Complex<f64>`s uses `cgemm` and `zgemm`.
+//!
+//! [`matmul_into`] writes `alpha·a·b + beta·c` into an existing `c` rather than allocating, and
+//!  [`MatmulAssign`] multiplies a matrix in place by a square matrix.
+//!
+//! [`SemiringMatmul::matmul_over`] multiplies over a [`Semiring`] other than ordinary arithmetic,
+//!  such as [`MinPlus`] for shortest paths or [`OrAnd`] for reachability.
+//!
+//! Batches of equally shaped matrices can be multiplied in one call with [`MatmulBatch`], or in
+//!  parallel with `ParMatmulBatch` when the `parallel` feature is enabled.
+//! ### Backends
+//! BLAS and LAPACK routines come from the backend selected by one of these cargo features:
+//! - `openblas` (default): OpenBLAS, built from source.
+//! - `system`: a system-installed OpenBLAS.
+//! - `netlib`: the reference Netlib implementation, built from source.
+//!
+//! With `default-features = false` and none of these enabled, BLAS routines and the decompositions
+//!  use pure-Rust kernels instead, so no C toolchain is needed. General eigendecomposition
+//!  (`eig`) is only available with a backend.
+//! ```toml
+//! static-la = { version = "0.2", default-features = false }
+//! ```
+
+#[cfg(all(feature = "openblas-src", feature = "netlib-src"))]
+compile_error!("Only one of the `openblas` and `netlib` backends can be enabled");
+#[cfg(feature = "netlib-src")]
+extern crate netlib_src;
+#[cfg(feature = "openblas-src")]
+extern crate openblas_src;
 
 /// [`std::ops::Add`] Arithmetic addition operations.
 mod add;
@@ -181,32 +221,75 @@ pub use add_rows::AddRows;
 /// Functionality to expand matrices.
 mod add_columns;
 pub use add_columns::AddColumns;
+/// Cholesky decomposition functionality.
+mod cholesky;
+pub use cholesky::CholeskySolve;
+/// Complex element functionality.
+mod complex;
 /// [`std::fmt::Display`] Format implementations.
 mod display;
 /// [`std::ops::Div`] Arithmetic division operations.
 mod div;
 /// [`std::ops::DivAssign`] Arithmetic division operations.
 mod div_assign;
+/// General eigendecomposition functionality.
+#[doc(cfg(feature = "lapacke"))]
+#[cfg(feature = "lapacke")]
+mod eig;
+#[cfg(feature = "lapacke")]
+pub use eig::Eig;
+/// Symmetric eigendecomposition functionality.
+mod eigh;
+pub use eigh::Eigh;
 /// [`std::convert::From`] Value-to-value conversions.
 mod from;
 /// [`std::ops::Index`] Indexing operations.
 mod index;
+/// Determinant and inverse functionality.
+mod inverse;
 /// Iterations functionality.
 mod iter;
+/// Storage layout functionality.
+mod layout;
+pub use layout::{ColumnMajor, Layout, RowMajor};
+/// LU decomposition functionality.
+mod lu;
+pub use lu::Lu;
 /// Matrix multiplication functionality.
 mod matmul;
-pub use matmul::Matmul;
+#[cfg(feature = "parallel")]
+pub use matmul::ParMatmulBatch;
+pub use matmul::{matmul_into, Matmul, MatmulAssign, MatmulBatch};
 /// [`std::ops::Mul`] Arithmetic multiplication operations.
 mod mul;
 /// [`std::ops::MulAssign`] Arithmetic multiplication operations.
 mod mul_assign;
+/// Pure-Rust BLAS kernels, used in place of `cblas` when no backend is enabled.
+#[cfg_attr(feature = "cblas", allow(dead_code, unused_imports))]
+mod native;
 /// [`std::cmp::PartialEq`] Partial equality comparison operations.
 mod partial_eq;
+/// Matrix multiplication over semirings functionality.
+mod semiring;
+pub use semiring::{Arithmetic, Infinity, MaxPlus, MinPlus, OrAnd, Semiring, SemiringMatmul};
+/// QR decomposition and least squares functionality.
+mod qr;
+pub use qr::{Lstsq, Qr};
+/// Linear system solving functionality.
+mod solve;
+pub use solve::Solve;
+/// Singular value decomposition functionality.
+mod svd;
+pub use svd::{min, Svd};
 /// Slicing functionality.
 mod slice;
-pub use slice::{SliceDxD, SliceDxS, SliceSxD, SliceSxS};
+pub use slice::{
+    SliceDxD, SliceDxDMut, SliceDxS, SliceDxSMut, SliceSxD, SliceSxDMut, SliceSxS, SliceSxSMut,
+    ViewDxD, ViewDxDMut, ViewDxS, ViewDxSMut, ViewSxD, ViewSxDMut, ViewSxS, ViewSxSMut,
+};
 /// Implementations relating to dimensions of matrices.
 mod dims;
+pub use dims::{Concat, Dim, Dynamic, Join, Static, Storage};
 /// Constructing matrices with random values functionality.
 #[doc(cfg(feature = "distribution"))]
 #[cfg(feature = "distribution")]
@@ -234,11 +317,9 @@ mod bitxor;
 mod bitxor_assign;
 /// BLAS operations.
 ///
-/// There are too many combinations for the BLAS operations (sgemm has more than 64) to manually
-///  write the implementations to allow for compile time checking. So the checking and type
-///  joining does not occur with these functions.
-///
-/// If you have any idea how to do this better please open an issue, discussion or pull request.
+/// These functions check dimensions at runtime. For compile time checking of `sgemm` and
+///  `dgemm` use the [`blas::Gemm`] trait, where static dimensions are joined as with other
+///  operations.
 ///
 /// There is nothing in the way of full support other than my own motivation, I plan to implement all BLAS functionality moving forward.
 pub mod blas;
@@ -255,51 +336,88 @@ mod try_from;
 
 // Matrix types
 // --------------------------------------------------
-/// A `dynamic x dynamic` matrix where neither dimension is known at compile time.
+/// A matrix with `R` rows and `C` columns, where each dimension is either [`Static`] (known at
+///  compile time) or [`Dynamic`] (known at runtime).
+///
+/// Elements are stored in the order of the [`Layout`] `L` in an array when both dimensions are
+///  static and in a [`Vec`] otherwise. The four kinds of matrix, [`MatrixDxD`], [`MatrixDxS`],
+///  [`MatrixSxD`] and [`MatrixSxS`], are row-major aliases of this type, so functionality can be
+///  written once over any `R: Dim`, `C: Dim` and `L: Layout`:
 /// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// use static_la::*;
 /// use std::convert::TryFrom;
-/// let _ = static_la::MatrixDxD::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+/// fn double<R: Dim, C: Dim, L: Layout>(a: &Matrix<i32, R, C, L>) -> Matrix<i32, R, C, L>
+/// where
+///     (R, C, L): Storage<i32>,
+/// {
+///     a.map(|x| 2 * x)
+/// }
+/// assert_eq!(double(&MatrixSxS::from([[1, 2]])), MatrixSxS::from([[2, 4]]));
+/// let a = MatrixDxD::try_from(vec![vec![1, 2]]).unwrap();
+/// assert_eq!(double(&a), MatrixDxD::try_from(vec![vec![2, 4]]).unwrap());
+/// assert_eq!(double(&a.transpose()), MatrixDxD::try_from(vec![vec![2], vec![4]]).unwrap());
 /// ```
-#[derive(Eq, PartialEq, Debug, Clone)]
-pub struct MatrixDxD<T> {
+pub struct Matrix<T, R: Dim, C: Dim, L: Layout = RowMajor>
+where
+    (R, C, L): Storage<T>,
+{
     /// Underlying data.
-    data: Vec<T>,
-    columns: usize,
-    rows: usize,
+    data: <(R, C, L) as Storage<T>>::Buffer,
+    rows: R::Value,
+    columns: C::Value,
+}
+impl<T, R: Dim, C: Dim, L: Layout> Clone for Matrix<T, R, C, L>
+where
+    (R, C, L): Storage<T>,
+    <(R, C, L) as Storage<T>>::Buffer: Clone,
+{
+    fn clone(&self) -> Self {
+        Matrix {
+            data: self.data.clone(),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+}
+impl<T, R: Dim, C: Dim, L: Layout> std::fmt::Debug for Matrix<T, R, C, L>
+where
+    (R, C, L): Storage<T>,
+    <(R, C, L) as Storage<T>>::Buffer: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Matrix")
+            .field("data", &self.data)
+            .field("rows", &R::size(self.rows))
+            .field("columns", &C::size(self.columns))
+            .field("column_major", &L::COLUMN_MAJOR)
+            .finish()
+    }
 }
+/// A `dynamic x dynamic` matrix where neither dimension is known at compile time.
+/// ```
+/// use std::convert::TryFrom;
+/// let _ = static_la::MatrixDxD::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+/// ```
+pub type MatrixDxD<T> = Matrix<T, Dynamic, Dynamic, RowMajor>;
 /// A `dynamic x static` matrix where the columns dimension is known at compile time.
 /// ```
 /// let _ = static_la::MatrixDxS::from(vec![[1, 2, 3], [4, 5, 6]]);
 /// ```
-#[derive(Eq, PartialEq, Debug, Clone)]
-pub struct MatrixDxS<T, const COLUMNS: usize> {
-    /// Underlying data.
-    data: Vec<T>,
-    rows: usize,
-}
+pub type MatrixDxS<T, const COLUMNS: usize> = Matrix<T, Dynamic, Static<COLUMNS>, RowMajor>;
 /// A `static x dynamic` matrix where the rows dimension is known at compile time.
 /// ```
 /// use std::convert::TryFrom;
 /// let _ = static_la::MatrixSxD::try_from([vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
 /// ```
-#[derive(Eq, PartialEq, Debug, Clone)]
-pub struct MatrixSxD<T, const ROWS: usize> {
-    /// Underlying data.
-    data: Vec<T>,
-    columns: usize,
-}
+pub type MatrixSxD<T, const ROWS: usize> = Matrix<T, Static<ROWS>, Dynamic, RowMajor>;
 /// A `static x static` matrix where both dimensions are known at compile time.
 /// ```
 /// let _ = static_la::MatrixSxS::<i32,2,3>::from([[1, 2, 3], [4, 5, 6]]);
 /// ```
-#[derive(Eq, PartialEq, Debug, Clone)]
-pub struct MatrixSxS<T, const ROWS: usize, const COLUMNS: usize>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    /// Underlying data.
-    data: [T; ROWS * COLUMNS],
-}
+pub type MatrixSxS<T, const ROWS: usize, const COLUMNS: usize> =
+    Matrix<T, Static<ROWS>, Static<COLUMNS>, RowMajor>;
 // Vector aliases
 // --------------------------------------------------
 /// A matrix with 1 column and a dynamic number of rows.
@@ -343,6 +461,22 @@ pub type RowVectorD<T> = MatrixSxD<T, 1>;
 /// ```
 pub type RowVectorS<T, const COLUMNS: usize> = MatrixSxS<T, 1, COLUMNS>;
 
+/// Asserts all elements of two slices are equal within a tolerance relative to the precision
+///  of `T`.
+#[cfg(test)]
+fn assert_approx_eq<T: num_traits::Float + std::fmt::Debug>(a: &[T], b: &[T]) {
+    assert_eq!(a.len(), b.len(), "Non-matching lengths");
+    let tolerance = T::epsilon().sqrt();
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert!(
+            (*x - *y).abs() <= tolerance * T::one().max(x.abs()).max(y.abs()),
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
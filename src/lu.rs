@@ -0,0 +1,379 @@
+use crate::*;
+use num_traits::Float;
+
+/// Internal LU decomposition trait.
+pub trait InternalLu: Sized {
+    /// Factors `a` in-place such that `P·A = L·U`.
+    ///
+    /// On return the strictly lower triangle of `a` holds `L` (its unit diagonal is implied) and
+    ///  the upper triangle holds `U`. `permutation[i]` is the row of the original `a` which was
+    ///  moved to row `i`.
+    fn lu(
+        // An `n` by `n` row-major matrix.
+        a: &mut [Self],
+        // Length `n`.
+        permutation: &mut [usize],
+        // Rows and columns of `a`.
+        n: usize,
+    );
+}
+/// Default LU decomposition implementation (Doolittle with partial pivoting).
+impl<T: Float> InternalLu for T {
+    default fn lu(a: &mut [T], permutation: &mut [usize], n: usize) {
+        debug_assert_eq!(a.len(), n * n);
+        debug_assert_eq!(permutation.len(), n);
+
+        for (i, p) in permutation.iter_mut().enumerate() {
+            *p = i;
+        }
+        for k in 0..n {
+            // Selects the row with the largest magnitude in column `k` as the pivot.
+            let mut pivot = k;
+            for i in k + 1..n {
+                if a[i * n + k].abs() > a[pivot * n + k].abs() {
+                    pivot = i;
+                }
+            }
+            if pivot != k {
+                for j in 0..n {
+                    a.swap(k * n + j, pivot * n + j);
+                }
+                permutation.swap(k, pivot);
+            }
+            // A zero pivot means the column is already eliminated.
+            let diagonal = a[k * n + k];
+            if diagonal == T::zero() {
+                continue;
+            }
+            for i in k + 1..n {
+                let factor = a[i * n + k] / diagonal;
+                a[i * n + k] = factor;
+                for j in k + 1..n {
+                    a[i * n + j] = a[i * n + j] - factor * a[k * n + j];
+                }
+            }
+        }
+    }
+}
+/// `f32` LU decomposition specialization.
+#[cfg(feature = "lapacke")]
+impl InternalLu for f32 {
+    fn lu(a: &mut [f32], permutation: &mut [usize], n: usize) {
+        assert_eq!(a.len(), n * n);
+        assert_eq!(permutation.len(), n);
+        let mut ipiv = vec![Default::default(); n];
+        let info = unsafe {
+            lapacke::sgetrf(
+                lapacke::Layout::RowMajor,
+                n as i32,
+                n as i32,
+                a,
+                n as i32,
+                &mut ipiv,
+            )
+        };
+        assert!(info >= 0, "Illegal `sgetrf` argument");
+        ipiv_to_permutation(&ipiv, permutation);
+    }
+}
+/// `f64` LU decomposition specialization.
+#[cfg(feature = "lapacke")]
+impl InternalLu for f64 {
+    fn lu(a: &mut [f64], permutation: &mut [usize], n: usize) {
+        assert_eq!(a.len(), n * n);
+        assert_eq!(permutation.len(), n);
+        let mut ipiv = vec![Default::default(); n];
+        let info = unsafe {
+            lapacke::dgetrf(
+                lapacke::Layout::RowMajor,
+                n as i32,
+                n as i32,
+                a,
+                n as i32,
+                &mut ipiv,
+            )
+        };
+        assert!(info >= 0, "Illegal `dgetrf` argument");
+        ipiv_to_permutation(&ipiv, permutation);
+    }
+}
+/// Converts LAPACK 1-based sequential row interchanges into a row permutation.
+fn ipiv_to_permutation(ipiv: &[i32], permutation: &mut [usize]) {
+    for (i, p) in permutation.iter_mut().enumerate() {
+        *p = i;
+    }
+    for (i, p) in ipiv.iter().enumerate() {
+        permutation.swap(i, *p as usize - 1);
+    }
+}
+/// Splits a packed `n` by `n` LU factorization into a unit lower triangular `l` and upper
+///  triangular `u`.
+fn unpack<T: Float>(lu: &[T], l: &mut [T], u: &mut [T], n: usize) {
+    for i in 0..n {
+        for j in 0..n {
+            let (lower, upper) = match i.cmp(&j) {
+                std::cmp::Ordering::Greater => (lu[i * n + j], T::zero()),
+                std::cmp::Ordering::Equal => (T::one(), lu[i * n + j]),
+                std::cmp::Ordering::Less => (T::zero(), lu[i * n + j]),
+            };
+            l[i * n + j] = lower;
+            u[i * n + j] = upper;
+        }
+    }
+}
+
+/// An LU decomposition with partial pivoting such that `P·A = L·U`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lu<M, P> {
+    /// Unit lower triangular factor.
+    pub l: M,
+    /// Upper triangular factor.
+    pub u: M,
+    /// Row permutation, row `i` of `P·A` is row `permutation[i]` of `A`.
+    pub permutation: P,
+}
+
+// MatrixDxD
+// --------------------------------------------------
+impl<T: Float> MatrixDxD<T> {
+    /// Computes the LU decomposition with partial pivoting of `self`.
+    ///
+    /// Panics if `self` is not square.
+    /// ```
+    /// use static_la::MatrixDxD;
+    /// use std::convert::TryFrom;
+    /// let a = MatrixDxD::try_from(vec![vec![2., 1.], vec![4., 3.]]).unwrap();
+    /// let lu = a.lu();
+    /// assert_eq!(lu.permutation, vec![1, 0]);
+    /// assert_eq!(lu.l, MatrixDxD::try_from(vec![vec![1., 0.], vec![0.5, 1.]]).unwrap());
+    /// assert_eq!(lu.u, MatrixDxD::try_from(vec![vec![4., 3.], vec![0., -0.5]]).unwrap());
+    /// ```
+    pub fn lu(&self) -> Lu<MatrixDxD<T>, Vec<usize>> {
+        assert_eq!(self.rows, self.columns, "Non-square matrix");
+
+        let n = self.rows;
+        let mut data = self.data.clone();
+        let mut permutation = vec![0; n];
+        InternalLu::lu(&mut data, &mut permutation, n);
+        let (mut l, mut u) = (vec![T::zero(); n * n], vec![T::zero(); n * n]);
+        unpack(&data, &mut l, &mut u, n);
+        Lu {
+            l: MatrixDxD {
+                data: l,
+                rows: n,
+                columns: n,
+            },
+            u: MatrixDxD {
+                data: u,
+                rows: n,
+                columns: n,
+            },
+            permutation,
+        }
+    }
+}
+// MatrixDxS
+// --------------------------------------------------
+impl<T: Float, const N: usize> MatrixDxS<T, N>
+where
+    [(); N * N]:,
+{
+    /// Computes the LU decomposition with partial pivoting of `self`.
+    ///
+    /// Since `self` must be square the factors are `N` by `N` static matrices.
+    ///
+    /// Panics if `self` is not square.
+    pub fn lu(&self) -> Lu<MatrixSxS<T, N, N>, [usize; N]> {
+        assert_eq!(self.rows, N, "Non-square matrix");
+
+        let mut data = [T::zero(); N * N];
+        data.copy_from_slice(&self.data);
+        lu_sxs(data)
+    }
+}
+// MatrixSxD
+// --------------------------------------------------
+impl<T: Float, const N: usize> MatrixSxD<T, N>
+where
+    [(); N * N]:,
+{
+    /// Computes the LU decomposition with partial pivoting of `self`.
+    ///
+    /// Since `self` must be square the factors are `N` by `N` static matrices.
+    ///
+    /// Panics if `self` is not square.
+    pub fn lu(&self) -> Lu<MatrixSxS<T, N, N>, [usize; N]> {
+        assert_eq!(self.columns, N, "Non-square matrix");
+
+        let mut data = [T::zero(); N * N];
+        data.copy_from_slice(&self.data);
+        lu_sxs(data)
+    }
+}
+// MatrixSxS
+// --------------------------------------------------
+impl<T: Float, const N: usize> MatrixSxS<T, N, N>
+where
+    [(); N * N]:,
+{
+    /// Computes the LU decomposition with partial pivoting of `self`.
+    /// ```
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use static_la::MatrixSxS;
+    /// let a = MatrixSxS::<f64, 2, 2>::from([[2., 1.], [4., 3.]]);
+    /// let lu = a.lu();
+    /// assert_eq!(lu.permutation, [1, 0]);
+    /// assert_eq!(lu.l, MatrixSxS::from([[1., 0.], [0.5, 1.]]));
+    /// assert_eq!(lu.u, MatrixSxS::from([[4., 3.], [0., -0.5]]));
+    /// ```
+    pub fn lu(&self) -> Lu<MatrixSxS<T, N, N>, [usize; N]> {
+        lu_sxs(self.data)
+    }
+}
+/// Factors an `N` by `N` array into static matrices.
+fn lu_sxs<T: Float, const N: usize>(mut data: [T; N * N]) -> Lu<MatrixSxS<T, N, N>, [usize; N]>
+where
+    [(); N * N]:,
+{
+    let mut permutation = [0; N];
+    InternalLu::lu(&mut data, &mut permutation, N);
+    let (mut l, mut u) = ([T::zero(); N * N], [T::zero(); N * N]);
+    unpack(&data, &mut l, &mut u, N);
+    Lu {
+        l: MatrixSxS {
+            data: l,
+            rows: Static,
+            columns: Static,
+        },
+        u: MatrixSxS {
+            data: u,
+            rows: Static,
+            columns: Static,
+        },
+        permutation,
+    }
+}
+
+// Tests
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::convert::TryFrom;
+
+    /// Checks `P·A = L·U`, that `L` is unit lower triangular and that `U` is upper triangular.
+    fn check<T: num_traits::Float + std::fmt::Debug>(
+        a: &[T],
+        l: &[T],
+        u: &[T],
+        permutation: &[usize],
+    ) {
+        let n = permutation.len();
+        for i in 0..n {
+            assert_eq!(l[i * n + i], T::one());
+            for j in i + 1..n {
+                assert_eq!(l[i * n + j], T::zero());
+                assert_eq!(u[j * n + i], T::zero());
+            }
+        }
+        let mut pa = vec![T::zero(); n * n];
+        for (i, p) in permutation.iter().enumerate() {
+            pa[i * n..(i + 1) * n].copy_from_slice(&a[p * n..(p + 1) * n]);
+        }
+        let mut lu = vec![T::zero(); n * n];
+        for i in 0..n {
+            for j in 0..n {
+                for k in 0..n {
+                    lu[i * n + j] = lu[i * n + j] + l[i * n + k] * u[k * n + j];
+                }
+            }
+        }
+        assert_approx_eq(&pa, &lu);
+    }
+    // f32
+    // --------------------------------------------------
+    #[test]
+    fn f32_dxd() {
+        let a =
+            MatrixDxD::<f32>::try_from(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 10.]])
+                .unwrap();
+        let lu = a.lu();
+        assert_eq!(lu.permutation, vec![2, 0, 1]);
+        check(&a.data, &lu.l.data, &lu.u.data, &lu.permutation);
+    }
+    // f64
+    // --------------------------------------------------
+    #[test]
+    fn f64_dxd() {
+        let a =
+            MatrixDxD::<f64>::try_from(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 10.]])
+                .unwrap();
+        let lu = a.lu();
+        assert_eq!(lu.permutation, vec![2, 0, 1]);
+        check(&a.data, &lu.l.data, &lu.u.data, &lu.permutation);
+    }
+    #[test]
+    fn f64_singular() {
+        let a = MatrixSxS::<f64, 2, 2>::from([[1., 2.], [2., 4.]]);
+        let lu = a.lu();
+        assert_eq!(lu.u.data[3], 0.);
+        check(&a.data, &lu.l.data, &lu.u.data, &lu.permutation);
+    }
+    // f16
+    // --------------------------------------------------
+    #[test]
+    fn f16_sxs() {
+        let f = half::f16::from_f32;
+        let a = MatrixSxS::<half::f16, 3, 3>::from([
+            [f(1.), f(2.), f(3.)],
+            [f(4.), f(5.), f(6.)],
+            [f(7.), f(8.), f(10.)],
+        ]);
+        let lu = a.lu();
+        assert_eq!(lu.permutation, [2, 0, 1]);
+        check(&a.data, &lu.l.data, &lu.u.data, &lu.permutation);
+    }
+    // MatrixDxS
+    // --------------------------------------------------
+    #[test]
+    fn dxs() {
+        let a = MatrixDxS::from(vec![[2., 1.], [4., 3.]]);
+        let lu = a.lu();
+        assert_eq!(lu.permutation, [1, 0]);
+        assert_eq!(lu.l, MatrixSxS::from([[1., 0.], [0.5, 1.]]));
+        assert_eq!(lu.u, MatrixSxS::from([[4., 3.], [0., -0.5]]));
+    }
+    #[test]
+    #[should_panic(expected = "Non-square matrix")]
+    fn dxs_non_square() {
+        let a = MatrixDxS::from(vec![[1., 2.]]);
+        a.lu();
+    }
+    // MatrixSxD
+    // --------------------------------------------------
+    #[test]
+    fn sxd() {
+        let a = MatrixSxD::try_from([vec![2., 1.], vec![4., 3.]]).unwrap();
+        let lu = a.lu();
+        assert_eq!(lu.permutation, [1, 0]);
+        assert_eq!(lu.l, MatrixSxS::from([[1., 0.], [0.5, 1.]]));
+        assert_eq!(lu.u, MatrixSxS::from([[4., 3.], [0., -0.5]]));
+    }
+    #[test]
+    #[should_panic(expected = "Non-square matrix")]
+    fn sxd_non_square() {
+        let a = MatrixSxD::try_from([vec![1.], vec![2.]]).unwrap();
+        a.lu();
+    }
+    // MatrixSxS
+    // --------------------------------------------------
+    #[test]
+    fn sxs() {
+        let a = MatrixSxS::<f64, 2, 2>::from([[2., 1.], [4., 3.]]);
+        let lu = a.lu();
+        assert_eq!(lu.permutation, [1, 0]);
+        assert_eq!(lu.l, MatrixSxS::from([[1., 0.], [0.5, 1.]]));
+        assert_eq!(lu.u, MatrixSxS::from([[4., 3.], [0., -0.5]]));
+    }
+}
@@ -1,8 +1,13 @@
-extern crate openblas_src;
-
+#[cfg(not(feature = "cblas"))]
+use crate::native as cblas;
 use crate::*;
+use num_complex::Complex;
+use num_traits::Num;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::borrow::Cow;
 use std::fmt::Debug;
-use std::ops::{AddAssign, Mul};
+use std::ops::{AddAssign, Mul, Neg};
 
 /// Internal matrix multiplication trait
 pub trait InternalMatmul: Sized {
@@ -19,82 +24,921 @@ pub trait InternalMatmul: Sized {
         n: usize,
         // Columns of `a` and rows of `b`.
         k: usize,
+    ) {
+        Self::matmul_strided(a, k, b, n, c, m, n, k);
+    }
+    /// [`InternalMatmul::matmul`] where the rows of `a` and `b` may not be contiguous.
+    fn matmul_strided(
+        // An `m` by `k` row-major matrix.
+        a: &[Self],
+        // Distance between the starts of consecutive rows of `a`.
+        lda: usize,
+        // An `k` by `n` row-major matrix.
+        b: &[Self],
+        // Distance between the starts of consecutive rows of `b`.
+        ldb: usize,
+        // An `m` by `n` row-major matrix.
+        c: &mut [Self],
+        // Rows of `a` and rows of `c`.
+        m: usize,
+        // Columns of `b` and columns of `c`.
+        n: usize,
+        // Columns of `a` and rows of `b`.
+        k: usize,
+    ) {
+        let none = cblas::Transpose::None;
+        Self::matmul_transposed(a, lda, none, b, ldb, none, c, m, n, k);
+    }
+    /// [`InternalMatmul::matmul_strided`] where `a` and `b` may be given as their transposes or
+    ///  conjugate transposes.
+    #[allow(clippy::too_many_arguments)]
+    fn matmul_transposed(
+        // An `m` by `k` row-major matrix, or `k` by `m` if `transpose_a` transposes.
+        a: &[Self],
+        // Distance between the starts of consecutive rows of `a`.
+        lda: usize,
+        // Whether `a` holds the left operand, its transpose or its conjugate transpose.
+        transpose_a: cblas::Transpose,
+        // An `k` by `n` row-major matrix, or `n` by `k` if `transpose_b` transposes.
+        b: &[Self],
+        // Distance between the starts of consecutive rows of `b`.
+        ldb: usize,
+        // Whether `b` holds the right operand, its transpose or its conjugate transpose.
+        transpose_b: cblas::Transpose,
+        // An `m` by `n` row-major matrix.
+        c: &mut [Self],
+        // Rows of the left operand and rows of `c`.
+        m: usize,
+        // Columns of the right operand and columns of `c`.
+        n: usize,
+        // Columns of the left operand and rows of the right operand.
+        k: usize,
+    );
+    /// [`InternalMatmul::matmul_transposed`] computing `c = alpha·a·b + beta·c`, where the rows of
+    ///  `c` may not be contiguous.
+    #[allow(clippy::too_many_arguments)]
+    fn matmul_scaled(
+        a: &[Self],
+        lda: usize,
+        transpose_a: cblas::Transpose,
+        b: &[Self],
+        ldb: usize,
+        transpose_b: cblas::Transpose,
+        // An `m` by `n` row-major matrix.
+        c: &mut [Self],
+        // Distance between the starts of consecutive rows of `c`.
+        ldc: usize,
+        m: usize,
+        n: usize,
+        k: usize,
+        // Scales the product.
+        alpha: Self,
+        // Scales `c` before the product is added.
+        beta: Self,
+    );
+    /// [`InternalMatmul::matmul`] over a batch of products of the same shape.
+    fn matmul_batch<'a>(
+        // The `m` by `k` and `k` by `n` row-major operands of each product, along with the `m` by
+        //  `n` row-major matrix it is added to.
+        products: impl Iterator<Item = Product<'a, Self>>,
+        m: usize,
+        n: usize,
+        k: usize,
+    ) where
+        Self: 'a;
+    /// [`InternalMatmul::matmul_batch`] computing the products in parallel.
+    #[cfg(feature = "parallel")]
+    fn par_matmul_batch<'a>(
+        products: impl ParallelIterator<Item = Product<'a, Self>>,
+        m: usize,
+        n: usize,
+        k: usize,
+    ) where
+        Self: Send + Sync + 'a;
+}
+/// Asserts a `rows` by `columns` row-major matrix with rows `ld` apart fits within `len` elements.
+pub(crate) fn assert_fits(len: usize, rows: usize, columns: usize, ld: usize) {
+    assert!(ld >= columns, "Leading dimension less than columns");
+    assert!(
+        rows == 0 || columns == 0 || len >= (rows - 1) * ld + columns,
+        "Matrix exceeds data"
     );
 }
+/// Asserts the operands of [`InternalMatmul::matmul_transposed`] fit within their data.
+#[allow(clippy::too_many_arguments)]
+fn assert_operands<T>(
+    a: &[T],
+    lda: usize,
+    transpose_a: cblas::Transpose,
+    b: &[T],
+    ldb: usize,
+    transpose_b: cblas::Transpose,
+    c: &[T],
+    ldc: usize,
+    m: usize,
+    n: usize,
+    k: usize,
+) {
+    let swap = |t, x: usize, y: usize| if transposed(t) { (y, x) } else { (x, y) };
+    let (rows, columns) = swap(transpose_a, m, k);
+    assert_fits(a.len(), rows, columns, lda);
+    let (rows, columns) = swap(transpose_b, k, n);
+    assert_fits(b.len(), rows, columns, ldb);
+    assert_fits(c.len(), m, n, ldc);
+}
+/// Whether an operand is read as its transpose, conjugated or not.
+fn transposed(x: cblas::Transpose) -> bool {
+    !matches!(x, cblas::Transpose::None)
+}
+/// How an operand held transposed when `transpose` and conjugated when `conjugate` is read, BLAS
+///  only conjugating along with a transpose.
+fn op(transpose: bool, conjugate: bool) -> cblas::Transpose {
+    match (transpose, conjugate) {
+        (false, false) => cblas::Transpose::None,
+        (true, false) => cblas::Transpose::Ordinary,
+        (true, true) => cblas::Transpose::Conjugate,
+        (false, true) => unreachable!("Conjugate without transpose"),
+    }
+}
+/// The operands of one product of an [`InternalMatmul::matmul_batch`], `((a, b), c)`.
+type Product<'a, T> = ((&'a [T], &'a [T]), &'a mut [T]);
+/// Multiply-adds per product below which a batch runs through the blocked kernel with its packing
+///  buffers shared by every product, rather than calling [`InternalMatmul::matmul`] per product.
+const BATCH_BLOCKED: usize = 32 * 32 * 32;
+/// Whether a value is its default, zero for numbers, where this can be compared.
+trait IsDefault {
+    fn is_default(&self) -> bool;
+}
+impl<T> IsDefault for T {
+    default fn is_default(&self) -> bool {
+        false
+    }
+}
+impl<T: Default + PartialEq> IsDefault for T {
+    fn is_default(&self) -> bool {
+        *self == T::default()
+    }
+}
+/// Complex conjugation, the identity for other types.
+trait Conjugate {
+    fn conjugate(self) -> Self;
+}
+impl<T> Conjugate for T {
+    default fn conjugate(self) -> Self {
+        self
+    }
+}
+impl<T: Clone + Num + Neg<Output = T>> Conjugate for Complex<T> {
+    fn conjugate(self) -> Self {
+        self.conj()
+    }
+}
 /// Default matrix multiplication implementation.
-impl<T: Debug + Mul<Output = T> + AddAssign + Copy + Debug> InternalMatmul for T {
-    default fn matmul(a: &[T], b: &[T], c: &mut [T], m: usize, n: usize, k: usize) {
-        debug_assert_eq!(a.len(), m * k);
-        debug_assert_eq!(b.len(), k * n);
+impl<T: Debug + Default + Mul<Output = T> + AddAssign + Copy> InternalMatmul for T {
+    default fn matmul_transposed(
+        a: &[T],
+        lda: usize,
+        transpose_a: cblas::Transpose,
+        b: &[T],
+        ldb: usize,
+        transpose_b: cblas::Transpose,
+        c: &mut [T],
+        m: usize,
+        n: usize,
+        k: usize,
+    ) {
         debug_assert_eq!(c.len(), m * n);
+        let a = Strided::op(a, lda, transpose_a);
+        let b = Strided::op(b, ldb, transpose_b);
+        blocked::<T, Ordinary>(a, b, c, n, m, n, k, |x| x);
+    }
+    default fn matmul_scaled(
+        a: &[T],
+        lda: usize,
+        transpose_a: cblas::Transpose,
+        b: &[T],
+        ldb: usize,
+        transpose_b: cblas::Transpose,
+        c: &mut [T],
+        ldc: usize,
+        m: usize,
+        n: usize,
+        k: usize,
+        alpha: T,
+        beta: T,
+    ) {
+        assert_operands(a, lda, transpose_a, b, ldb, transpose_b, c, ldc, m, n, k);
+        // As in BLAS `c` is not read when `beta` is zero, such that it may hold `NaN`s.
+        let overwrite = beta.is_default();
+        for row in c.chunks_mut(ldc.max(1)).take(m) {
+            for x in &mut row[..n] {
+                *x = match overwrite {
+                    true => T::default(),
+                    false => beta * *x,
+                };
+            }
+        }
+        let a = Strided::op(a, lda, transpose_a);
+        let b = Strided::op(b, ldb, transpose_b);
+        // Scaling `a` as it is packed scales the product at no extra cost.
+        blocked::<T, Ordinary>(a, b, c, ldc, m, n, k, |x| alpha * x);
+    }
+    default fn matmul_batch<'a>(
+        products: impl Iterator<Item = Product<'a, T>>,
+        m: usize,
+        n: usize,
+        k: usize,
+    ) where
+        T: 'a,
+    {
+        if m * n * k < BATCH_BLOCKED {
+            let mut packing = Packing::new(T::default(), m, n, k);
+            for ((a, b), c) in products {
+                let (a, b) = (Strided::new(a, k, false), Strided::new(b, n, false));
+                blocked_with::<T, Ordinary>(&mut packing, a, b, c, n, m, n, k, |x| x);
+            }
+        } else {
+            for ((a, b), c) in products {
+                T::matmul(a, b, c, m, n, k);
+            }
+        }
+    }
+    #[cfg(feature = "parallel")]
+    default fn par_matmul_batch<'a>(
+        products: impl ParallelIterator<Item = Product<'a, T>>,
+        m: usize,
+        n: usize,
+        k: usize,
+    ) where
+        T: Send + Sync + 'a,
+    {
+        if m * n * k < BATCH_BLOCKED {
+            // Each thread packs into its own buffers.
+            products.for_each_init(
+                || Packing::new(T::default(), m, n, k),
+                |packing, ((a, b), c)| {
+                    let (a, b) = (Strided::new(a, k, false), Strided::new(b, n, false));
+                    blocked_with::<T, Ordinary>(packing, a, b, c, n, m, n, k, |x| x);
+                },
+            );
+        } else {
+            products.for_each(|((a, b), c)| T::matmul(a, b, c, m, n, k));
+        }
+    }
+}
 
-        for l_index in 0..m {
-            for m_index in 0..k {
-                for n_index in 0..n {
-                    let (i, j, k) = (
-                        l_index * n + n_index,
-                        l_index * k + m_index,
-                        m_index * n + n_index,
-                    );
-                    c[i] += a[j] * b[k];
+// Blocked kernel
+// --------------------------------------------------
+// The default multiplication follows the GotoBLAS design. `C` is split into `NC` wide column
+//  panels and `K` into `KC` deep slices, for each the `KC` by `NC` block of `B` is packed such
+//  that it stays in L3 cache. Then `A` is split into `MC` tall row blocks, each packed to stay in
+//  L2 cache, and every `MR` by `NR` tile of `C` is computed by a micro-kernel whose operands are
+//  read contiguously and whose accumulators stay in registers.
+
+/// Rows of the tile of `C` computed by the micro-kernel.
+const MR: usize = 4;
+/// Columns of the tile of `C` computed by the micro-kernel.
+const NR: usize = 4;
+/// Rows of `A` packed at a time.
+const MC: usize = 64;
+/// Columns of `A` and rows of `B` packed at a time.
+const KC: usize = 256;
+/// Columns of `B` packed at a time.
+const NC: usize = 1024;
+
+/// The operations [`blocked`] multiplies with.
+pub(crate) trait Ops<T> {
+    /// The identity of `add`, padding packed operands and starting each accumulation.
+    fn zero() -> T;
+    fn add(x: T, y: T) -> T;
+    fn mul(x: T, y: T) -> T;
+}
+/// Ordinary arithmetic, under the bounds of the default [`InternalMatmul`].
+struct Ordinary;
+impl<T: Default + Mul<Output = T> + AddAssign + Copy> Ops<T> for Ordinary {
+    fn zero() -> T {
+        T::default()
+    }
+    fn add(mut x: T, y: T) -> T {
+        x += y;
+        x
+    }
+    fn mul(x: T, y: T) -> T {
+        x * y
+    }
+}
+/// A row-major operand of [`blocked`], which may be given as its transpose or conjugate.
+#[derive(Clone, Copy)]
+pub(crate) struct Strided<'a, T> {
+    data: &'a [T],
+    ld: usize,
+    transpose: bool,
+    conjugate: bool,
+}
+impl<'a, T: Copy> Strided<'a, T> {
+    pub(crate) fn new(data: &'a [T], ld: usize, transpose: bool) -> Self {
+        Self {
+            data,
+            ld,
+            transpose,
+            conjugate: false,
+        }
+    }
+    /// The operand read as its conjugate, which is done as it is packed.
+    pub(crate) fn conjugated(self) -> Self {
+        Self {
+            conjugate: true,
+            ..self
+        }
+    }
+    /// The operand held in `data` as read by BLAS under `transpose`.
+    fn op(data: &'a [T], ld: usize, transpose: cblas::Transpose) -> Self {
+        let x = Self::new(data, ld, transposed(transpose));
+        match transpose {
+            cblas::Transpose::Conjugate => x.conjugated(),
+            _ => x,
+        }
+    }
+    /// Element `i`,`j` of the operand.
+    fn get(&self, i: usize, j: usize) -> T {
+        let x = match self.transpose {
+            true => self.data[j * self.ld + i],
+            false => self.data[i * self.ld + j],
+        };
+        match self.conjugate {
+            true => x.conjugate(),
+            false => x,
+        }
+    }
+}
+/// The buffers [`blocked`] packs operands into, which the products of a batch of the same shape
+///  share.
+struct Packing<T> {
+    a: Vec<T>,
+    b: Vec<T>,
+}
+impl<T: Copy> Packing<T> {
+    /// Buffers for an `m` by `k` times `k` by `n` product, padded with `zero`.
+    fn new(zero: T, m: usize, n: usize, k: usize) -> Self {
+        Self {
+            a: vec![zero; MC.min(round_up(m, MR)) * KC.min(k)],
+            b: vec![zero; KC.min(k) * NC.min(round_up(n, NR))],
+        }
+    }
+}
+/// `c += a·b` under the operations `O`, where `a` is `m` by `k`, `b` is `k` by `n` and the rows
+///  of `c` are `ldc` apart, with each element of `a` passed through `scale` as it is packed.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn blocked<T: Copy, O: Ops<T>>(
+    a: Strided<T>,
+    b: Strided<T>,
+    c: &mut [T],
+    ldc: usize,
+    m: usize,
+    n: usize,
+    k: usize,
+    scale: impl Fn(T) -> T,
+) {
+    let mut packing = Packing::new(O::zero(), m, n, k);
+    blocked_with::<T, O>(&mut packing, a, b, c, ldc, m, n, k, scale);
+}
+/// [`blocked`] packing into `packing`, which must be sized for the product.
+#[allow(clippy::too_many_arguments)]
+fn blocked_with<T: Copy, O: Ops<T>>(
+    packing: &mut Packing<T>,
+    a: Strided<T>,
+    b: Strided<T>,
+    c: &mut [T],
+    ldc: usize,
+    m: usize,
+    n: usize,
+    k: usize,
+    scale: impl Fn(T) -> T,
+) {
+    let Packing {
+        a: packed_a,
+        b: packed_b,
+    } = packing;
+    for jc in (0..n).step_by(NC) {
+        let nc = NC.min(n - jc);
+        for pc in (0..k).step_by(KC) {
+            let kc = KC.min(k - pc);
+            pack(packed_b, kc, nc, NR, O::zero(), |p, j| {
+                b.get(pc + p, jc + j)
+            });
+            for ic in (0..m).step_by(MC) {
+                let mc = MC.min(m - ic);
+                pack(packed_a, kc, mc, MR, O::zero(), |p, i| {
+                    scale(a.get(ic + i, pc + p))
+                });
+                for jr in (0..nc).step_by(NR) {
+                    let b = &packed_b[jr * kc..(jr + NR) * kc];
+                    for ir in (0..mc).step_by(MR) {
+                        let a = &packed_a[ir * kc..(ir + MR) * kc];
+                        let offset = (ic + ir) * ldc + jc + jr;
+                        micro_kernel::<T, O>(
+                            a,
+                            b,
+                            &mut c[offset..],
+                            ldc,
+                            MR.min(mc - ir),
+                            NR.min(nc - jr),
+                        );
+                    }
                 }
             }
         }
     }
 }
+fn round_up(x: usize, multiple: usize) -> usize {
+    (x + multiple - 1) / multiple * multiple
+}
+/// Packs `len` rows (or columns) of an operand `depth` deep into slivers `width` wide, where
+///  `get(p, i)` is element `p` along the depth of row (or column) `i`.
+///
+/// Each sliver holds its `width` elements at depth `0`, then at depth `1` and so on, with the
+///  last sliver padded with `zero`.
+fn pack<T: Copy>(
+    packed: &mut [T],
+    depth: usize,
+    len: usize,
+    width: usize,
+    zero: T,
+    get: impl Fn(usize, usize) -> T,
+) {
+    for (s, sliver) in packed
+        .chunks_exact_mut(width * depth)
+        .take(round_up(len, width) / width)
+        .enumerate()
+    {
+        for (p, elements) in sliver.chunks_exact_mut(width).enumerate() {
+            for (i, x) in elements.iter_mut().enumerate() {
+                let i = s * width + i;
+                *x = if i < len { get(p, i) } else { zero };
+            }
+        }
+    }
+}
+/// `c += a·b` for an `MR` by `NR` tile of `c` with rows `ldc` apart, where `a` and `b` are
+///  packed slivers and only the leading `mr` by `nr` of the tile is written.
+fn micro_kernel<T: Copy, O: Ops<T>>(
+    a: &[T],
+    b: &[T],
+    c: &mut [T],
+    ldc: usize,
+    mr: usize,
+    nr: usize,
+) {
+    let mut tile = [[O::zero(); NR]; MR];
+    for (a, b) in a.chunks_exact(MR).zip(b.chunks_exact(NR)) {
+        for (row, &x) in tile.iter_mut().zip(a) {
+            for (y, &z) in row.iter_mut().zip(b) {
+                *y = O::add(*y, O::mul(x, z));
+            }
+        }
+    }
+    for (i, row) in tile.iter().enumerate().take(mr) {
+        for (j, x) in row.iter().enumerate().take(nr) {
+            c[i * ldc + j] = O::add(c[i * ldc + j], *x);
+        }
+    }
+}
+// Unrolled kernel
+// --------------------------------------------------
+// Products of small static matrices skip both the blocked kernel and BLAS, whose packing and
+//  call overhead outweighs the product itself. Every loop bound is a constant, so the loops are
+//  fully unrolled and the operands and result never leave the stack.
+
+/// The largest `M·N·K` of a static product computed by [`unrolled`], that of two 4 by 4 matrices.
+const UNROLL: usize = 64;
+
+/// `a·b` where `a` is `M` by `K` and `b` is `K` by `N`, both row-major.
+#[inline(always)]
+fn unrolled<
+    T: Default + Mul<Output = T> + AddAssign + Copy,
+    const M: usize,
+    const K: usize,
+    const N: usize,
+>(
+    a: &[T; M * K],
+    b: &[T; K * N],
+) -> [T; M * N]
+where
+    [(); M * K]:,
+    [(); K * N]:,
+    [(); M * N]:,
+{
+    let mut c = [T::default(); M * N];
+    for i in 0..M {
+        for j in 0..N {
+            let mut x = T::default();
+            for l in 0..K {
+                x += a[i * K + l] * b[l * N + j];
+            }
+            c[i * N + j] = x;
+        }
+    }
+    c
+}
 /// `f32` matrix multiplication specialization.
 impl InternalMatmul for f32 {
-    fn matmul(a: &[f32], b: &[f32], c: &mut [f32], m: usize, n: usize, k: usize) {
-        assert_eq!(a.len(), m * k);
-        assert_eq!(b.len(), n * k);
-        assert_eq!(c.len(), m * n);
+    fn matmul_transposed(
+        a: &[f32],
+        lda: usize,
+        transpose_a: cblas::Transpose,
+        b: &[f32],
+        ldb: usize,
+        transpose_b: cblas::Transpose,
+        c: &mut [f32],
+        m: usize,
+        n: usize,
+        k: usize,
+    ) {
+        Self::matmul_scaled(
+            a,
+            lda,
+            transpose_a,
+            b,
+            ldb,
+            transpose_b,
+            c,
+            n,
+            m,
+            n,
+            k,
+            1.,
+            1.,
+        );
+    }
+    fn matmul_scaled(
+        a: &[f32],
+        lda: usize,
+        transpose_a: cblas::Transpose,
+        b: &[f32],
+        ldb: usize,
+        transpose_b: cblas::Transpose,
+        c: &mut [f32],
+        ldc: usize,
+        m: usize,
+        n: usize,
+        k: usize,
+        alpha: f32,
+        beta: f32,
+    ) {
+        assert_operands(a, lda, transpose_a, b, ldb, transpose_b, c, ldc, m, n, k);
+        let (m, n, k) = (m as i32, n as i32, k as i32);
+        let (lda, ldb, ldc) = (lda as i32, ldb as i32, ldc as i32);
+        unsafe {
+            if n == 1 {
+                // A matrix by column vector product, where the elements of `b` are `ldb` apart
+                //  unless it is given as a row.
+                let (rows, columns) = if transposed(transpose_a) {
+                    (k, m)
+                } else {
+                    (m, k)
+                };
+                cblas::sgemv(
+                    cblas::Layout::RowMajor,
+                    transpose_a,
+                    rows,
+                    columns,
+                    alpha,
+                    a,
+                    lda,
+                    b,
+                    if transposed(transpose_b) { 1 } else { ldb },
+                    beta,
+                    c,
+                    ldc,
+                );
+            } else {
+                cblas::sgemm(
+                    cblas::Layout::RowMajor,
+                    transpose_a,
+                    transpose_b,
+                    m,
+                    n,
+                    k,
+                    alpha,
+                    a,
+                    lda,
+                    b,
+                    ldb,
+                    beta,
+                    c,
+                    ldc,
+                );
+            }
+        }
+    }
+    // BLAS outruns the blocked kernel even on small products, so each product calls it.
+    fn matmul_batch<'a>(
+        products: impl Iterator<Item = Product<'a, f32>>,
+        m: usize,
+        n: usize,
+        k: usize,
+    ) {
+        for ((a, b), c) in products {
+            Self::matmul(a, b, c, m, n, k);
+        }
+    }
+    #[cfg(feature = "parallel")]
+    fn par_matmul_batch<'a>(
+        products: impl ParallelIterator<Item = Product<'a, f32>>,
+        m: usize,
+        n: usize,
+        k: usize,
+    ) {
+        products.for_each(|((a, b), c)| Self::matmul(a, b, c, m, n, k));
+    }
+}
+/// `f64` matrix multiplication specialization.
+impl InternalMatmul for f64 {
+    fn matmul_transposed(
+        a: &[f64],
+        lda: usize,
+        transpose_a: cblas::Transpose,
+        b: &[f64],
+        ldb: usize,
+        transpose_b: cblas::Transpose,
+        c: &mut [f64],
+        m: usize,
+        n: usize,
+        k: usize,
+    ) {
+        Self::matmul_scaled(
+            a,
+            lda,
+            transpose_a,
+            b,
+            ldb,
+            transpose_b,
+            c,
+            n,
+            m,
+            n,
+            k,
+            1.,
+            1.,
+        );
+    }
+    fn matmul_scaled(
+        a: &[f64],
+        lda: usize,
+        transpose_a: cblas::Transpose,
+        b: &[f64],
+        ldb: usize,
+        transpose_b: cblas::Transpose,
+        c: &mut [f64],
+        ldc: usize,
+        m: usize,
+        n: usize,
+        k: usize,
+        alpha: f64,
+        beta: f64,
+    ) {
+        assert_operands(a, lda, transpose_a, b, ldb, transpose_b, c, ldc, m, n, k);
+        let (m, n, k) = (m as i32, n as i32, k as i32);
+        let (lda, ldb, ldc) = (lda as i32, ldb as i32, ldc as i32);
+        unsafe {
+            if n == 1 {
+                // A matrix by column vector product, where the elements of `b` are `ldb` apart
+                //  unless it is given as a row.
+                let (rows, columns) = if transposed(transpose_a) {
+                    (k, m)
+                } else {
+                    (m, k)
+                };
+                cblas::dgemv(
+                    cblas::Layout::RowMajor,
+                    transpose_a,
+                    rows,
+                    columns,
+                    alpha,
+                    a,
+                    lda,
+                    b,
+                    if transposed(transpose_b) { 1 } else { ldb },
+                    beta,
+                    c,
+                    ldc,
+                );
+            } else {
+                cblas::dgemm(
+                    cblas::Layout::RowMajor,
+                    transpose_a,
+                    transpose_b,
+                    m,
+                    n,
+                    k,
+                    alpha,
+                    a,
+                    lda,
+                    b,
+                    ldb,
+                    beta,
+                    c,
+                    ldc,
+                );
+            }
+        }
+    }
+    // BLAS outruns the blocked kernel even on small products, so each product calls it.
+    fn matmul_batch<'a>(
+        products: impl Iterator<Item = Product<'a, f64>>,
+        m: usize,
+        n: usize,
+        k: usize,
+    ) {
+        for ((a, b), c) in products {
+            Self::matmul(a, b, c, m, n, k);
+        }
+    }
+    #[cfg(feature = "parallel")]
+    fn par_matmul_batch<'a>(
+        products: impl ParallelIterator<Item = Product<'a, f64>>,
+        m: usize,
+        n: usize,
+        k: usize,
+    ) {
+        products.for_each(|((a, b), c)| Self::matmul(a, b, c, m, n, k));
+    }
+}
+/// `Complex<f32>` matrix multiplication specialization.
+impl InternalMatmul for Complex<f32> {
+    fn matmul_transposed(
+        a: &[Complex<f32>],
+        lda: usize,
+        transpose_a: cblas::Transpose,
+        b: &[Complex<f32>],
+        ldb: usize,
+        transpose_b: cblas::Transpose,
+        c: &mut [Complex<f32>],
+        m: usize,
+        n: usize,
+        k: usize,
+    ) {
+        Self::matmul_scaled(
+            a,
+            lda,
+            transpose_a,
+            b,
+            ldb,
+            transpose_b,
+            c,
+            n,
+            m,
+            n,
+            k,
+            Complex::new(1., 0.),
+            Complex::new(1., 0.),
+        );
+    }
+    fn matmul_scaled(
+        a: &[Complex<f32>],
+        lda: usize,
+        transpose_a: cblas::Transpose,
+        b: &[Complex<f32>],
+        ldb: usize,
+        transpose_b: cblas::Transpose,
+        c: &mut [Complex<f32>],
+        ldc: usize,
+        m: usize,
+        n: usize,
+        k: usize,
+        alpha: Complex<f32>,
+        beta: Complex<f32>,
+    ) {
+        assert_operands(a, lda, transpose_a, b, ldb, transpose_b, c, ldc, m, n, k);
         let (m, n, k) = (m as i32, n as i32, k as i32);
+        let (lda, ldb, ldc) = (lda as i32, ldb as i32, ldc as i32);
         unsafe {
-            cblas::sgemm(
+            cblas::cgemm(
                 cblas::Layout::RowMajor,
-                cblas::Transpose::None,
-                cblas::Transpose::None,
+                transpose_a,
+                transpose_b,
                 m,
                 n,
                 k,
-                1.,
+                alpha,
                 a,
-                k,
+                lda,
                 b,
-                n,
-                1.,
+                ldb,
+                beta,
                 c,
-                n,
+                ldc,
             );
         }
     }
+    // BLAS outruns the blocked kernel even on small products, so each product calls it.
+    fn matmul_batch<'a>(
+        products: impl Iterator<Item = Product<'a, Complex<f32>>>,
+        m: usize,
+        n: usize,
+        k: usize,
+    ) {
+        for ((a, b), c) in products {
+            Self::matmul(a, b, c, m, n, k);
+        }
+    }
+    #[cfg(feature = "parallel")]
+    fn par_matmul_batch<'a>(
+        products: impl ParallelIterator<Item = Product<'a, Complex<f32>>>,
+        m: usize,
+        n: usize,
+        k: usize,
+    ) {
+        products.for_each(|((a, b), c)| Self::matmul(a, b, c, m, n, k));
+    }
 }
-/// `f64` matrix multiplication specialization.
-impl InternalMatmul for f64 {
-    fn matmul(a: &[f64], b: &[f64], c: &mut [f64], m: usize, n: usize, k: usize) {
-        assert_eq!(a.len(), m * k);
-        assert_eq!(b.len(), n * k);
-        assert_eq!(c.len(), m * n);
+/// `Complex<f64>` matrix multiplication specialization.
+impl InternalMatmul for Complex<f64> {
+    fn matmul_transposed(
+        a: &[Complex<f64>],
+        lda: usize,
+        transpose_a: cblas::Transpose,
+        b: &[Complex<f64>],
+        ldb: usize,
+        transpose_b: cblas::Transpose,
+        c: &mut [Complex<f64>],
+        m: usize,
+        n: usize,
+        k: usize,
+    ) {
+        Self::matmul_scaled(
+            a,
+            lda,
+            transpose_a,
+            b,
+            ldb,
+            transpose_b,
+            c,
+            n,
+            m,
+            n,
+            k,
+            Complex::new(1., 0.),
+            Complex::new(1., 0.),
+        );
+    }
+    fn matmul_scaled(
+        a: &[Complex<f64>],
+        lda: usize,
+        transpose_a: cblas::Transpose,
+        b: &[Complex<f64>],
+        ldb: usize,
+        transpose_b: cblas::Transpose,
+        c: &mut [Complex<f64>],
+        ldc: usize,
+        m: usize,
+        n: usize,
+        k: usize,
+        alpha: Complex<f64>,
+        beta: Complex<f64>,
+    ) {
+        assert_operands(a, lda, transpose_a, b, ldb, transpose_b, c, ldc, m, n, k);
         let (m, n, k) = (m as i32, n as i32, k as i32);
+        let (lda, ldb, ldc) = (lda as i32, ldb as i32, ldc as i32);
         unsafe {
-            cblas::dgemm(
+            cblas::zgemm(
                 cblas::Layout::RowMajor,
-                cblas::Transpose::None,
-                cblas::Transpose::None,
+                transpose_a,
+                transpose_b,
                 m,
                 n,
                 k,
-                1.,
+                alpha,
                 a,
-                k,
+                lda,
                 b,
-                n,
-                1.,
+                ldb,
+                beta,
                 c,
-                n,
+                ldc,
             );
         }
     }
+    // BLAS outruns the blocked kernel even on small products, so each product calls it.
+    fn matmul_batch<'a>(
+        products: impl Iterator<Item = Product<'a, Complex<f64>>>,
+        m: usize,
+        n: usize,
+        k: usize,
+    ) {
+        for ((a, b), c) in products {
+            Self::matmul(a, b, c, m, n, k);
+        }
+    }
+    #[cfg(feature = "parallel")]
+    fn par_matmul_batch<'a>(
+        products: impl ParallelIterator<Item = Product<'a, Complex<f64>>>,
+        m: usize,
+        n: usize,
+        k: usize,
+    ) {
+        products.for_each(|((a, b), c)| Self::matmul(a, b, c, m, n, k));
+    }
 }
 
 /// A trait for matrix multiplication.
@@ -112,261 +956,561 @@ pub trait Matmul<T> {
     /// - N: Columns of `other` and columns of `Self::Output`.
     fn matmul(&self, other: &T) -> Self::Output;
 }
+/// A trait for multiplying batches of matrices.
+///
+/// Each product is as given by [`Matmul`], with the data of each matrix passed to the kernel in
+///  place. Products of `f32`, `f64` and their complex counterparts call BLAS once each, while
+///  batches of other small products run through the blocked kernel sharing its packing buffers
+///  rather than allocating these per pair. All matrices of a batch must be the same shape.
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// use static_la::*;
+/// let a = vec![MatrixSxS::<f32, 2, 3>::from([[1., 3., 5.], [2., 4., 6.]]); 3];
+/// let b = vec![MatrixSxS::<f32, 3, 1>::from([[1.], [0.], [1.]]); 3];
+/// let c: Vec<MatrixSxS<f32, 2, 1>> = a.matmul_batch(&b);
+/// assert_eq!(c, vec![MatrixSxS::from([[6.], [8.]]); 3]);
+/// ```
+pub trait MatmulBatch<Rhs> {
+    type Output;
+    fn matmul_batch(&self, other: &[Rhs]) -> Self::Output;
+}
+/// [`MatmulBatch`] computing the products in parallel.
+#[doc(cfg(feature = "parallel"))]
+#[cfg(feature = "parallel")]
+pub trait ParMatmulBatch<Rhs> {
+    type Output;
+    fn par_matmul_batch(&self, other: &[Rhs]) -> Self::Output;
+}
+/// A trait for multiplying a matrix in place by a square matrix, `a = a·b`.
+///
+/// The product is written over `self`, so only the copy of `self` it is computed from is
+///  allocated, and not even that when `self` is static. Repeated products of dynamic matrices
+///  can avoid this allocation by copying into a reused matrix with
+///  [`MatmulAssign::matmul_assign_with`].
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// use static_la::*;
+/// let mut a = MatrixSxS::from([[1, 2], [3, 4], [5, 6]]);
+/// a.matmul_assign(&MatrixSxS::from([[0, 1], [1, 0]]));
+/// assert_eq!(a, MatrixSxS::from([[2, 1], [4, 3], [6, 5]]));
+/// ```
+pub trait MatmulAssign<Rhs> {
+    fn matmul_assign(&mut self, other: &Rhs);
+    /// [`MatmulAssign::matmul_assign`] copying `self` into `scratch`, which must be of the same
+    ///  shape, rather than allocating the copy.
+    /// ```
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use static_la::*;
+    /// let mut a = MatrixDxD::from((2, 2, 1));
+    /// let mut scratch = MatrixDxD::from((2, 2, 0));
+    /// for _ in 0..3 {
+    ///     a.matmul_assign_with(&MatrixSxS::from([[1, 1], [0, 1]]), &mut scratch);
+    /// }
+    /// assert_eq!(a, MatrixSxS::from([[1, 4], [1, 4]]));
+    /// ```
+    fn matmul_assign_with(&mut self, other: &Rhs, scratch: &mut Self);
+}
 
-// MatrixDxD
+// Matrix
 // --------------------------------------------------
-impl<T: Debug + Default + Copy + AddAssign + Mul<Output = T>, const K: usize, const N: usize>
-    Matmul<MatrixSxS<T, K, N>> for MatrixDxD<T>
+impl<T, R: Dim, C: Dim, L: Layout, B: blas::Operand<T>> Matmul<B> for Matrix<T, R, C, L>
 where
-    [(); K * N]:,
+    T: Debug + Default + Copy + AddAssign + Mul<Output = T>,
+    C: Join<B::Rows>,
+    (R, C, L): Storage<T>,
+    (R, B::Columns, L): Storage<T>,
 {
-    type Output = MatrixDxS<T, N>;
-    fn matmul(&self, other: &MatrixSxS<T, K, N>) -> Self::Output {
-        assert_eq!(self.columns, K, "Non-matching columns to rows");
-
-        let m = self.rows;
-        let mut data = vec![Default::default(); N * m];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, m, N, K);
-        Self::Output { data, rows: m }
+    type Output = Matrix<T, R, B::Columns, L>;
+    default fn matmul(&self, other: &B) -> Self::Output {
+        product(self, other)
     }
 }
-impl<T: Debug + Default + Copy + AddAssign + Mul<Output = T>, const N: usize>
-    Matmul<MatrixDxS<T, N>> for MatrixDxD<T>
+impl<T, const M: usize, const K: usize, const N: usize> Matmul<MatrixSxS<T, K, N>>
+    for MatrixSxS<T, M, K>
+where
+    T: Debug + Default + Copy + AddAssign + Mul<Output = T>,
+    [(); M * K]:,
+    [(); K * N]:,
+    [(); M * N]:,
 {
-    type Output = MatrixDxS<T, N>;
-    fn matmul(&self, other: &MatrixDxS<T, N>) -> Self::Output {
-        assert_eq!(self.columns, other.rows, "Non-matching columns to rows");
-
-        let (m, k) = (self.rows, self.columns);
-        let mut data = vec![Default::default(); m * N];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, m, N, k);
-        Self::Output { data, rows: m }
+    fn matmul(&self, other: &MatrixSxS<T, K, N>) -> Self::Output {
+        // The shape is constant, so only one of the branches is kept.
+        if M * N * K <= UNROLL {
+            Matrix {
+                data: unrolled::<T, M, K, N>(&self.data, &other.data),
+                rows: Static,
+                columns: Static,
+            }
+        } else {
+            product(self, other)
+        }
     }
 }
-impl<T: Debug + Default + Copy + AddAssign + Mul<Output = T> + std::fmt::Debug, const K: usize>
-    Matmul<MatrixSxD<T, K>> for MatrixDxD<T>
+// View
+// --------------------------------------------------
+impl<'a, T: Debug + Default + Copy + AddAssign + Mul<Output = T>, B: blas::Operand<T>> Matmul<B>
+    for ViewDxD<'a, T>
+where
+    Dynamic: Join<B::Rows>,
+    (Dynamic, B::Columns, RowMajor): Storage<T>,
 {
-    type Output = MatrixDxD<T>;
-    fn matmul(&self, other: &MatrixSxD<T, K>) -> Self::Output {
-        let (m, n) = (self.rows, other.columns);
-        let mut data = vec![Default::default(); m * n];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, m, n, K);
-        Self::Output {
-            data,
-            rows: m,
-            columns: n,
-        }
+    type Output = Matrix<T, Dynamic, B::Columns>;
+    fn matmul(&self, other: &B) -> Self::Output {
+        product(self, other)
     }
 }
-impl<T: Debug + Default + Copy + AddAssign + Mul<Output = T> + std::fmt::Debug> Matmul<MatrixDxD<T>>
-    for MatrixDxD<T>
+impl<
+        'a,
+        T: Debug + Default + Copy + AddAssign + Mul<Output = T>,
+        B: blas::Operand<T>,
+        const COLUMNS: usize,
+    > Matmul<B> for ViewDxS<'a, T, COLUMNS>
+where
+    Static<COLUMNS>: Join<B::Rows>,
+    (Dynamic, B::Columns, RowMajor): Storage<T>,
 {
-    type Output = MatrixDxD<T>;
-    fn matmul(&self, other: &MatrixDxD<T>) -> Self::Output {
-        assert_eq!(self.columns, other.rows, "Non-matching columns to rows");
-
-        let (m, k, n) = (self.rows, self.columns, other.columns);
-        let mut data = vec![Default::default(); m * n];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, m, n, k);
-        Self::Output {
-            data,
-            rows: m,
-            columns: n,
-        }
+    type Output = Matrix<T, Dynamic, B::Columns>;
+    fn matmul(&self, other: &B) -> Self::Output {
+        product(self, other)
     }
 }
-// MatrixDxS
-// --------------------------------------------------
-impl<T: Debug + Default + Copy + AddAssign + Mul<Output = T>, const K: usize, const N: usize>
-    Matmul<MatrixSxS<T, K, N>> for MatrixDxS<T, K>
+impl<
+        'a,
+        T: Debug + Default + Copy + AddAssign + Mul<Output = T>,
+        B: blas::Operand<T>,
+        const ROWS: usize,
+    > Matmul<B> for ViewSxD<'a, T, ROWS>
 where
-    [(); K * N]:,
+    Dynamic: Join<B::Rows>,
+    (Static<ROWS>, B::Columns, RowMajor): Storage<T>,
 {
-    type Output = MatrixDxS<T, N>;
-    fn matmul(&self, other: &MatrixSxS<T, K, N>) -> Self::Output {
-        let m = self.rows;
-        let mut data = vec![Default::default(); m * N];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, m, N, K);
-        Self::Output { data, rows: m }
+    type Output = Matrix<T, Static<ROWS>, B::Columns>;
+    fn matmul(&self, other: &B) -> Self::Output {
+        product(self, other)
     }
 }
-impl<T: Debug + Default + Copy + AddAssign + Mul<Output = T>, const K: usize, const N: usize>
-    Matmul<MatrixDxS<T, N>> for MatrixDxS<T, K>
+impl<
+        'a,
+        T: Debug + Default + Copy + AddAssign + Mul<Output = T>,
+        B: blas::Operand<T>,
+        const ROWS: usize,
+        const COLUMNS: usize,
+    > Matmul<B> for ViewSxS<'a, T, ROWS, COLUMNS>
+where
+    Static<COLUMNS>: Join<B::Rows>,
+    (Static<ROWS>, B::Columns, RowMajor): Storage<T>,
 {
-    type Output = MatrixDxS<T, N>;
-    fn matmul(&self, other: &MatrixDxS<T, N>) -> Self::Output {
-        let m = self.rows;
-        let mut data = vec![Default::default(); m * N];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, m, N, K);
-        Self::Output { data, rows: m }
+    type Output = Matrix<T, Static<ROWS>, B::Columns>;
+    fn matmul(&self, other: &B) -> Self::Output {
+        product(self, other)
     }
 }
-impl<T: Debug + Default + Copy + AddAssign + Mul<Output = T> + std::fmt::Debug, const K: usize>
-    Matmul<MatrixSxD<T, K>> for MatrixDxS<T, K>
-{
-    type Output = MatrixDxD<T>;
-    fn matmul(&self, other: &MatrixSxD<T, K>) -> Self::Output {
-        let (m, n) = (self.rows, other.columns);
-        let mut data = vec![Default::default(); m * n];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, m, n, K);
-        Self::Output {
-            data,
-            rows: m,
-            columns: n,
+// Transpose
+// --------------------------------------------------
+/// The data of an operand, the distance between its rows and how it is read, where `data` holds
+///  the `rows` by `columns` operand transposed when `transpose` and conjugated when `conjugate`.
+///
+/// BLAS only conjugates along with a transpose, so an operand which is conjugated but not
+///  transposed, as a conjugate transpose read in the other layout is, is conjugated into a copy.
+fn operand<T: Copy>(
+    data: &[T],
+    ld: usize,
+    transpose: bool,
+    conjugate: bool,
+    rows: usize,
+    columns: usize,
+) -> (Cow<[T]>, usize, cblas::Transpose) {
+    match conjugate && !transpose {
+        true => {
+            let rows = data.chunks(ld.max(1)).take(rows);
+            let copy = rows.flat_map(|row| row[..columns].iter().map(|&x| x.conjugate()));
+            (Cow::Owned(copy.collect()), columns, cblas::Transpose::None)
         }
+        false => (Cow::Borrowed(data), ld, op(transpose, conjugate)),
     }
 }
-impl<T: Debug + Default + Copy + AddAssign + Mul<Output = T> + std::fmt::Debug, const K: usize>
-    Matmul<MatrixDxD<T>> for MatrixDxS<T, K>
+/// `a·b` in the layout `L`, where transposed, conjugate transposed and column-major operands are
+///  passed to [`InternalMatmul::matmul_transposed`] by flag rather than copied.
+fn product<T, A, B, L>(a: &A, b: &B) -> Matrix<T, A::Rows, B::Columns, L>
+where
+    T: Debug + Default + Copy + AddAssign + Mul<Output = T>,
+    A: blas::Operand<T>,
+    B: blas::Operand<T>,
+    L: Layout,
+    (A::Rows, B::Columns, L): Storage<T>,
 {
-    type Output = MatrixDxD<T>;
-    fn matmul(&self, other: &MatrixDxD<T>) -> Self::Output {
-        assert_eq!(K, other.rows, "Non-matching columns to rows");
+    use blas::Matrix as _;
+    let (x, y) = (a.stored(), b.stored());
+    let shape =
+        |t: bool, rows: usize, columns: usize| if t { (columns, rows) } else { (rows, columns) };
+    let (m, k) = shape(A::TRANSPOSE, x.rows(), x.columns());
+    let (rows, n) = shape(B::TRANSPOSE, y.rows(), y.columns());
+    assert_eq!(k, rows, "Non-matching columns to rows");
 
-        let (m, n) = (self.rows, other.columns);
-        let mut data = vec![Default::default(); m * n];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, m, n, K);
-        Self::Output {
-            data,
-            rows: m,
-            columns: n,
-        }
+    // Whether the row-major reading of the data of each operand is its transpose.
+    let (ta, tb) = (
+        A::TRANSPOSE ^ x.column_major(),
+        B::TRANSPOSE ^ y.column_major(),
+    );
+    let (lda, ldb) = (x.leading_dimension(), y.leading_dimension());
+    let mut data = <(A::Rows, B::Columns, L)>::collect(std::iter::repeat(T::default()).take(m * n));
+    if L::COLUMN_MAJOR {
+        // The column-major data of `C = A·B` is the row-major data of `Cᵀ = Bᵀ·Aᵀ`.
+        let (b, ldb, tb) = operand(y.data(), ldb, !tb, B::CONJUGATE, n, k);
+        let (a, lda, ta) = operand(x.data(), lda, !ta, A::CONJUGATE, k, m);
+        T::matmul_transposed(&b, ldb, tb, &a, lda, ta, data.as_mut(), n, m, k);
+    } else {
+        let (a, lda, ta) = operand(x.data(), lda, ta, A::CONJUGATE, m, k);
+        let (b, ldb, tb) = operand(y.data(), ldb, tb, B::CONJUGATE, k, n);
+        T::matmul_transposed(&a, lda, ta, &b, ldb, tb, data.as_mut(), m, n, k);
+    }
+    Matrix {
+        data,
+        rows: <A::Rows as Dim>::value(m),
+        columns: <B::Columns as Dim>::value(n),
     }
 }
-// MatrixSxD
-// --------------------------------------------------
-impl<
-        T: Debug + Default + Copy + AddAssign + Mul<Output = T>,
-        const M: usize,
-        const K: usize,
-        const N: usize,
-    > Matmul<MatrixSxS<T, K, N>> for MatrixSxD<T, M>
+impl<'a, T: Debug + Default + Copy + AddAssign + Mul<Output = T>, B: blas::Operand<T>> Matmul<B>
+    for TransposeDxD<'a, T>
 where
-    [(); M * N]:,
-    [(); K * N]:,
+    Dynamic: Join<B::Rows>,
+    (Dynamic, B::Columns, RowMajor): Storage<T>,
 {
-    type Output = MatrixSxS<T, M, N>;
-    fn matmul(&self, other: &MatrixSxS<T, K, N>) -> Self::Output {
-        let mut data = [Default::default(); M * N];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, M, N, K);
-        Self::Output { data }
+    type Output = Matrix<T, Dynamic, B::Columns>;
+    fn matmul(&self, other: &B) -> Self::Output {
+        product(self, other)
     }
 }
-impl<T: Debug + Default + Copy + AddAssign + Mul<Output = T>, const M: usize, const N: usize>
-    Matmul<MatrixDxS<T, N>> for MatrixSxD<T, M>
+impl<
+        'a,
+        T: Debug + Default + Copy + AddAssign + Mul<Output = T>,
+        B: blas::Operand<T>,
+        const COLUMNS: usize,
+    > Matmul<B> for TransposeDxS<'a, T, COLUMNS>
 where
-    [(); M * N]:,
+    Dynamic: Join<B::Rows>,
+    (Static<COLUMNS>, B::Columns, RowMajor): Storage<T>,
 {
-    type Output = MatrixSxS<T, M, N>;
-    fn matmul(&self, other: &MatrixDxS<T, N>) -> Self::Output {
-        assert_eq!(self.columns, other.rows, "Non-matching columns to rows");
-
-        let k = self.columns;
-        let mut data = [Default::default(); M * N];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, M, N, k);
-        Self::Output { data }
+    type Output = Matrix<T, Static<COLUMNS>, B::Columns>;
+    fn matmul(&self, other: &B) -> Self::Output {
+        product(self, other)
     }
 }
 impl<
-        T: Debug + Default + Copy + AddAssign + Mul<Output = T> + std::fmt::Debug,
-        const M: usize,
-        const K: usize,
-    > Matmul<MatrixSxD<T, K>> for MatrixSxD<T, M>
+        'a,
+        T: Debug + Default + Copy + AddAssign + Mul<Output = T>,
+        B: blas::Operand<T>,
+        const ROWS: usize,
+    > Matmul<B> for TransposeSxD<'a, T, ROWS>
+where
+    Static<ROWS>: Join<B::Rows>,
+    (Dynamic, B::Columns, RowMajor): Storage<T>,
 {
-    type Output = MatrixSxD<T, M>;
-    fn matmul(&self, other: &MatrixSxD<T, K>) -> Self::Output {
-        let n = other.columns;
-        let mut data = vec![Default::default(); M * n];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, M, n, K);
-        Self::Output { data, columns: n }
+    type Output = Matrix<T, Dynamic, B::Columns>;
+    fn matmul(&self, other: &B) -> Self::Output {
+        product(self, other)
     }
 }
-impl<T: Debug + Default + Copy + AddAssign + Mul<Output = T> + std::fmt::Debug, const M: usize>
-    Matmul<MatrixDxD<T>> for MatrixSxD<T, M>
+impl<
+        'a,
+        T: Debug + Default + Copy + AddAssign + Mul<Output = T>,
+        B: blas::Operand<T>,
+        const ROWS: usize,
+        const COLUMNS: usize,
+    > Matmul<B> for TransposeSxS<'a, T, ROWS, COLUMNS>
+where
+    [(); ROWS * COLUMNS]:,
+    Static<ROWS>: Join<B::Rows>,
+    (Static<COLUMNS>, B::Columns, RowMajor): Storage<T>,
 {
-    type Output = MatrixSxD<T, M>;
-    fn matmul(&self, other: &MatrixDxD<T>) -> Self::Output {
-        assert_eq!(self.columns, other.rows, "Non-matching columns to rows");
-
-        let (k, n) = (self.columns, other.columns);
-        let mut data = vec![Default::default(); M * n];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, M, n, k);
-        Self::Output { data, columns: n }
+    type Output = Matrix<T, Static<COLUMNS>, B::Columns>;
+    fn matmul(&self, other: &B) -> Self::Output {
+        product(self, other)
     }
 }
-// MatrixSxS
+// Conjugate transpose
 // --------------------------------------------------
-impl<
-        T: Debug + Default + Copy + AddAssign + Mul<Output = T>,
-        const M: usize,
-        const K: usize,
-        const N: usize,
-    > Matmul<MatrixSxS<T, K, N>> for MatrixSxS<T, M, K>
+impl<'a, T, B: blas::Operand<Complex<T>>> Matmul<B> for ConjugateTransposeDxD<'a, T>
 where
-    [(); M * K]:,
-    [(); K * N]:,
-    [(); M * N]:,
+    Complex<T>: Debug + Default + Copy + AddAssign + Mul<Output = Complex<T>>,
+    Dynamic: Join<B::Rows>,
+    (Dynamic, B::Columns, RowMajor): Storage<Complex<T>>,
 {
-    type Output = MatrixSxS<T, M, N>;
-    fn matmul(&self, other: &MatrixSxS<T, K, N>) -> Self::Output {
-        let mut data = [Default::default(); M * N];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, M, N, K);
-        Self::Output { data }
+    type Output = Matrix<Complex<T>, Dynamic, B::Columns>;
+    fn matmul(&self, other: &B) -> Self::Output {
+        product(self, other)
     }
 }
-impl<
-        T: Debug + Default + Copy + AddAssign + Mul<Output = T>,
-        const M: usize,
-        const K: usize,
-        const N: usize,
-    > Matmul<MatrixDxS<T, N>> for MatrixSxS<T, M, K>
+impl<'a, T, B: blas::Operand<Complex<T>>, const COLUMNS: usize> Matmul<B>
+    for ConjugateTransposeDxS<'a, T, COLUMNS>
 where
-    [(); M * K]:,
-    [(); M * N]:,
+    Complex<T>: Debug + Default + Copy + AddAssign + Mul<Output = Complex<T>>,
+    Dynamic: Join<B::Rows>,
+    (Static<COLUMNS>, B::Columns, RowMajor): Storage<Complex<T>>,
 {
-    type Output = MatrixSxS<T, M, N>;
-    fn matmul(&self, other: &MatrixDxS<T, N>) -> Self::Output {
-        let mut data = [Default::default(); M * N];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, M, N, K);
-        Self::Output { data }
+    type Output = Matrix<Complex<T>, Static<COLUMNS>, B::Columns>;
+    fn matmul(&self, other: &B) -> Self::Output {
+        product(self, other)
     }
 }
-impl<
-        T: Debug + Default + Copy + AddAssign + Mul<Output = T> + std::fmt::Debug,
-        const M: usize,
-        const K: usize,
-    > Matmul<MatrixSxD<T, K>> for MatrixSxS<T, M, K>
+impl<'a, T, B: blas::Operand<Complex<T>>, const ROWS: usize> Matmul<B>
+    for ConjugateTransposeSxD<'a, T, ROWS>
 where
-    [(); M * K]:,
+    Complex<T>: Debug + Default + Copy + AddAssign + Mul<Output = Complex<T>>,
+    Static<ROWS>: Join<B::Rows>,
+    (Dynamic, B::Columns, RowMajor): Storage<Complex<T>>,
 {
-    type Output = MatrixSxD<T, M>;
-    fn matmul(&self, other: &MatrixSxD<T, K>) -> Self::Output {
-        let n = other.columns;
-        let mut data = vec![Default::default(); M * n];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, M, n, K);
-        Self::Output { data, columns: n }
+    type Output = Matrix<Complex<T>, Dynamic, B::Columns>;
+    fn matmul(&self, other: &B) -> Self::Output {
+        product(self, other)
     }
 }
-impl<
-        T: Debug + Default + Copy + AddAssign + Mul<Output = T> + std::fmt::Debug,
-        const M: usize,
-        const K: usize,
-    > Matmul<MatrixDxD<T>> for MatrixSxS<T, M, K>
+impl<'a, T, B: blas::Operand<Complex<T>>, const ROWS: usize, const COLUMNS: usize> Matmul<B>
+    for ConjugateTransposeSxS<'a, T, ROWS, COLUMNS>
 where
-    [(); M * K]:,
+    Complex<T>: Debug + Default + Copy + AddAssign + Mul<Output = Complex<T>>,
+    [(); ROWS * COLUMNS]:,
+    Static<ROWS>: Join<B::Rows>,
+    (Static<COLUMNS>, B::Columns, RowMajor): Storage<Complex<T>>,
 {
-    type Output = MatrixSxD<T, M>;
-    fn matmul(&self, other: &MatrixDxD<T>) -> Self::Output {
-        assert_eq!(K, other.rows, "Non-matching columns to rows");
-
-        let n = other.columns;
-        let mut data = vec![Default::default(); M * n];
-        InternalMatmul::matmul(&self.data, &other.data, &mut data, M, n, K);
-        Self::Output { data, columns: n }
+    type Output = Matrix<Complex<T>, Static<COLUMNS>, B::Columns>;
+    fn matmul(&self, other: &B) -> Self::Output {
+        product(self, other)
     }
 }
-// Tests
+// Batch
 // --------------------------------------------------
+/// Asserts batches `a` and `b` are of equal length with all matrices of each the same shape,
+///  returning the `m`, `n` and `k` of their products.
+fn batch_shape<T, R1: Dim, C1: Dim, R2: Dim, C2: Dim>(
+    a: &[Matrix<T, R1, C1>],
+    b: &[Matrix<T, R2, C2>],
+) -> (usize, usize, usize)
+where
+    (R1, C1, RowMajor): Storage<T>,
+    (R2, C2, RowMajor): Storage<T>,
+{
+    assert_eq!(a.len(), b.len(), "Non-matching lengths");
+    let (m, k) = a.first().map_or((0, 0), |x| x.shape());
+    let (rows, n) = b.first().map_or((0, 0), |x| x.shape());
+    assert_eq!(k, rows, "Non-matching columns to rows");
+    for (rows, columns) in a.iter().map(|x| x.shape()) {
+        assert_eq!(rows, m, "Non-matching rows");
+        assert_eq!(columns, k, "Non-matching columns");
+    }
+    for (rows, columns) in b.iter().map(|x| x.shape()) {
+        assert_eq!(rows, k, "Non-matching rows");
+        assert_eq!(columns, n, "Non-matching columns");
+    }
+    (m, n, k)
+}
+/// `len` zeroed `m` by `n` matrices for the products of a batch to be added to.
+fn zeros<T: Default, R: Dim, C: Dim>(len: usize, m: usize, n: usize) -> Vec<Matrix<T, R, C>>
+where
+    (R, C, RowMajor): Storage<T>,
+{
+    (0..len)
+        .map(|_| Matrix {
+            data: <(R, C, RowMajor)>::collect((0..m * n).map(|_| T::default())),
+            rows: R::value(m),
+            columns: C::value(n),
+        })
+        .collect()
+}
+impl<T, R1: Dim, C1: Dim, R2: Dim, C2: Dim> MatmulBatch<Matrix<T, R2, C2>> for [Matrix<T, R1, C1>]
+where
+    T: Debug + Default + Copy + AddAssign + Mul<Output = T>,
+    Matrix<T, R1, C1>: Matmul<Matrix<T, R2, C2>, Output = Matrix<T, R1, C2>>,
+    (R1, C1, RowMajor): Storage<T>,
+    (R2, C2, RowMajor): Storage<T>,
+    (R1, C2, RowMajor): Storage<T>,
+{
+    type Output = Vec<Matrix<T, R1, C2>>;
+    fn matmul_batch(&self, other: &[Matrix<T, R2, C2>]) -> Self::Output {
+        let (m, n, k) = batch_shape(self, other);
+        if m * n * k <= UNROLL {
+            // Tiny products are cheaper pair by pair, being unrolled when static.
+            return self.iter().zip(other).map(|(a, b)| a.matmul(b)).collect();
+        }
+        let mut c: Self::Output = zeros(self.len(), m, n);
+        let products = self.iter().zip(other).zip(c.iter_mut());
+        T::matmul_batch(
+            products.map(|((a, b), c)| ((a.as_slice(), b.as_slice()), c.data.as_mut())),
+            m,
+            n,
+            k,
+        );
+        c
+    }
+}
+#[cfg(feature = "parallel")]
+impl<T, R1: Dim, C1: Dim, R2: Dim, C2: Dim> ParMatmulBatch<Matrix<T, R2, C2>>
+    for [Matrix<T, R1, C1>]
+where
+    T: Debug + Default + Copy + AddAssign + Mul<Output = T> + Send + Sync,
+    Matrix<T, R1, C1>: Matmul<Matrix<T, R2, C2>, Output = Matrix<T, R1, C2>> + Sync,
+    Matrix<T, R2, C2>: Sync,
+    Matrix<T, R1, C2>: Send,
+    (R1, C1, RowMajor): Storage<T>,
+    (R2, C2, RowMajor): Storage<T>,
+    (R1, C2, RowMajor): Storage<T>,
+{
+    type Output = Vec<Matrix<T, R1, C2>>;
+    fn par_matmul_batch(&self, other: &[Matrix<T, R2, C2>]) -> Self::Output {
+        let (m, n, k) = batch_shape(self, other);
+        if m * n * k <= UNROLL {
+            return self
+                .par_iter()
+                .zip(other)
+                .map(|(a, b)| a.matmul(b))
+                .collect();
+        }
+        let mut c: Self::Output = zeros(self.len(), m, n);
+        let products = self.par_iter().zip(other).zip(c.par_iter_mut());
+        T::par_matmul_batch(
+            products.map(|((a, b), c)| ((a.as_slice(), b.as_slice()), c.data.as_mut())),
+            m,
+            n,
+            k,
+        );
+        c
+    }
+}
+
+// Into
+// --------------------------------------------------
+/// [`matmul_into`] without checking shapes at compile time.
+fn into<T, A, B, C>(a: &A, b: &B, c: &mut C, alpha: T, beta: T)
+where
+    T: Debug + Default + Copy + AddAssign + Mul<Output = T>,
+    A: blas::Operand<T>,
+    B: blas::Operand<T>,
+    C: blas::MatrixMut<T>,
+{
+    use blas::Matrix as _;
+    let (x, y) = (a.stored(), b.stored());
+    let shape =
+        |t: bool, rows: usize, columns: usize| if t { (columns, rows) } else { (rows, columns) };
+    let (m, k) = shape(A::TRANSPOSE, x.rows(), x.columns());
+    let (rows, n) = shape(B::TRANSPOSE, y.rows(), y.columns());
+    assert_eq!(k, rows, "Non-matching columns to rows");
+    assert_eq!(m, c.rows(), "Non-matching rows");
+    assert_eq!(n, c.columns(), "Non-matching columns");
+
+    let (ta, tb) = (
+        A::TRANSPOSE ^ x.column_major(),
+        B::TRANSPOSE ^ y.column_major(),
+    );
+    let (lda, ldb, ldc) = (
+        x.leading_dimension(),
+        y.leading_dimension(),
+        c.leading_dimension(),
+    );
+    if c.column_major() {
+        // The column-major data of `C = A·B` is the row-major data of `Cᵀ = Bᵀ·Aᵀ`.
+        let (b, ldb, tb) = operand(y.data(), ldb, !tb, B::CONJUGATE, n, k);
+        let (a, lda, ta) = operand(x.data(), lda, !ta, A::CONJUGATE, k, m);
+        T::matmul_scaled(
+            &b,
+            ldb,
+            tb,
+            &a,
+            lda,
+            ta,
+            c.data_mut(),
+            ldc,
+            n,
+            m,
+            k,
+            alpha,
+            beta,
+        );
+    } else {
+        let (a, lda, ta) = operand(x.data(), lda, ta, A::CONJUGATE, m, k);
+        let (b, ldb, tb) = operand(y.data(), ldb, tb, B::CONJUGATE, k, n);
+        T::matmul_scaled(
+            &a,
+            lda,
+            ta,
+            &b,
+            ldb,
+            tb,
+            c.data_mut(),
+            ldc,
+            m,
+            n,
+            k,
+            alpha,
+            beta,
+        );
+    }
+}
+/// `c = alpha·a·b + beta·c`, writing the product into `c` rather than allocating it.
+///
+/// Any of matrices, views, transposes or [`blas::row`]s and [`blas::column`]s can be given, and
+///  `c` may be a mutable view. Where dimensions are static they are checked at compile time, a
+///  `c` of the wrong shape failing to compile:
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// use static_la::*;
+/// let a = MatrixSxS::from([[1, 3, 5], [2, 4, 6]]);
+/// let b = MatrixDxS::from(vec![[7, 10], [8, 11], [9, 12]]);
+/// let mut c = MatrixSxS::from([[1, 1], [1, 1]]);
+/// matmul_into(&a, &b, &mut c, 1, 2);
+/// assert_eq!(c, MatrixSxS::from([[78, 105], [102, 138]]));
+/// // `bᵀ·aᵀ = (a·b)ᵀ`
+/// matmul_into(&b.transpose_ref(), &a.transpose_ref(), &mut c, 1, 0);
+/// assert_eq!(c, MatrixSxS::from([[76, 100], [103, 136]]));
+/// ```
+/// ```compile_fail
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// use static_la::*;
+/// let a = MatrixSxS::from([[1, 3, 5], [2, 4, 6]]);
+/// let b = MatrixSxS::from([[7, 10], [8, 11], [9, 12]]);
+/// let mut c = MatrixSxS::from([[0, 0, 0], [0, 0, 0]]);
+/// matmul_into(&a, &b, &mut c, 1, 0);
+/// ```
+pub fn matmul_into<T, A, B, C>(a: &A, b: &B, c: &mut C, alpha: T, beta: T)
+where
+    T: Debug + Default + Copy + AddAssign + Mul<Output = T>,
+    A: blas::Operand<T>,
+    B: blas::Operand<T>,
+    C: blas::Shape + blas::MatrixMut<T>,
+    A::Rows: Join<C::Rows>,
+    A::Columns: Join<B::Rows>,
+    B::Columns: Join<C::Columns>,
+{
+    into(a, b, c, alpha, beta);
+}
+impl<T, R: Dim, C: Dim, L: Layout, B: blas::Operand<T>> MatmulAssign<B> for Matrix<T, R, C, L>
+where
+    T: Debug + Default + Copy + AddAssign + Num,
+    (R, C, L): Storage<T>,
+    Self: blas::Operand<T> + blas::MatrixMut<T>,
+    C: Join<B::Rows> + Join<B::Columns>,
+{
+    fn matmul_assign(&mut self, other: &B) {
+        let a = Matrix::<T, R, C, L> {
+            data: <(R, C, L)>::collect(self.data.as_ref().iter().copied()),
+            rows: self.rows,
+            columns: self.columns,
+        };
+        into(&a, other, self, T::one(), T::zero());
+    }
+    fn matmul_assign_with(&mut self, other: &B, scratch: &mut Self) {
+        let ((rows, columns), (scratch_rows, scratch_columns)) = (self.shape(), scratch.shape());
+        assert_eq!(rows, scratch_rows, "Non-matching rows");
+        assert_eq!(columns, scratch_columns, "Non-matching columns");
+        scratch.data.as_mut().copy_from_slice(self.data.as_ref());
+        into(scratch, other, self, T::one(), T::zero());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -382,6 +1526,12 @@ mod tests {
         let d = MatrixDxD::<f32>::try_from(vec![vec![76., 103.], vec![100., 136.]]).unwrap();
         assert_eq!(c, d);
     }
+    #[test]
+    fn f32_column_vector() {
+        let a = MatrixDxD::<f32>::try_from(vec![vec![1., 3., 5.], vec![2., 4., 6.]]).unwrap();
+        let b = ColumnVectorS::<f32, 3>::from([[7.], [8.], [9.]]);
+        assert_eq!(a.matmul(&b), ColumnVectorD::from(vec![[76.], [100.]]));
+    }
     // f64
     // --------------------------------------------------
     #[test]
@@ -393,6 +1543,118 @@ mod tests {
         let d = MatrixDxD::<f64>::try_from(vec![vec![76., 103.], vec![100., 136.]]).unwrap();
         assert_eq!(c, d);
     }
+    // Complex
+    // --------------------------------------------------
+    fn complex<T: From<i8>>(re: i8, im: i8) -> num_complex::Complex<T> {
+        num_complex::Complex::new(T::from(re), T::from(im))
+    }
+    #[test]
+    fn c32_sxs() {
+        let c = complex::<f32>;
+        let a = MatrixSxS::from([[c(1, 1), c(2, 0)], [c(0, 0), c(0, 1)]]);
+        let b = MatrixSxS::from([[c(1, 0), c(0, 1)], [c(1, -1), c(1, 0)]]);
+        let d = MatrixSxS::from([[c(3, -1), c(1, 1)], [c(1, 1), c(0, 1)]]);
+        assert_eq!(a.matmul(&b), d);
+    }
+    #[test]
+    fn c64_dxd() {
+        let c = complex::<f64>;
+        let a = MatrixDxD::try_from(vec![vec![c(1, 1), c(2, 0)], vec![c(0, 0), c(0, 1)]]).unwrap();
+        let b = MatrixDxD::try_from(vec![vec![c(1, 0), c(0, 1)], vec![c(1, -1), c(1, 0)]]).unwrap();
+        let d = MatrixDxD::try_from(vec![vec![c(3, -1), c(1, 1)], vec![c(1, 1), c(0, 1)]]).unwrap();
+        assert_eq!(a.matmul(&b), d);
+    }
+    #[test]
+    fn c_i32_dxs() {
+        let c = complex::<i32>;
+        let a = MatrixDxS::from(vec![[c(1, 1), c(2, 0)], [c(0, 0), c(0, 1)]]);
+        let b = MatrixDxS::from(vec![[c(1, 0), c(0, 1)], [c(1, -1), c(1, 0)]]);
+        let d = MatrixDxS::from(vec![[c(3, -1), c(1, 1)], [c(1, 1), c(0, 1)]]);
+        assert_eq!(a.matmul(&b), d);
+    }
+    #[test]
+    fn c64_conj_transpose() {
+        let c = complex::<f64>;
+        let a = MatrixDxD::try_from(vec![
+            vec![c(1, 1), c(2, 0), c(0, -1)],
+            vec![c(0, 0), c(0, 1), c(3, 2)],
+        ])
+        .unwrap();
+        let b = MatrixDxD::try_from(vec![vec![c(1, 0), c(0, 1)], vec![c(1, -1), c(1, 0)]]).unwrap();
+        assert_eq!(
+            a.conj_transpose_ref().matmul(&b),
+            a.conj_transpose().matmul(&b)
+        );
+        assert_eq!(
+            a.matmul(&a.conj_transpose_ref()),
+            a.matmul(&a.conj_transpose())
+        );
+        assert_eq!(
+            b.conj_transpose_ref().matmul(&b.conj_transpose_ref()),
+            b.conj_transpose().matmul(&b.conj_transpose())
+        );
+    }
+    #[test]
+    fn c_i32_conj_transpose() {
+        let c = complex::<i32>;
+        let a =
+            MatrixSxS::<_, 2, 3>::from([[c(1, 1), c(2, 0), c(0, -1)], [c(0, 0), c(0, 1), c(3, 2)]]);
+        let b = MatrixDxS::from(vec![[c(1, 0), c(0, 1)], [c(1, -1), c(1, 0)]]);
+        assert_eq!(
+            a.conj_transpose_ref().matmul(&b),
+            a.conj_transpose().matmul(&b)
+        );
+        assert_eq!(
+            b.conj_transpose_ref().matmul(&a),
+            b.conj_transpose().matmul(&a)
+        );
+    }
+    #[test]
+    fn c64_conj_transpose_column_major() {
+        let c = complex::<f64>;
+        let a = MatrixDxD::try_from(vec![
+            vec![c(1, 1), c(2, 0), c(0, -1)],
+            vec![c(0, 0), c(0, 1), c(3, 2)],
+        ])
+        .unwrap();
+        let b = MatrixDxD::try_from(vec![vec![c(1, 0), c(0, 1)], vec![c(1, -1), c(1, 0)]]).unwrap();
+        // A column-major product reads a conjugate transpose as a conjugate.
+        let d = a.clone().transpose();
+        assert_eq!(
+            d.matmul(&b.conj_transpose_ref()),
+            d.matmul(&b.conj_transpose())
+        );
+        let mut e = MatrixDxD::from((2, 2, c(1, 0))).transpose();
+        matmul_into(&b.conj_transpose_ref(), &b, &mut e, c(1, 0), c(1, 0));
+        assert_eq!(e, b.conj_transpose().matmul(&b) + c(1, 0));
+    }
+    #[test]
+    fn f64_view() {
+        let a = MatrixDxD::<f64>::try_from(vec![
+            vec![0., 1., 3., 5., 0.],
+            vec![0., 2., 4., 6., 0.],
+            vec![0., 0., 0., 0., 0.],
+        ])
+        .unwrap();
+        let b = MatrixSxS::<f64, 4, 3>::from([
+            [7., 10., 0.],
+            [8., 11., 0.],
+            [9., 12., 0.],
+            [0., 0., 0.],
+        ]);
+        let c = a
+            .slice_dxs::<{ 1..4 }>(0..2)
+            .matmul(&b.slice_sxs::<{ 0..3 }, { 0..2 }>());
+        assert_eq!(c, MatrixSxS::<f64, 2, 2>::from([[76., 103.], [100., 136.]]));
+    }
+    #[test]
+    fn f64_view_column_vector() {
+        let a = MatrixSxS::<f64, 2, 3>::from([[1., 3., 5.], [2., 4., 6.]]);
+        let b = MatrixSxS::<f64, 3, 2>::from([[7., 10.], [8., 11.], [9., 12.]]);
+        // The column is strided within `b`.
+        let c = a.matmul(&b.slice_sxs::<{ 0..3 }, { 1..2 }>());
+        assert_eq!(c, ColumnVectorS::<f64, 2>::from([[103.], [136.]]));
+    }
     // MatrixDxD
     // --------------------------------------------------
     #[test]
@@ -427,6 +1689,12 @@ mod tests {
         let d = MatrixSxS::from([[76, 103], [100, 136]]);
         assert_eq!(c, d);
     }
+    #[test]
+    #[should_panic(expected = "Non-matching columns to rows")]
+    fn dxd_sxd_columns_to_rows() {
+        let a = MatrixDxD::try_from(vec![vec![1, 3, 5], vec![2, 4, 6]]).unwrap();
+        let _ = a.matmul(&MatrixSxD::<i32, 2>::try_from([vec![7, 10], vec![8, 11]]).unwrap());
+    }
     // MatrixDxS
     // --------------------------------------------------
     #[test]
@@ -461,6 +1729,12 @@ mod tests {
         let d = MatrixSxS::from([[76, 103], [100, 136]]);
         assert_eq!(c, d);
     }
+    #[test]
+    #[should_panic(expected = "Non-matching columns to rows")]
+    fn dxs_dxs_columns_to_rows() {
+        let a = MatrixDxS::<f32, 2>::from(vec![[1., 3.], [2., 4.]]);
+        let _ = a.matmul(&MatrixDxS::<f32, 1>::from(vec![[7.], [8.], [9.]]));
+    }
     // MatrixSxD
     // --------------------------------------------------
     #[test]
@@ -495,6 +1769,18 @@ mod tests {
         let d = MatrixSxD::try_from([vec![76, 103], vec![100, 136]]).unwrap();
         assert_eq!(c, d);
     }
+    #[test]
+    #[should_panic(expected = "Non-matching columns to rows")]
+    fn sxd_sxs_columns_to_rows() {
+        let a = MatrixSxD::try_from([vec![1, 3, 5], vec![2, 4, 6]]).unwrap();
+        let _ = a.matmul(&MatrixSxS::from([[7, 10], [8, 11]]));
+    }
+    #[test]
+    #[should_panic(expected = "Non-matching columns to rows")]
+    fn sxd_sxd_columns_to_rows() {
+        let a = MatrixSxD::try_from([vec![1, 3], vec![2, 4]]).unwrap();
+        let _ = a.matmul(&MatrixSxD::<i32, 3>::try_from([vec![7], vec![8], vec![9]]).unwrap());
+    }
     // MatrixSxS
     // --------------------------------------------------
     #[test]
@@ -529,4 +1815,1156 @@ mod tests {
         let d = MatrixSxS::from([[76, 103], [100, 136]]);
         assert_eq!(c, d);
     }
+    #[test]
+    #[should_panic(expected = "Non-matching columns to rows")]
+    fn sxs_dxs_columns_to_rows() {
+        let a = MatrixSxS::from([[1, 3], [2, 4]]);
+        let _ = a.matmul(&MatrixDxS::from(vec![[7, 10], [8, 11], [9, 12]]));
+    }
+    // ViewDxD
+    // --------------------------------------------------
+    #[test]
+    fn view_dxd_dxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixDxD::try_from(vec![vec![7, 10], vec![8, 11], vec![9, 12]]).unwrap();
+        let c = a.slice_dxd((1..3, 1..4)).matmul(&b);
+        let d = MatrixDxD::try_from(vec![vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxd_dxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixDxS::from(vec![[7, 10], [8, 11], [9, 12]]);
+        let c = a.slice_dxd((1..3, 1..4)).matmul(&b);
+        let d = MatrixDxS::from(vec![[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxd_sxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxD::try_from([vec![7, 10], vec![8, 11], vec![9, 12]]).unwrap();
+        let c = a.slice_dxd((1..3, 1..4)).matmul(&b);
+        let d = MatrixDxD::try_from(vec![vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxd_sxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([[7, 10], [8, 11], [9, 12]]);
+        let c = a.slice_dxd((1..3, 1..4)).matmul(&b);
+        let d = MatrixDxS::from(vec![[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    // ViewDxS
+    // --------------------------------------------------
+    #[test]
+    fn view_dxs_dxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixDxD::try_from(vec![vec![7, 10], vec![8, 11], vec![9, 12]]).unwrap();
+        let c = a.slice_dxs::<{ 1..4 }>(1..3).matmul(&b);
+        let d = MatrixDxD::try_from(vec![vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxs_dxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixDxS::from(vec![[7, 10], [8, 11], [9, 12]]);
+        let c = a.slice_dxs::<{ 1..4 }>(1..3).matmul(&b);
+        let d = MatrixDxS::from(vec![[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxs_sxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxD::try_from([vec![7, 10], vec![8, 11], vec![9, 12]]).unwrap();
+        let c = a.slice_dxs::<{ 1..4 }>(1..3).matmul(&b);
+        let d = MatrixDxD::try_from(vec![vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxs_sxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([[7, 10], [8, 11], [9, 12]]);
+        let c = a.slice_dxs::<{ 1..4 }>(1..3).matmul(&b);
+        let d = MatrixDxS::from(vec![[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    // ViewSxD
+    // --------------------------------------------------
+    #[test]
+    fn view_sxd_dxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixDxD::try_from(vec![vec![7, 10], vec![8, 11], vec![9, 12]]).unwrap();
+        let c = a.slice_sxd::<{ 1..3 }>(1..4).matmul(&b);
+        let d = MatrixSxD::try_from([vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxd_dxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixDxS::from(vec![[7, 10], [8, 11], [9, 12]]);
+        let c = a.slice_sxd::<{ 1..3 }>(1..4).matmul(&b);
+        let d = MatrixSxS::from([[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxd_sxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxD::try_from([vec![7, 10], vec![8, 11], vec![9, 12]]).unwrap();
+        let c = a.slice_sxd::<{ 1..3 }>(1..4).matmul(&b);
+        let d = MatrixSxD::try_from([vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxd_sxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([[7, 10], [8, 11], [9, 12]]);
+        let c = a.slice_sxd::<{ 1..3 }>(1..4).matmul(&b);
+        let d = MatrixSxS::from([[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    // ViewSxS
+    // --------------------------------------------------
+    #[test]
+    fn view_sxs_dxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixDxD::try_from(vec![vec![7, 10], vec![8, 11], vec![9, 12]]).unwrap();
+        let c = a.slice_sxs::<{ 1..3 }, { 1..4 }>().matmul(&b);
+        let d = MatrixSxD::try_from([vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxs_dxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixDxS::from(vec![[7, 10], [8, 11], [9, 12]]);
+        let c = a.slice_sxs::<{ 1..3 }, { 1..4 }>().matmul(&b);
+        let d = MatrixSxS::from([[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxs_sxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxD::try_from([vec![7, 10], vec![8, 11], vec![9, 12]]).unwrap();
+        let c = a.slice_sxs::<{ 1..3 }, { 1..4 }>().matmul(&b);
+        let d = MatrixSxD::try_from([vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxs_sxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([[7, 10], [8, 11], [9, 12]]);
+        let c = a.slice_sxs::<{ 1..3 }, { 1..4 }>().matmul(&b);
+        let d = MatrixSxS::from([[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    // MatrixDxD x View
+    // --------------------------------------------------
+    #[test]
+    fn dxd_view_dxd() {
+        let a = MatrixDxD::try_from(vec![vec![1, 3, 5], vec![2, 4, 6]]).unwrap();
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_dxd((1..4, 1..3)));
+        let d = MatrixDxD::try_from(vec![vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn dxd_view_dxs() {
+        let a = MatrixDxD::try_from(vec![vec![1, 3, 5], vec![2, 4, 6]]).unwrap();
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_dxs::<{ 1..3 }>(1..4));
+        let d = MatrixDxS::from(vec![[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn dxd_view_sxd() {
+        let a = MatrixDxD::try_from(vec![vec![1, 3, 5], vec![2, 4, 6]]).unwrap();
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_sxd::<{ 1..4 }>(1..3));
+        let d = MatrixDxD::try_from(vec![vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn dxd_view_sxs() {
+        let a = MatrixDxD::try_from(vec![vec![1, 3, 5], vec![2, 4, 6]]).unwrap();
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_sxs::<{ 1..4 }, { 1..3 }>());
+        let d = MatrixDxS::from(vec![[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    // MatrixDxS x View
+    // --------------------------------------------------
+    #[test]
+    fn dxs_view_dxd() {
+        let a = MatrixDxS::from(vec![[1, 3, 5], [2, 4, 6]]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_dxd((1..4, 1..3)));
+        let d = MatrixDxD::try_from(vec![vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn dxs_view_dxs() {
+        let a = MatrixDxS::from(vec![[1, 3, 5], [2, 4, 6]]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_dxs::<{ 1..3 }>(1..4));
+        let d = MatrixDxS::from(vec![[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn dxs_view_sxd() {
+        let a = MatrixDxS::from(vec![[1, 3, 5], [2, 4, 6]]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_sxd::<{ 1..4 }>(1..3));
+        let d = MatrixDxD::try_from(vec![vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn dxs_view_sxs() {
+        let a = MatrixDxS::from(vec![[1, 3, 5], [2, 4, 6]]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_sxs::<{ 1..4 }, { 1..3 }>());
+        let d = MatrixDxS::from(vec![[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    // MatrixSxD x View
+    // --------------------------------------------------
+    #[test]
+    fn sxd_view_dxd() {
+        let a = MatrixSxD::try_from([vec![1, 3, 5], vec![2, 4, 6]]).unwrap();
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_dxd((1..4, 1..3)));
+        let d = MatrixSxD::try_from([vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn sxd_view_dxs() {
+        let a = MatrixSxD::try_from([vec![1, 3, 5], vec![2, 4, 6]]).unwrap();
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_dxs::<{ 1..3 }>(1..4));
+        let d = MatrixSxS::from([[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn sxd_view_sxd() {
+        let a = MatrixSxD::try_from([vec![1, 3, 5], vec![2, 4, 6]]).unwrap();
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_sxd::<{ 1..4 }>(1..3));
+        let d = MatrixSxD::try_from([vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn sxd_view_sxs() {
+        let a = MatrixSxD::try_from([vec![1, 3, 5], vec![2, 4, 6]]).unwrap();
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_sxs::<{ 1..4 }, { 1..3 }>());
+        let d = MatrixSxS::from([[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    // MatrixSxS x View
+    // --------------------------------------------------
+    #[test]
+    fn sxs_view_dxd() {
+        let a = MatrixSxS::from([[1, 3, 5], [2, 4, 6]]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_dxd((1..4, 1..3)));
+        let d = MatrixSxD::try_from([vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn sxs_view_dxs() {
+        let a = MatrixSxS::from([[1, 3, 5], [2, 4, 6]]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_dxs::<{ 1..3 }>(1..4));
+        let d = MatrixSxS::from([[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn sxs_view_sxd() {
+        let a = MatrixSxS::from([[1, 3, 5], [2, 4, 6]]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_sxd::<{ 1..4 }>(1..3));
+        let d = MatrixSxD::try_from([vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn sxs_view_sxs() {
+        let a = MatrixSxS::from([[1, 3, 5], [2, 4, 6]]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.matmul(&b.slice_sxs::<{ 1..4 }, { 1..3 }>());
+        let d = MatrixSxS::from([[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    // ViewDxD x View
+    // --------------------------------------------------
+    #[test]
+    fn view_dxd_view_dxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a.slice_dxd((1..3, 1..4)).matmul(&b.slice_dxd((1..4, 1..3)));
+        let d = MatrixDxD::try_from(vec![vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxd_view_dxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a
+            .slice_dxd((1..3, 1..4))
+            .matmul(&b.slice_dxs::<{ 1..3 }>(1..4));
+        let d = MatrixDxS::from(vec![[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxd_view_sxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a
+            .slice_dxd((1..3, 1..4))
+            .matmul(&b.slice_sxd::<{ 1..4 }>(1..3));
+        let d = MatrixDxD::try_from(vec![vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxd_view_sxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a
+            .slice_dxd((1..3, 1..4))
+            .matmul(&b.slice_sxs::<{ 1..4 }, { 1..3 }>());
+        let d = MatrixDxS::from(vec![[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    // ViewDxS x View
+    // --------------------------------------------------
+    #[test]
+    fn view_dxs_view_dxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a
+            .slice_dxs::<{ 1..4 }>(1..3)
+            .matmul(&b.slice_dxd((1..4, 1..3)));
+        let d = MatrixDxD::try_from(vec![vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxs_view_dxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a
+            .slice_dxs::<{ 1..4 }>(1..3)
+            .matmul(&b.slice_dxs::<{ 1..3 }>(1..4));
+        let d = MatrixDxS::from(vec![[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxs_view_sxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a
+            .slice_dxs::<{ 1..4 }>(1..3)
+            .matmul(&b.slice_sxd::<{ 1..4 }>(1..3));
+        let d = MatrixDxD::try_from(vec![vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxs_view_sxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a
+            .slice_dxs::<{ 1..4 }>(1..3)
+            .matmul(&b.slice_sxs::<{ 1..4 }, { 1..3 }>());
+        let d = MatrixDxS::from(vec![[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    // ViewSxD x View
+    // --------------------------------------------------
+    #[test]
+    fn view_sxd_view_dxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a
+            .slice_sxd::<{ 1..3 }>(1..4)
+            .matmul(&b.slice_dxd((1..4, 1..3)));
+        let d = MatrixSxD::try_from([vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxd_view_dxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a
+            .slice_sxd::<{ 1..3 }>(1..4)
+            .matmul(&b.slice_dxs::<{ 1..3 }>(1..4));
+        let d = MatrixSxS::from([[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxd_view_sxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a
+            .slice_sxd::<{ 1..3 }>(1..4)
+            .matmul(&b.slice_sxd::<{ 1..4 }>(1..3));
+        let d = MatrixSxD::try_from([vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxd_view_sxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a
+            .slice_sxd::<{ 1..3 }>(1..4)
+            .matmul(&b.slice_sxs::<{ 1..4 }, { 1..3 }>());
+        let d = MatrixSxS::from([[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    // ViewSxS x View
+    // --------------------------------------------------
+    #[test]
+    fn view_sxs_view_dxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a
+            .slice_sxs::<{ 1..3 }, { 1..4 }>()
+            .matmul(&b.slice_dxd((1..4, 1..3)));
+        let d = MatrixSxD::try_from([vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxs_view_dxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a
+            .slice_sxs::<{ 1..3 }, { 1..4 }>()
+            .matmul(&b.slice_dxs::<{ 1..3 }>(1..4));
+        let d = MatrixSxS::from([[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxs_view_sxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a
+            .slice_sxs::<{ 1..3 }, { 1..4 }>()
+            .matmul(&b.slice_sxd::<{ 1..4 }>(1..3));
+        let d = MatrixSxD::try_from([vec![76, 103], vec![100, 136]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxs_view_sxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 3, 5, 0],
+            [0, 2, 4, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::from([
+            [0, 0, 0, 0],
+            [0, 7, 10, 0],
+            [0, 8, 11, 0],
+            [0, 9, 12, 0],
+            [0, 0, 0, 0],
+        ]);
+        let c = a
+            .slice_sxs::<{ 1..3 }, { 1..4 }>()
+            .matmul(&b.slice_sxs::<{ 1..4 }, { 1..3 }>());
+        let d = MatrixSxS::from([[76, 103], [100, 136]]);
+        assert_eq!(c, d);
+    }
+    // ColumnMajor
+    // --------------------------------------------------
+    #[test]
+    fn column_major() {
+        let a = MatrixSxS::from([[1, 3, 5], [2, 4, 6]]);
+        let b = MatrixDxS::from(vec![[7, 10], [8, 11], [9, 12]]);
+        let d = MatrixSxS::from([[76, 103], [100, 136]]);
+        let (a_t, b_t) = (a.to_layout::<ColumnMajor>(), b.to_layout::<ColumnMajor>());
+        let c = a_t.matmul(&b);
+        assert_eq!(c, d);
+        assert_eq!(c.as_slice(), &[76, 100, 103, 136]);
+        assert_eq!(a_t.matmul(&b_t), d);
+        assert_eq!(a.matmul(&b_t), d);
+    }
+    #[test]
+    fn column_major_f64() {
+        let a = MatrixDxD::try_from(vec![vec![1., 3., 5.], vec![2., 4., 6.]]).unwrap();
+        let b = MatrixSxS::from([[7., 10.], [8., 11.], [9., 12.]]);
+        let c = a
+            .to_layout::<ColumnMajor>()
+            .matmul(&b.to_layout::<ColumnMajor>());
+        assert_eq!(c, MatrixSxS::from([[76., 103.], [100., 136.]]));
+    }
+    #[test]
+    fn column_major_mixed_f32() {
+        let a = MatrixDxS::<f32, 3>::from(vec![[1., 3., 5.], [2., 4., 6.]]);
+        let b =
+            MatrixSxD::<f32, 3>::try_from([vec![7., 10.], vec![8., 11.], vec![9., 12.]]).unwrap();
+        let d = MatrixSxS::from([[76., 103.], [100., 136.]]);
+        let c = a.to_layout::<ColumnMajor>().matmul(&b);
+        assert_eq!(c.as_slice(), &[76., 100., 103., 136.]);
+        assert_eq!(a.matmul(&b.to_layout::<ColumnMajor>()), d);
+    }
+    #[test]
+    #[should_panic(expected = "Non-matching columns to rows")]
+    fn column_major_columns_to_rows() {
+        let a = MatrixSxS::from([[1, 2], [3, 4]]).transpose();
+        let _ = a.matmul(&MatrixDxS::from(vec![[1, 2, 3]]));
+    }
+    // Transpose
+    // --------------------------------------------------
+    #[test]
+    fn transpose_f32() {
+        let a = MatrixSxS::<f32, 3, 2>::from([[1., 2.], [3., 4.], [5., 6.]]);
+        let b = MatrixDxD::try_from(vec![vec![7., 10.], vec![8., 11.], vec![9., 12.]]).unwrap();
+        let c: MatrixSxD<f32, 2> = a.transpose_ref().matmul(&b);
+        assert_eq!(c, MatrixSxS::from([[76., 103.], [100., 136.]]));
+        let c: ColumnVectorS<f32, 2> =
+            a.transpose_ref()
+                .matmul(&ColumnVectorS::from([[1.], [1.], [1.]]));
+        assert_eq!(c, ColumnVectorS::from([[9.], [12.]]));
+    }
+    #[test]
+    fn transpose_both_f64() {
+        let a = MatrixSxS::<f64, 3, 2>::from([[1., 2.], [3., 4.], [5., 6.]]);
+        let b = MatrixSxS::<f64, 2, 3>::from([[7., 8., 9.], [10., 11., 12.]]);
+        let c: MatrixSxS<f64, 2, 2> = a.transpose_ref().matmul(&b.transpose_ref());
+        assert_eq!(c, MatrixSxS::from([[76., 103.], [100., 136.]]));
+    }
+    #[test]
+    fn transpose_rhs() {
+        let a = MatrixDxS::from(vec![[1, 3, 5], [2, 4, 6]]);
+        let b = MatrixSxD::<i32, 2>::try_from([vec![7, 8, 9], vec![10, 11, 12]]).unwrap();
+        let d = MatrixSxS::from([[76, 103], [100, 136]]);
+        let c: MatrixDxS<i32, 2> = a.matmul(&b.transpose_ref());
+        assert_eq!(c, d);
+        let c = a.to_layout::<ColumnMajor>().matmul(&b.transpose_ref());
+        assert_eq!(c, d);
+        assert_eq!(c.as_slice(), &[76, 100, 103, 136]);
+    }
+    #[test]
+    #[should_panic(expected = "Non-matching columns to rows")]
+    fn transpose_columns_to_rows() {
+        let a = MatrixDxD::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let _ = a.transpose_ref().matmul(&a);
+    }
+    // Blocked kernel
+    // --------------------------------------------------
+    /// `a·b` by the definition, where `a` is `m` by `k` and `b` is `k` by `n`.
+    fn naive(a: &[i64], b: &[i64], m: usize, n: usize, k: usize) -> Vec<i64> {
+        let mut c = vec![0; m * n];
+        for i in 0..m {
+            for l in 0..k {
+                for j in 0..n {
+                    c[i * n + j] += a[i * k + l] * b[l * n + j];
+                }
+            }
+        }
+        c
+    }
+    fn integers(rows: usize, columns: usize) -> MatrixDxD<i64> {
+        let data = (0..rows * columns).map(|i| (i * 7 % 19) as i64 - 9);
+        MatrixDxD::try_from((rows, columns, data.collect::<Vec<_>>())).unwrap()
+    }
+    #[test]
+    fn blocked() {
+        // Crossing the boundaries of every block and leaving partial micro-kernel tiles.
+        for &(m, n, k) in &[(1, 1, 1), (5, 7, 3), (70, 6, 3), (5, 1030, 260), (0, 3, 2)] {
+            let (a, b) = (integers(m, k), integers(k, n));
+            let c = a.matmul(&b);
+            assert_eq!(
+                c.as_slice(),
+                &naive(a.as_slice(), b.as_slice(), m, n, k)[..]
+            );
+        }
+    }
+    #[test]
+    fn blocked_transpose() {
+        let (a, b) = (integers(67, 9), integers(67, 5));
+        let c = a.transpose_ref().matmul(&b);
+        let d = naive(a.transpose().as_slice(), b.as_slice(), 9, 5, 67);
+        assert_eq!((c.rows(), c.columns()), (9, 5));
+        assert_eq!(c.as_slice(), &d[..]);
+    }
+    // Unrolled kernel
+    // --------------------------------------------------
+    #[test]
+    fn unrolled() {
+        let a = MatrixSxS::<i64, 2, 3>::from([[1, 3, 5], [2, 4, 6]]);
+        let b = MatrixSxS::<i64, 3, 2>::from([[7, 10], [8, 11], [9, 12]]);
+        assert_eq!(a.matmul(&b), MatrixSxS::from([[76, 103], [100, 136]]));
+        let a = MatrixSxS::<f32, 2, 2>::from([[1., 2.], [3., 4.]]);
+        let b = MatrixSxS::<f32, 2, 2>::from([[5., 6.], [7., 8.]]);
+        assert_eq!(a.matmul(&b), MatrixSxS::from([[19., 22.], [43., 50.]]));
+    }
+    #[test]
+    fn unrolled_sizes() {
+        // 3 by 3 and 4 by 4 products are unrolled, 5 by 5 falls back to the blocked kernel.
+        fn square<const N: usize>()
+        where
+            [(); N * N]:,
+        {
+            let mut data = [0; N * N];
+            data.copy_from_slice(integers(N, N).as_slice());
+            let a = MatrixSxS::<i64, N, N>::from(&data);
+            let c = a.matmul(&a);
+            assert_eq!(c.as_slice(), &naive(&data, &data, N, N, N)[..]);
+        }
+        square::<3>();
+        square::<4>();
+        square::<5>();
+    }
+    #[test]
+    fn unrolled_vector() {
+        let a = MatrixSxS::<f64, 4, 4>::from([
+            [1., 2., 3., 4.],
+            [5., 6., 7., 8.],
+            [9., 10., 11., 12.],
+            [13., 14., 15., 16.],
+        ]);
+        let b = ColumnVectorS::<f64, 4>::from([[1.], [0.], [-1.], [2.]]);
+        assert_eq!(
+            a.matmul(&b),
+            ColumnVectorS::from([[6.], [14.], [22.], [30.]])
+        );
+    }
+    // Batch
+    // --------------------------------------------------
+    /// `len` different `rows` by `columns` matrices.
+    fn batch_of(len: usize, rows: usize, columns: usize) -> Vec<MatrixDxD<i64>> {
+        (0..len)
+            .map(|i| {
+                let data = (0..rows * columns).map(|j| ((i + j) * 7 % 19) as i64 - 9);
+                MatrixDxD::try_from((rows, columns, data.collect::<Vec<_>>())).unwrap()
+            })
+            .collect()
+    }
+    #[test]
+    fn batch_small() {
+        let a = [
+            MatrixSxS::<i32, 2, 3>::from([[1, 3, 5], [2, 4, 6]]),
+            MatrixSxS::from([[1, 0, 0], [0, 1, 0]]),
+        ];
+        let b = [
+            MatrixSxS::<i32, 3, 2>::from([[7, 10], [8, 11], [9, 12]]),
+            MatrixSxS::from([[1, 2], [3, 4], [5, 6]]),
+        ];
+        let c: Vec<MatrixSxS<i32, 2, 2>> = a.matmul_batch(&b);
+        assert_eq!(
+            c,
+            vec![
+                MatrixSxS::from([[76, 103], [100, 136]]),
+                MatrixSxS::from([[1, 2], [3, 4]])
+            ]
+        );
+    }
+    #[test]
+    fn batch_dxd() {
+        let (a, b) = (batch_of(4, 5, 6), batch_of(4, 6, 7));
+        let c = a.matmul_batch(&b);
+        assert_eq!(c.len(), 4);
+        for ((a, b), c) in a.iter().zip(&b).zip(&c) {
+            assert_eq!(c, &a.matmul(b));
+        }
+    }
+    #[test]
+    fn batch_f64() {
+        let to_f64 = |x: &MatrixDxD<i64>| {
+            let data = x.as_slice().iter().map(|&x| x as f64).collect::<Vec<_>>();
+            MatrixDxD::try_from((x.rows(), x.columns(), data)).unwrap()
+        };
+        let a = batch_of(3, 5, 6).iter().map(to_f64).collect::<Vec<_>>();
+        let b = batch_of(3, 6, 7).iter().map(to_f64).collect::<Vec<_>>();
+        let c = a.matmul_batch(&b);
+        for ((a, b), c) in a.iter().zip(&b).zip(&c) {
+            assert_eq!(c, &a.matmul(b));
+        }
+    }
+    #[test]
+    fn batch_f32() {
+        let a = vec![
+            MatrixSxS::<f32, 3, 4>::from([
+                [1., 2., 3., 4.],
+                [5., 6., 7., 8.],
+                [9., 0., 1., 2.]
+            ]);
+            3
+        ];
+        let b = [
+            MatrixSxS::<f32, 4, 6>::from(1.),
+            MatrixSxS::from(-2.),
+            MatrixSxS::from(0.5),
+        ];
+        let c: Vec<MatrixSxS<f32, 3, 6>> = a.matmul_batch(&b);
+        for ((a, b), c) in a.iter().zip(&b).zip(&c) {
+            assert_eq!(c, &a.matmul(b));
+        }
+        assert_eq!(c[0], MatrixSxS::from([[10.; 6], [26.; 6], [12.; 6]]));
+    }
+    #[test]
+    fn batch_mixed() {
+        let a = batch_of(2, 3, 8);
+        let b = [MatrixSxS::<i64, 8, 4>::from(7), MatrixSxS::from(-1)];
+        let c: Vec<MatrixDxS<i64, 4>> = a.matmul_batch(&b);
+        assert_eq!(c[0], a[0].matmul(&b[0]));
+        assert_eq!(c[1], a[1].matmul(&b[1]));
+    }
+    #[test]
+    fn batch_empty() {
+        let a: Vec<MatrixDxD<i64>> = Vec::new();
+        assert!(a.matmul_batch(&a).is_empty());
+    }
+    #[test]
+    #[should_panic(expected = "Non-matching lengths")]
+    fn batch_lengths() {
+        batch_of(2, 3, 3).matmul_batch(&batch_of(3, 3, 3));
+    }
+    #[test]
+    #[should_panic(expected = "Non-matching rows")]
+    fn batch_shapes() {
+        let mut a = batch_of(2, 3, 3);
+        a.push(batch_of(1, 2, 3).remove(0));
+        a.matmul_batch(&batch_of(3, 3, 3));
+    }
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn par_batch() {
+        let (a, b) = (batch_of(8, 5, 6), batch_of(8, 6, 7));
+        assert_eq!(a.par_matmul_batch(&b), a.matmul_batch(&b));
+        let a = vec![MatrixSxS::<i32, 2, 2>::from([[1, 2], [3, 4]]); 8];
+        assert_eq!(
+            a.par_matmul_batch(&a),
+            vec![MatrixSxS::from([[7, 10], [15, 22]]); 8]
+        );
+        let a = vec![MatrixSxS::<f64, 4, 5>::from(2.); 8];
+        let b = vec![MatrixSxS::<f64, 5, 4>::from(3.); 8];
+        assert_eq!(a.par_matmul_batch(&b), vec![MatrixSxS::from(30.); 8]);
+    }
+    // Into
+    // --------------------------------------------------
+    #[test]
+    fn into_scaled() {
+        let a = MatrixSxS::<i32, 2, 3>::from([[1, 3, 5], [2, 4, 6]]);
+        let b = MatrixSxS::<i32, 3, 2>::from([[7, 10], [8, 11], [9, 12]]);
+        let mut c = MatrixSxS::from([[1, 2], [3, 4]]);
+        matmul_into(&a, &b, &mut c, 2, -1);
+        assert_eq!(c, MatrixSxS::from([[151, 204], [197, 268]]));
+        let a = MatrixDxD::<f64>::try_from(vec![vec![1., 3., 5.], vec![2., 4., 6.]]).unwrap();
+        let b = MatrixSxS::<f64, 3, 2>::from([[7., 10.], [8., 11.], [9., 12.]]);
+        let mut c = MatrixDxS::<f64, 2>::from(vec![[1., 2.], [3., 4.]]);
+        matmul_into(&a, &b, &mut c, 2., -1.);
+        assert_eq!(c, MatrixSxS::from([[151., 204.], [197., 268.]]));
+    }
+    #[test]
+    fn into_layouts() {
+        let a = MatrixSxS::<f32, 3, 2>::from([[1., 2.], [3., 4.], [5., 6.]]);
+        let b = MatrixDxD::try_from(vec![vec![7., 10.], vec![8., 11.], vec![9., 12.]]).unwrap();
+        let mut c = MatrixSxS::<f32, 2, 2>::from(0.).to_layout::<ColumnMajor>();
+        matmul_into(&a.transpose_ref(), &b, &mut c, 1., 0.);
+        assert_eq!(c, MatrixSxS::from([[76., 103.], [100., 136.]]));
+        assert_eq!(c.as_slice(), &[76., 100., 103., 136.]);
+    }
+    #[test]
+    fn into_view() {
+        // Rows of the view are 9 apart in `c`, and the product is large enough to be blocked.
+        let (a, b) = (integers(5, 6), integers(6, 7));
+        let mut c = MatrixDxD::<i64>::from((8, 9, 1));
+        let mut view = c.slice_dxd_mut((2..7, 1..8));
+        matmul_into(&a, &b, &mut view, 1, 1);
+        let d = naive(a.as_slice(), b.as_slice(), 5, 7, 6);
+        for i in 0..8 {
+            for j in 0..9 {
+                let inside = (2..7).contains(&i) && (1..8).contains(&j);
+                let x = if inside {
+                    d[(i - 2) * 7 + j - 1] + 1
+                } else {
+                    1
+                };
+                assert_eq!(c[(i, j)], x);
+            }
+        }
+    }
+    #[test]
+    #[should_panic(expected = "Non-matching columns")]
+    fn into_shape() {
+        let a = MatrixDxD::<f32>::from((2, 3, 1.));
+        let mut c = MatrixDxD::<f32>::from((2, 3, 0.));
+        matmul_into(&a, &MatrixDxD::from((3, 2, 1.)), &mut c, 1., 0.);
+    }
+    #[test]
+    fn matmul_assign() {
+        let mut a = MatrixSxS::<i32, 2, 3>::from([[1, 3, 5], [2, 4, 6]]);
+        a.matmul_assign(&MatrixSxS::from([[0, 0, 1], [0, 1, 0], [1, 0, 0]]));
+        assert_eq!(a, MatrixSxS::from([[5, 3, 1], [6, 4, 2]]));
+        let mut a = MatrixDxD::<f64>::try_from(vec![vec![1., 2.], vec![3., 4.]]).unwrap();
+        let b = a.clone();
+        a.matmul_assign(&b);
+        assert_eq!(a, MatrixSxS::from([[7., 10.], [15., 22.]]));
+        let mut a = a.to_layout::<ColumnMajor>();
+        a.matmul_assign(&MatrixSxS::from([[1., 0.], [0., 2.]]));
+        assert_eq!(a, MatrixSxS::from([[7., 20.], [15., 44.]]));
+    }
+    #[test]
+    fn matmul_assign_with() {
+        let mut a = MatrixDxD::<f64>::try_from(vec![vec![1., 2.], vec![3., 4.]]).unwrap();
+        let mut scratch = MatrixDxD::from((2, 2, f64::NAN));
+        a.matmul_assign_with(&MatrixSxS::from([[0., 1.], [1., 0.]]), &mut scratch);
+        assert_eq!(a, MatrixSxS::from([[2., 1.], [4., 3.]]));
+        a.matmul_assign_with(&MatrixSxS::from([[2., 0.], [0., 1.]]), &mut scratch);
+        assert_eq!(a, MatrixSxS::from([[4., 1.], [8., 3.]]));
+    }
+    #[test]
+    #[should_panic(expected = "Non-matching rows")]
+    fn matmul_assign_with_scratch() {
+        let mut a = MatrixDxD::<f32>::from((2, 2, 1.));
+        a.matmul_assign_with(
+            &MatrixSxS::from([[1., 0.], [0., 1.]]),
+            &mut MatrixDxD::from((3, 2, 0.)),
+        );
+    }
+    #[test]
+    #[should_panic(expected = "Non-matching columns")]
+    fn matmul_assign_square() {
+        let mut a = MatrixDxD::<f32>::from((2, 3, 1.));
+        a.matmul_assign(&MatrixDxD::from((3, 2, 1.)));
+    }
 }
@@ -1,504 +1,252 @@
+use crate::iter::zip_rows;
 use crate::*;
 use std::ops::{Mul, MulAssign};
 
 // T
 // --------------------------------------------------
-impl<T: MulAssign + Copy> Mul<T> for MatrixDxD<T> {
-    type Output = Self;
-    fn mul(mut self, x: T) -> Self::Output {
-        for a in self.data.iter_mut() {
-            *a *= x;
-        }
-        self
-    }
-}
-impl<T: MulAssign + Copy, const COLUMNS: usize> Mul<T> for MatrixDxS<T, COLUMNS> {
-    type Output = Self;
-    fn mul(mut self, x: T) -> Self::Output {
-        for a in self.data.iter_mut() {
-            *a *= x;
-        }
-        self
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize> Mul<T> for MatrixSxD<T, ROWS> {
-    type Output = Self;
-    fn mul(mut self, x: T) -> Self::Output {
-        for a in self.data.iter_mut() {
-            *a *= x;
-        }
-        self
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize> Mul<T>
-    for MatrixSxS<T, ROWS, COLUMNS>
+impl<T: MulAssign + Copy, R: Dim, C: Dim, L: Layout> Mul<T> for Matrix<T, R, C, L>
 where
-    [(); ROWS * COLUMNS]:,
+    (R, C, L): Storage<T>,
 {
     type Output = Self;
     fn mul(mut self, x: T) -> Self::Output {
-        for a in self.data.iter_mut() {
+        for a in self.data.as_mut().iter_mut() {
             *a *= x;
         }
         self
     }
 }
-// MatrixDxD
+// Matrix
 // --------------------------------------------------
-impl<T: MulAssign + Copy> Mul<MatrixDxD<T>> for MatrixDxD<T> {
-    type Output = Self;
-    fn mul(mut self, other: Self) -> Self {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-        self
-    }
-}
-impl<T: MulAssign + Copy, const COLUMNS: usize> Mul<MatrixDxS<T, COLUMNS>> for MatrixDxD<T> {
-    type Output = MatrixDxS<T, COLUMNS>;
-    fn mul(self, mut other: MatrixDxS<T, COLUMNS>) -> Self::Output {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-        for (a, b) in self.data.iter().zip(other.data.iter_mut()) {
-            *b *= *a;
-        }
-        other
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize> Mul<MatrixSxD<T, ROWS>> for MatrixDxD<T> {
-    type Output = MatrixSxD<T, ROWS>;
-    fn mul(self, mut other: MatrixSxD<T, ROWS>) -> Self::Output {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter().zip(other.data.iter_mut()) {
-            *b *= *a;
-        }
-        other
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize> Mul<MatrixSxS<T, ROWS, COLUMNS>>
-    for MatrixDxD<T>
+impl<T: Mul<Output = T> + Copy, R: Dim, C: Dim, L: Layout, R2: Dim, C2: Dim, L2: Layout>
+    Mul<Matrix<T, R2, C2, L2>> for Matrix<T, R, C, L>
 where
-    [(); ROWS * COLUMNS]:,
+    R: Join<R2>,
+    C: Join<C2>,
+    (R, C, L): Storage<T>,
+    (R2, C2, L2): Storage<T>,
+    (R::Output, C::Output, L): Storage<T>,
 {
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn mul(self, mut other: MatrixSxS<T, ROWS, COLUMNS>) -> Self::Output {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-        for (a, b) in self.data.iter().zip(other.data.iter_mut()) {
-            *b *= *a;
-        }
-        other
-    }
-}
-// MatrixDxS
-// --------------------------------------------------
-impl<T: MulAssign + Copy, const COLUMNS: usize> Mul<MatrixDxS<T, COLUMNS>>
-    for MatrixDxS<T, COLUMNS>
-{
-    type Output = Self;
-    fn mul(mut self, other: Self) -> Self {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-        self
-    }
-}
-impl<T: Mul<Output = T> + Copy + Default, const ROWS: usize, const COLUMNS: usize>
-    Mul<MatrixSxD<T, ROWS>> for MatrixDxS<T, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn mul(self, other: MatrixSxD<T, ROWS>) -> Self::Output {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-        let mut data = [Default::default(); ROWS * COLUMNS];
-        for i in 0..ROWS * COLUMNS {
-            data[i] = self.data[i] * other.data[i];
-        }
-        Self::Output { data }
-    }
-}
-impl<T: MulAssign + Copy, const COLUMNS: usize> Mul<MatrixDxD<T>> for MatrixDxS<T, COLUMNS> {
-    type Output = Self;
-    fn mul(mut self, other: MatrixDxD<T>) -> Self::Output {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-        self
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize> Mul<MatrixSxS<T, ROWS, COLUMNS>>
-    for MatrixDxS<T, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn mul(self, mut other: MatrixSxS<T, ROWS, COLUMNS>) -> Self::Output {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        for (a, b) in self.data.iter().zip(other.data.iter_mut()) {
-            *b *= *a;
-        }
-        other
-    }
-}
-// MatrixSxD
-// --------------------------------------------------
-impl<T: MulAssign + Copy, const ROWS: usize> Mul<MatrixSxD<T, ROWS>> for MatrixSxD<T, ROWS> {
-    type Output = Self;
-    fn mul(mut self, other: Self) -> Self {
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-        self
-    }
-}
-impl<T: Mul<Output = T> + Copy + Default, const ROWS: usize, const COLUMNS: usize>
-    Mul<MatrixDxS<T, COLUMNS>> for MatrixSxD<T, ROWS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn mul(self, other: MatrixDxS<T, COLUMNS>) -> Self::Output {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-        let mut data = [Default::default(); ROWS * COLUMNS];
-        for i in 0..ROWS * COLUMNS {
-            data[i] = self.data[i] * other.data[i];
-        }
-        Self::Output { data }
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize> Mul<MatrixDxD<T>> for MatrixSxD<T, ROWS> {
-    type Output = Self;
-    fn mul(mut self, other: MatrixDxD<T>) -> Self::Output {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-        self
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize> Mul<MatrixSxS<T, ROWS, COLUMNS>>
-    for MatrixSxD<T, ROWS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn mul(self, mut other: MatrixSxS<T, ROWS, COLUMNS>) -> Self::Output {
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-        for (a, b) in self.data.iter().zip(other.data.iter_mut()) {
-            *b *= *a;
-        }
-        other
-    }
-}
-// MatrixSxS
-// --------------------------------------------------
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize> Mul<MatrixSxS<T, ROWS, COLUMNS>>
-    for MatrixSxS<T, ROWS, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = Self;
-    fn mul(mut self, other: Self) -> Self {
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-        self
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize> Mul<MatrixDxS<T, COLUMNS>>
-    for MatrixSxS<T, ROWS, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = Self;
-    fn mul(mut self, other: MatrixDxS<T, COLUMNS>) -> Self::Output {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-        self
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize> Mul<MatrixSxD<T, ROWS>>
-    for MatrixSxS<T, ROWS, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = Self;
-    fn mul(mut self, other: MatrixSxD<T, ROWS>) -> Self::Output {
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-        self
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize> Mul<MatrixDxD<T>>
-    for MatrixSxS<T, ROWS, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = Self;
-    fn mul(mut self, other: MatrixDxD<T>) -> Self::Output {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-        self
+    type Output = Matrix<T, R::Output, C::Output, L>;
+    fn mul(self, other: Matrix<T, R2, C2, L2>) -> Self::Output {
+        self.zip_map(&other, |a, b| *a * *b)
     }
 }
 // TransposeDxD
 // --------------------------------------------------
-impl<'a, T: MulAssign + Copy> Mul<MatrixDxD<T>> for TransposeDxD<'a, T> {
-    type Output = MatrixDxD<T>;
-    fn mul(self, mut other: MatrixDxD<T>) -> Self::Output {
-        assert_eq!(self.rows(), other.rows, "Non-matching rows");
-        assert_eq!(self.columns(), other.columns, "Non-matching columns");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b *= *a;
-        }
-        other
-    }
-}
-impl<'a, T: MulAssign + Copy, const COLUMNS: usize> Mul<MatrixDxS<T, COLUMNS>>
+impl<'a, T: Mul<Output = T> + Copy, R2: Dim, C2: Dim, L2: Layout> Mul<Matrix<T, R2, C2, L2>>
     for TransposeDxD<'a, T>
-{
-    type Output = MatrixDxS<T, COLUMNS>;
-    fn mul(self, mut other: MatrixDxS<T, COLUMNS>) -> Self::Output {
-        assert_eq!(self.rows(), other.rows, "Non-matching rows");
-        assert_eq!(self.columns(), COLUMNS, "Non-matching columns");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b *= *a;
-        }
-        other
-    }
-}
-impl<'a, T: MulAssign + Copy, const ROWS: usize> Mul<MatrixSxD<T, ROWS>> for TransposeDxD<'a, T> {
-    type Output = MatrixSxD<T, ROWS>;
-    fn mul(self, mut other: MatrixSxD<T, ROWS>) -> Self::Output {
-        assert_eq!(self.rows(), ROWS, "Non-matching rows");
-        assert_eq!(self.columns(), other.columns, "Non-matching columns");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b *= *a;
-        }
-        other
-    }
-}
-impl<'a, T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    Mul<MatrixSxS<T, ROWS, COLUMNS>> for TransposeDxD<'a, T>
 where
-    [(); ROWS * COLUMNS]:,
+    Dynamic: Join<R2>,
+    Dynamic: Join<C2>,
+    (R2, C2, L2): Storage<T>,
+    (
+        <Dynamic as Join<R2>>::Output,
+        <Dynamic as Join<C2>>::Output,
+        RowMajor,
+    ): Storage<T>,
 {
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn mul(self, mut other: MatrixSxS<T, ROWS, COLUMNS>) -> Self::Output {
-        assert_eq!(self.rows(), ROWS, "Non-matching rows");
-        assert_eq!(self.columns(), COLUMNS, "Non-matching columns");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b *= *a;
-        }
-        other
+    type Output = Matrix<T, <Dynamic as Join<R2>>::Output, <Dynamic as Join<C2>>::Output>;
+    fn mul(self, other: Matrix<T, R2, C2, L2>) -> Self::Output {
+        zip_rows(
+            self.iter(),
+            (Dynamic, self.rows()),
+            (Dynamic, self.columns()),
+            &other,
+            |a, b| *a * *b,
+        )
     }
 }
 // TransposeDxS
 // --------------------------------------------------
-impl<'a, T: Mul<Output = T> + Default + Copy, const ROWS: usize, const COLUMNS: usize>
-    Mul<MatrixDxS<T, COLUMNS>> for TransposeDxS<'a, T, ROWS>
+impl<'a, T: Mul<Output = T> + Copy, const ROWS: usize, R2: Dim, C2: Dim, L2: Layout>
+    Mul<Matrix<T, R2, C2, L2>> for TransposeDxS<'a, T, ROWS>
 where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn mul(self, other: MatrixDxS<T, COLUMNS>) -> MatrixSxS<T, ROWS, COLUMNS> {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(self.columns(), COLUMNS, "Non-matching columns");
-
-        let mut data = [Default::default(); ROWS * COLUMNS];
-        for (c, (a, b)) in data.iter_mut().zip(self.iter().zip(other.data.iter())) {
-            *c = *a * *b;
-        }
-        Self::Output { data }
-    }
-}
-impl<'a, T: MulAssign + Copy, const ROWS: usize> Mul<MatrixSxD<T, ROWS>>
-    for TransposeDxS<'a, T, ROWS>
+    Static<ROWS>: Join<R2>,
+    Dynamic: Join<C2>,
+    (R2, C2, L2): Storage<T>,
+    (
+        <Static<ROWS> as Join<R2>>::Output,
+        <Dynamic as Join<C2>>::Output,
+        RowMajor,
+    ): Storage<T>,
 {
-    type Output = MatrixSxD<T, ROWS>;
-    fn mul(self, mut other: MatrixSxD<T, ROWS>) -> Self::Output {
-        assert_eq!(self.columns(), other.columns, "Non-matching columns");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b *= *a;
-        }
-        other
-    }
-}
-impl<'a, T: Mul<Output = T> + Copy, const ROWS: usize> Mul<MatrixDxD<T>>
-    for TransposeDxS<'a, T, ROWS>
-{
-    type Output = MatrixSxD<T, ROWS>;
-    fn mul(self, other: MatrixDxD<T>) -> Self::Output {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(self.columns(), other.columns, "Non-matching columns");
-        Self::Output {
-            data: self
-                .iter()
-                .zip(other.data.iter())
-                .map(|(a, b)| *a * *b)
-                .collect(),
-            columns: self.columns(),
-        }
-    }
-}
-impl<'a, T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    Mul<MatrixSxS<T, ROWS, COLUMNS>> for TransposeDxS<'a, T, ROWS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn mul(self, mut other: MatrixSxS<T, ROWS, COLUMNS>) -> Self::Output {
-        assert_eq!(self.columns(), COLUMNS, "Non-matching columns");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b *= *a;
-        }
-        other
+    type Output = Matrix<T, <Static<ROWS> as Join<R2>>::Output, <Dynamic as Join<C2>>::Output>;
+    fn mul(self, other: Matrix<T, R2, C2, L2>) -> Self::Output {
+        zip_rows(
+            self.iter(),
+            (Static::<ROWS>, ROWS),
+            (Dynamic, self.columns()),
+            &other,
+            |a, b| *a * *b,
+        )
     }
 }
 // TransposeSxD
 // --------------------------------------------------
-impl<'a, T: Mul<Output = T> + Copy + Default, const ROWS: usize, const COLUMNS: usize>
-    Mul<MatrixSxD<T, ROWS>> for TransposeSxD<'a, T, COLUMNS>
+impl<'a, T: Mul<Output = T> + Copy, const COLUMNS: usize, R2: Dim, C2: Dim, L2: Layout>
+    Mul<Matrix<T, R2, C2, L2>> for TransposeSxD<'a, T, COLUMNS>
 where
-    [(); ROWS * COLUMNS]:,
+    Dynamic: Join<R2>,
+    Static<COLUMNS>: Join<C2>,
+    (R2, C2, L2): Storage<T>,
+    (
+        <Dynamic as Join<R2>>::Output,
+        <Static<COLUMNS> as Join<C2>>::Output,
+        RowMajor,
+    ): Storage<T>,
 {
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn mul(self, other: MatrixSxD<T, ROWS>) -> Self::Output {
-        assert_eq!(self.rows(), ROWS, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        let mut data = [Default::default(); ROWS * COLUMNS];
-        for (c, (a, b)) in data.iter_mut().zip(self.iter().zip(other.data.iter())) {
-            *c = *a * *b;
-        }
-        Self::Output { data }
-    }
-}
-impl<'a, T: MulAssign + Copy, const COLUMNS: usize> Mul<MatrixDxS<T, COLUMNS>>
-    for TransposeSxD<'a, T, COLUMNS>
-{
-    type Output = MatrixDxS<T, COLUMNS>;
-    fn mul(self, mut other: MatrixDxS<T, COLUMNS>) -> Self::Output {
-        assert_eq!(self.rows(), other.rows, "Non-matching rows");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b *= *a;
-        }
-        other
-    }
-}
-impl<'a, T: Mul<Output = T> + Copy, const COLUMNS: usize> Mul<MatrixDxD<T>>
-    for TransposeSxD<'a, T, COLUMNS>
-{
-    type Output = MatrixDxS<T, COLUMNS>;
-    fn mul(self, other: MatrixDxD<T>) -> Self::Output {
-        assert_eq!(self.rows(), other.rows, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        Self::Output {
-            data: self
-                .iter()
-                .zip(other.data.iter())
-                .map(|(a, b)| *a * *b)
-                .collect(),
-            rows: self.rows(),
-        }
-    }
-}
-impl<'a, T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    Mul<MatrixSxS<T, ROWS, COLUMNS>> for TransposeSxD<'a, T, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn mul(self, mut other: MatrixSxS<T, ROWS, COLUMNS>) -> Self::Output {
-        assert_eq!(self.rows(), ROWS, "Non-matching columns");
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b *= *a;
-        }
-        other
+    type Output = Matrix<T, <Dynamic as Join<R2>>::Output, <Static<COLUMNS> as Join<C2>>::Output>;
+    fn mul(self, other: Matrix<T, R2, C2, L2>) -> Self::Output {
+        zip_rows(
+            self.iter(),
+            (Dynamic, self.rows()),
+            (Static::<COLUMNS>, COLUMNS),
+            &other,
+            |a, b| *a * *b,
+        )
     }
 }
 // TransposeSxS
 // --------------------------------------------------
-impl<'a, T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    Mul<MatrixSxS<T, ROWS, COLUMNS>> for TransposeSxS<'a, T, COLUMNS, ROWS>
+impl<
+        'a,
+        T: Mul<Output = T> + Copy,
+        const ROWS: usize,
+        const COLUMNS: usize,
+        R2: Dim,
+        C2: Dim,
+        L2: Layout,
+    > Mul<Matrix<T, R2, C2, L2>> for TransposeSxS<'a, T, COLUMNS, ROWS>
 where
-    [(); ROWS * COLUMNS]:,
+    Static<ROWS>: Join<R2>,
+    Static<COLUMNS>: Join<C2>,
+    (R2, C2, L2): Storage<T>,
+    (
+        <Static<ROWS> as Join<R2>>::Output,
+        <Static<COLUMNS> as Join<C2>>::Output,
+        RowMajor,
+    ): Storage<T>,
     [(); COLUMNS * ROWS]:,
 {
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn mul(self, mut other: MatrixSxS<T, ROWS, COLUMNS>) -> Self::Output {
-        for (a, b) in self.iter().zip(other.data.iter_mut()) {
-            *b *= *a;
-        }
-        other
-    }
-}
-impl<'a, T: Mul<Output = T> + Copy + Default, const ROWS: usize, const COLUMNS: usize>
-    Mul<MatrixDxS<T, COLUMNS>> for TransposeSxS<'a, T, COLUMNS, ROWS>
+    type Output =
+        Matrix<T, <Static<ROWS> as Join<R2>>::Output, <Static<COLUMNS> as Join<C2>>::Output>;
+    fn mul(self, other: Matrix<T, R2, C2, L2>) -> Self::Output {
+        zip_rows(
+            self.iter(),
+            (Static::<ROWS>, ROWS),
+            (Static::<COLUMNS>, COLUMNS),
+            &other,
+            |a, b| *a * *b,
+        )
+    }
+}
+// ViewDxD
+// --------------------------------------------------
+impl<'a, T: Mul<Output = T> + Copy, R2: Dim, C2: Dim, L2: Layout> Mul<Matrix<T, R2, C2, L2>>
+    for ViewDxD<'a, T>
 where
-    [(); COLUMNS * ROWS]:,
-    [(); ROWS * COLUMNS]:,
+    Dynamic: Join<R2>,
+    Dynamic: Join<C2>,
+    (R2, C2, L2): Storage<T>,
+    (
+        <Dynamic as Join<R2>>::Output,
+        <Dynamic as Join<C2>>::Output,
+        RowMajor,
+    ): Storage<T>,
 {
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn mul(self, other: MatrixDxS<T, COLUMNS>) -> Self::Output {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        let mut data = [Default::default(); ROWS * COLUMNS];
-        for (c, (a, b)) in data.iter_mut().zip(self.iter().zip(other.data.iter())) {
-            *c = *a * *b;
-        }
-        Self::Output { data }
-    }
-}
-impl<'a, T: Mul<Output = T> + Copy + Default, const ROWS: usize, const COLUMNS: usize>
-    Mul<MatrixSxD<T, ROWS>> for TransposeSxS<'a, T, COLUMNS, ROWS>
+    type Output = Matrix<T, <Dynamic as Join<R2>>::Output, <Dynamic as Join<C2>>::Output>;
+    fn mul(self, other: Matrix<T, R2, C2, L2>) -> Self::Output {
+        zip_rows(
+            self.iter(),
+            (Dynamic, self.rows()),
+            (Dynamic, self.columns()),
+            &other,
+            |a, b| *a * *b,
+        )
+    }
+}
+// ViewDxS
+// --------------------------------------------------
+impl<'a, T: Mul<Output = T> + Copy, const COLUMNS: usize, R2: Dim, C2: Dim, L2: Layout>
+    Mul<Matrix<T, R2, C2, L2>> for ViewDxS<'a, T, COLUMNS>
 where
-    [(); COLUMNS * ROWS]:,
-    [(); ROWS * COLUMNS]:,
+    Dynamic: Join<R2>,
+    Static<COLUMNS>: Join<C2>,
+    (R2, C2, L2): Storage<T>,
+    (
+        <Dynamic as Join<R2>>::Output,
+        <Static<COLUMNS> as Join<C2>>::Output,
+        RowMajor,
+    ): Storage<T>,
 {
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn mul(self, other: MatrixSxD<T, ROWS>) -> Self::Output {
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-        let mut data = [Default::default(); ROWS * COLUMNS];
-        for (c, (a, b)) in data.iter_mut().zip(self.iter().zip(other.data.iter())) {
-            *c = *a * *b;
-        }
-        Self::Output { data }
-    }
-}
-impl<'a, T: Mul<Output = T> + Copy + Default, const ROWS: usize, const COLUMNS: usize>
-    Mul<MatrixDxD<T>> for TransposeSxS<'a, T, COLUMNS, ROWS>
+    type Output = Matrix<T, <Dynamic as Join<R2>>::Output, <Static<COLUMNS> as Join<C2>>::Output>;
+    fn mul(self, other: Matrix<T, R2, C2, L2>) -> Self::Output {
+        zip_rows(
+            self.iter(),
+            (Dynamic, self.rows()),
+            (Static::<COLUMNS>, COLUMNS),
+            &other,
+            |a, b| *a * *b,
+        )
+    }
+}
+// ViewSxD
+// --------------------------------------------------
+impl<'a, T: Mul<Output = T> + Copy, const ROWS: usize, R2: Dim, C2: Dim, L2: Layout>
+    Mul<Matrix<T, R2, C2, L2>> for ViewSxD<'a, T, ROWS>
 where
-    [(); COLUMNS * ROWS]:,
-    [(); ROWS * COLUMNS]:,
+    Static<ROWS>: Join<R2>,
+    Dynamic: Join<C2>,
+    (R2, C2, L2): Storage<T>,
+    (
+        <Static<ROWS> as Join<R2>>::Output,
+        <Dynamic as Join<C2>>::Output,
+        RowMajor,
+    ): Storage<T>,
 {
-    type Output = MatrixSxS<T, ROWS, COLUMNS>;
-    fn mul(self, other: MatrixDxD<T>) -> Self::Output {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-        let mut data = [Default::default(); ROWS * COLUMNS];
-        for (c, (a, b)) in data.iter_mut().zip(self.iter().zip(other.data.iter())) {
-            *c = *a * *b;
-        }
-        Self::Output { data }
+    type Output = Matrix<T, <Static<ROWS> as Join<R2>>::Output, <Dynamic as Join<C2>>::Output>;
+    fn mul(self, other: Matrix<T, R2, C2, L2>) -> Self::Output {
+        zip_rows(
+            self.iter(),
+            (Static::<ROWS>, ROWS),
+            (Dynamic, self.columns()),
+            &other,
+            |a, b| *a * *b,
+        )
+    }
+}
+// ViewSxS
+// --------------------------------------------------
+impl<
+        'a,
+        T: Mul<Output = T> + Copy,
+        const ROWS: usize,
+        const COLUMNS: usize,
+        R2: Dim,
+        C2: Dim,
+        L2: Layout,
+    > Mul<Matrix<T, R2, C2, L2>> for ViewSxS<'a, T, ROWS, COLUMNS>
+where
+    Static<ROWS>: Join<R2>,
+    Static<COLUMNS>: Join<C2>,
+    (R2, C2, L2): Storage<T>,
+    (
+        <Static<ROWS> as Join<R2>>::Output,
+        <Static<COLUMNS> as Join<C2>>::Output,
+        RowMajor,
+    ): Storage<T>,
+{
+    type Output =
+        Matrix<T, <Static<ROWS> as Join<R2>>::Output, <Static<COLUMNS> as Join<C2>>::Output>;
+    fn mul(self, other: Matrix<T, R2, C2, L2>) -> Self::Output {
+        zip_rows(
+            self.iter(),
+            (Static::<ROWS>, ROWS),
+            (Static::<COLUMNS>, COLUMNS),
+            &other,
+            |a, b| *a * *b,
+        )
     }
 }
 // Tests
@@ -869,4 +617,261 @@ mod tests {
         let d = MatrixSxS::from([[1. * 7., 2. * 8., 3. * 9.], [4. * 10., 5. * 11., 6. * 12.]]);
         assert_eq!(c, d);
     }
+    // ViewDxD
+    // --------------------------------------------------
+    #[test]
+    fn view_dxd_dxd() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixDxD::try_from(vec![vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let c = a.slice_dxd((1..3, 1..4)) * b;
+        let d = MatrixDxD::try_from(vec![
+            vec![1. * 7., 2. * 8., 3. * 9.],
+            vec![4. * 10., 5. * 11., 6. * 12.],
+        ])
+        .unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxd_dxs() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixDxS::from(vec![[7., 8., 9.], [10., 11., 12.]]);
+        let c = a.slice_dxd((1..3, 1..4)) * b;
+        let d = MatrixDxS::from(vec![
+            [1. * 7., 2. * 8., 3. * 9.],
+            [4. * 10., 5. * 11., 6. * 12.],
+        ]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxd_sxd() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixSxD::try_from([vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let c = a.slice_dxd((1..3, 1..4)) * b;
+        let d = MatrixSxD::try_from([
+            vec![1. * 7., 2. * 8., 3. * 9.],
+            vec![4. * 10., 5. * 11., 6. * 12.],
+        ])
+        .unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxd_sxs() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixSxS::from([[7., 8., 9.], [10., 11., 12.]]);
+        let c = a.slice_dxd((1..3, 1..4)) * b;
+        let d = MatrixSxS::from([[1. * 7., 2. * 8., 3. * 9.], [4. * 10., 5. * 11., 6. * 12.]]);
+        assert_eq!(c, d);
+    }
+    // ViewDxS
+    // --------------------------------------------------
+    #[test]
+    fn view_dxs_dxd() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixDxD::try_from(vec![vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let c = a.slice_dxs::<{ 1..4 }>(1..3) * b;
+        let d = MatrixDxS::from(vec![
+            [1. * 7., 2. * 8., 3. * 9.],
+            [4. * 10., 5. * 11., 6. * 12.],
+        ]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxs_dxs() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixDxS::from(vec![[7., 8., 9.], [10., 11., 12.]]);
+        let c = a.slice_dxs::<{ 1..4 }>(1..3) * b;
+        let d = MatrixDxS::from(vec![
+            [1. * 7., 2. * 8., 3. * 9.],
+            [4. * 10., 5. * 11., 6. * 12.],
+        ]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxs_sxd() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixSxD::try_from([vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let c = a.slice_dxs::<{ 1..4 }>(1..3) * b;
+        let d = MatrixSxS::from([[1. * 7., 2. * 8., 3. * 9.], [4. * 10., 5. * 11., 6. * 12.]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_dxs_sxs() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixSxS::from([[7., 8., 9.], [10., 11., 12.]]);
+        let c = a.slice_dxs::<{ 1..4 }>(1..3) * b;
+        let d = MatrixSxS::from([[1. * 7., 2. * 8., 3. * 9.], [4. * 10., 5. * 11., 6. * 12.]]);
+        assert_eq!(c, d);
+    }
+    // ViewSxD
+    // --------------------------------------------------
+    #[test]
+    fn view_sxd_dxd() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixDxD::try_from(vec![vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let c = a.slice_sxd::<{ 1..3 }>(1..4) * b;
+        let d = MatrixSxD::try_from([
+            vec![1. * 7., 2. * 8., 3. * 9.],
+            vec![4. * 10., 5. * 11., 6. * 12.],
+        ])
+        .unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxd_dxs() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixDxS::from(vec![[7., 8., 9.], [10., 11., 12.]]);
+        let c = a.slice_sxd::<{ 1..3 }>(1..4) * b;
+        let d = MatrixSxS::from([[1. * 7., 2. * 8., 3. * 9.], [4. * 10., 5. * 11., 6. * 12.]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxd_sxd() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixSxD::try_from([vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let c = a.slice_sxd::<{ 1..3 }>(1..4) * b;
+        let d = MatrixSxD::try_from([
+            vec![1. * 7., 2. * 8., 3. * 9.],
+            vec![4. * 10., 5. * 11., 6. * 12.],
+        ])
+        .unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxd_sxs() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixSxS::from([[7., 8., 9.], [10., 11., 12.]]);
+        let c = a.slice_sxd::<{ 1..3 }>(1..4) * b;
+        let d = MatrixSxS::from([[1. * 7., 2. * 8., 3. * 9.], [4. * 10., 5. * 11., 6. * 12.]]);
+        assert_eq!(c, d);
+    }
+    // ViewSxS
+    // --------------------------------------------------
+    #[test]
+    fn view_sxs_dxd() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixDxD::try_from(vec![vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let c = a.slice_sxs::<{ 1..3 }, { 1..4 }>() * b;
+        let d = MatrixSxS::from([[1. * 7., 2. * 8., 3. * 9.], [4. * 10., 5. * 11., 6. * 12.]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxs_dxs() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixDxS::from(vec![[7., 8., 9.], [10., 11., 12.]]);
+        let c = a.slice_sxs::<{ 1..3 }, { 1..4 }>() * b;
+        let d = MatrixSxS::from([[1. * 7., 2. * 8., 3. * 9.], [4. * 10., 5. * 11., 6. * 12.]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxs_sxd() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixSxD::try_from([vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let c = a.slice_sxs::<{ 1..3 }, { 1..4 }>() * b;
+        let d = MatrixSxS::from([[1. * 7., 2. * 8., 3. * 9.], [4. * 10., 5. * 11., 6. * 12.]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn view_sxs_sxs() {
+        let a = MatrixSxS::from([
+            [0., 0., 0., 0., 0.],
+            [0., 1., 2., 3., 0.],
+            [0., 4., 5., 6., 0.],
+            [0., 0., 0., 0., 0.],
+        ]);
+        let b = MatrixSxS::from([[7., 8., 9.], [10., 11., 12.]]);
+        let c = a.slice_sxs::<{ 1..3 }, { 1..4 }>() * b;
+        let d = MatrixSxS::from([[1. * 7., 2. * 8., 3. * 9.], [4. * 10., 5. * 11., 6. * 12.]]);
+        assert_eq!(c, d);
+    }
+    // ColumnMajor
+    // --------------------------------------------------
+    #[test]
+    fn column_major() {
+        let a = MatrixSxS::from([[1., 2., 3.], [4., 5., 6.]]);
+        let b = MatrixDxD::try_from(vec![vec![1., 4.], vec![2., 5.], vec![3., 6.]]).unwrap();
+        let c = a.clone() * b.transpose();
+        assert_eq!(c, MatrixSxS::from([[1., 4., 9.], [16., 25., 36.]]));
+        let d = MatrixDxS::from(vec![[1., 4.], [2., 5.], [3., 6.]]).transpose() * a.clone();
+        assert_eq!(d, MatrixSxS::from([[1., 4., 9.], [16., 25., 36.]]));
+        let e = a.transpose() * 2.;
+        assert_eq!(
+            e,
+            MatrixSxS::from([[2., 4., 6.], [8., 10., 12.]]).transpose()
+        );
+    }
 }
@@ -1,230 +1,126 @@
+use crate::iter::zip_rows_apply;
 use crate::*;
 use std::ops::MulAssign;
 
 // T
 // --------------------------------------------------
-impl<T: MulAssign + Copy> MulAssign<T> for MatrixDxD<T> {
-    fn mul_assign(&mut self, x: T) {
-        for a in self.data.iter_mut() {
-            *a *= x;
-        }
-    }
-}
-impl<T: MulAssign + Copy, const COLUMNS: usize> MulAssign<T> for MatrixDxS<T, COLUMNS> {
-    fn mul_assign(&mut self, x: T) {
-        for a in self.data.iter_mut() {
-            *a *= x;
-        }
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize> MulAssign<T> for MatrixSxD<T, ROWS> {
-    fn mul_assign(&mut self, x: T) {
-        for a in self.data.iter_mut() {
-            *a *= x;
-        }
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize> MulAssign<T>
-    for MatrixSxS<T, ROWS, COLUMNS>
+impl<T: MulAssign + Copy, R: Dim, C: Dim, L: Layout> MulAssign<T> for Matrix<T, R, C, L>
 where
-    [(); ROWS * COLUMNS]:,
+    (R, C, L): Storage<T>,
 {
     fn mul_assign(&mut self, x: T) {
-        for a in self.data.iter_mut() {
+        for a in self.data.as_mut().iter_mut() {
             *a *= x;
         }
     }
 }
-// MatrixDxD
+// Matrix
 // --------------------------------------------------
-impl<T: MulAssign + Copy> MulAssign<MatrixDxD<T>> for MatrixDxD<T> {
-    fn mul_assign(&mut self, other: Self) {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-    }
-}
-impl<T: MulAssign + Copy, const COLUMNS: usize> MulAssign<MatrixDxS<T, COLUMNS>> for MatrixDxD<T> {
-    fn mul_assign(&mut self, other: MatrixDxS<T, COLUMNS>) {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize> MulAssign<MatrixSxD<T, ROWS>> for MatrixDxD<T> {
-    fn mul_assign(&mut self, other: MatrixSxD<T, ROWS>) {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    MulAssign<MatrixSxS<T, ROWS, COLUMNS>> for MatrixDxD<T>
+impl<T: MulAssign + Copy, R: Dim, C: Dim, L: Layout, R2: Dim, C2: Dim, L2: Layout>
+    MulAssign<Matrix<T, R2, C2, L2>> for Matrix<T, R, C, L>
 where
-    [(); ROWS * COLUMNS]:,
+    R: Join<R2>,
+    C: Join<C2>,
+    (R, C, L): Storage<T>,
+    (R2, C2, L2): Storage<T>,
 {
-    fn mul_assign(&mut self, other: MatrixSxS<T, ROWS, COLUMNS>) {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
+    fn mul_assign(&mut self, other: Matrix<T, R2, C2, L2>) {
+        self.zip_apply(&other, |a, b| *a *= *b);
     }
 }
-// MatrixDxS
+// ViewDxDMut
 // --------------------------------------------------
-impl<T: MulAssign + Copy, const COLUMNS: usize> MulAssign<MatrixDxS<T, COLUMNS>>
-    for MatrixDxS<T, COLUMNS>
-{
-    fn mul_assign(&mut self, other: Self) {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize> MulAssign<MatrixSxD<T, ROWS>>
-    for MatrixDxS<T, COLUMNS>
-{
-    fn mul_assign(&mut self, other: MatrixSxD<T, ROWS>) {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-    }
-}
-impl<T: MulAssign + Copy, const COLUMNS: usize> MulAssign<MatrixDxD<T>> for MatrixDxS<T, COLUMNS> {
-    fn mul_assign(&mut self, other: MatrixDxD<T>) {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
+impl<'a, T: MulAssign + Copy> MulAssign<T> for ViewDxDMut<'a, T> {
+    fn mul_assign(&mut self, x: T) {
+        for a in self.iter_mut() {
+            *a *= x;
         }
     }
 }
-impl<T: MulAssign + Copy + Default, const ROWS: usize, const COLUMNS: usize>
-    MulAssign<MatrixSxS<T, ROWS, COLUMNS>> for MatrixDxS<T, COLUMNS>
+impl<'a, T: MulAssign + Copy, R2: Dim, C2: Dim, L2: Layout> MulAssign<Matrix<T, R2, C2, L2>>
+    for ViewDxDMut<'a, T>
 where
-    [(); ROWS * COLUMNS]:,
+    Dynamic: Join<R2>,
+    Dynamic: Join<C2>,
+    (R2, C2, L2): Storage<T>,
 {
-    fn mul_assign(&mut self, other: MatrixSxS<T, ROWS, COLUMNS>) {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
+    fn mul_assign(&mut self, other: Matrix<T, R2, C2, L2>) {
+        let shape = (self.rows(), self.columns());
+        zip_rows_apply(self.iter_mut(), shape, &other, |a, b| *a *= *b);
     }
 }
-// MatrixSxD
+// ViewDxSMut
 // --------------------------------------------------
-impl<T: MulAssign + Copy, const ROWS: usize> MulAssign<MatrixSxD<T, ROWS>> for MatrixSxD<T, ROWS> {
-    fn mul_assign(&mut self, other: Self) {
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize> MulAssign<MatrixDxS<T, COLUMNS>>
-    for MatrixSxD<T, ROWS>
-{
-    fn mul_assign(&mut self, other: MatrixDxS<T, COLUMNS>) {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
-    }
-}
-impl<T: MulAssign + Copy, const ROWS: usize> MulAssign<MatrixDxD<T>> for MatrixSxD<T, ROWS> {
-    fn mul_assign(&mut self, other: MatrixDxD<T>) {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
+impl<'a, T: MulAssign + Copy, const COLUMNS: usize> MulAssign<T> for ViewDxSMut<'a, T, COLUMNS> {
+    fn mul_assign(&mut self, x: T) {
+        for a in self.iter_mut() {
+            *a *= x;
         }
     }
 }
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    MulAssign<MatrixSxS<T, ROWS, COLUMNS>> for MatrixSxD<T, ROWS>
+impl<'a, T: MulAssign + Copy, const COLUMNS: usize, R2: Dim, C2: Dim, L2: Layout>
+    MulAssign<Matrix<T, R2, C2, L2>> for ViewDxSMut<'a, T, COLUMNS>
 where
-    [(); ROWS * COLUMNS]:,
+    Dynamic: Join<R2>,
+    Static<COLUMNS>: Join<C2>,
+    (R2, C2, L2): Storage<T>,
 {
-    fn mul_assign(&mut self, other: MatrixSxS<T, ROWS, COLUMNS>) {
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
+    fn mul_assign(&mut self, other: Matrix<T, R2, C2, L2>) {
+        let shape = (self.rows(), self.columns());
+        zip_rows_apply(self.iter_mut(), shape, &other, |a, b| *a *= *b);
     }
 }
-// MatrixSxS
+// ViewSxDMut
 // --------------------------------------------------
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    MulAssign<MatrixSxS<T, ROWS, COLUMNS>> for MatrixSxS<T, ROWS, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    fn mul_assign(&mut self, other: Self) {
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
+impl<'a, T: MulAssign + Copy, const ROWS: usize> MulAssign<T> for ViewSxDMut<'a, T, ROWS> {
+    fn mul_assign(&mut self, x: T) {
+        for a in self.iter_mut() {
+            *a *= x;
         }
     }
 }
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize> MulAssign<MatrixDxS<T, COLUMNS>>
-    for MatrixSxS<T, ROWS, COLUMNS>
+impl<'a, T: MulAssign + Copy, const ROWS: usize, R2: Dim, C2: Dim, L2: Layout>
+    MulAssign<Matrix<T, R2, C2, L2>> for ViewSxDMut<'a, T, ROWS>
 where
-    [(); ROWS * COLUMNS]:,
+    Static<ROWS>: Join<R2>,
+    Dynamic: Join<C2>,
+    (R2, C2, L2): Storage<T>,
 {
-    fn mul_assign(&mut self, other: MatrixDxS<T, COLUMNS>) {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
+    fn mul_assign(&mut self, other: Matrix<T, R2, C2, L2>) {
+        let shape = (self.rows(), self.columns());
+        zip_rows_apply(self.iter_mut(), shape, &other, |a, b| *a *= *b);
     }
 }
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize> MulAssign<MatrixSxD<T, ROWS>>
-    for MatrixSxS<T, ROWS, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
+// ViewSxSMut
+// --------------------------------------------------
+impl<'a, T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize> MulAssign<T>
+    for ViewSxSMut<'a, T, ROWS, COLUMNS>
 {
-    fn mul_assign(&mut self, other: MatrixSxD<T, ROWS>) {
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
+    fn mul_assign(&mut self, x: T) {
+        for a in self.iter_mut() {
+            *a *= x;
         }
     }
 }
-impl<T: MulAssign + Copy, const ROWS: usize, const COLUMNS: usize> MulAssign<MatrixDxD<T>>
-    for MatrixSxS<T, ROWS, COLUMNS>
+impl<
+        'a,
+        T: MulAssign + Copy,
+        const ROWS: usize,
+        const COLUMNS: usize,
+        R2: Dim,
+        C2: Dim,
+        L2: Layout,
+    > MulAssign<Matrix<T, R2, C2, L2>> for ViewSxSMut<'a, T, ROWS, COLUMNS>
 where
-    [(); ROWS * COLUMNS]:,
+    Static<ROWS>: Join<R2>,
+    Static<COLUMNS>: Join<C2>,
+    (R2, C2, L2): Storage<T>,
 {
-    fn mul_assign(&mut self, other: MatrixDxD<T>) {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a *= *b;
-        }
+    fn mul_assign(&mut self, other: Matrix<T, R2, C2, L2>) {
+        let shape = (self.rows(), self.columns());
+        zip_rows_apply(self.iter_mut(), shape, &other, |a, b| *a *= *b);
     }
 }
-
 // Tests
 // --------------------------------------------------
 #[cfg(test)]
@@ -447,4 +343,282 @@ mod tests {
         let d = MatrixSxS::from([[1. * 7., 2. * 8., 3. * 9.], [4. * 10., 5. * 11., 6. * 12.]]);
         assert_eq!(a, d);
     }
+    // ViewDxDMut
+    // --------------------------------------------------
+    #[test]
+    fn t_view_dxd() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let mut c = a.slice_dxd_mut((0..2, 0..3));
+        c *= 3.;
+        let d = MatrixSxS::from([
+            [1. * 3., 2. * 3., 3. * 3., 0.],
+            [4. * 3., 5. * 3., 6. * 3., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxd_dxd() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixDxD::try_from(vec![vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let mut c = a.slice_dxd_mut((0..2, 0..3));
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxd_dxs() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixDxS::from(vec![[7., 8., 9.], [10., 11., 12.]]);
+        let mut c = a.slice_dxd_mut((0..2, 0..3));
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxd_sxd() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixSxD::try_from([vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let mut c = a.slice_dxd_mut((0..2, 0..3));
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxd_sxs() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixSxS::from([[7., 8., 9.], [10., 11., 12.]]);
+        let mut c = a.slice_dxd_mut((0..2, 0..3));
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    // ViewDxSMut
+    // --------------------------------------------------
+    #[test]
+    fn t_view_dxs() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let mut c = a.slice_dxs_mut::<{ 0..3 }>(0..2);
+        c *= 3.;
+        let d = MatrixSxS::from([
+            [1. * 3., 2. * 3., 3. * 3., 0.],
+            [4. * 3., 5. * 3., 6. * 3., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxs_dxd() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixDxD::try_from(vec![vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let mut c = a.slice_dxs_mut::<{ 0..3 }>(0..2);
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxs_dxs() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixDxS::from(vec![[7., 8., 9.], [10., 11., 12.]]);
+        let mut c = a.slice_dxs_mut::<{ 0..3 }>(0..2);
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxs_sxd() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixSxD::try_from([vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let mut c = a.slice_dxs_mut::<{ 0..3 }>(0..2);
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxs_sxs() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixSxS::from([[7., 8., 9.], [10., 11., 12.]]);
+        let mut c = a.slice_dxs_mut::<{ 0..3 }>(0..2);
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    // ViewSxDMut
+    // --------------------------------------------------
+    #[test]
+    fn t_view_sxd() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let mut c = a.slice_sxd_mut::<{ 0..2 }>(0..3);
+        c *= 3.;
+        let d = MatrixSxS::from([
+            [1. * 3., 2. * 3., 3. * 3., 0.],
+            [4. * 3., 5. * 3., 6. * 3., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxd_dxd() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixDxD::try_from(vec![vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let mut c = a.slice_sxd_mut::<{ 0..2 }>(0..3);
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxd_dxs() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixDxS::from(vec![[7., 8., 9.], [10., 11., 12.]]);
+        let mut c = a.slice_sxd_mut::<{ 0..2 }>(0..3);
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxd_sxd() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixSxD::try_from([vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let mut c = a.slice_sxd_mut::<{ 0..2 }>(0..3);
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxd_sxs() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixSxS::from([[7., 8., 9.], [10., 11., 12.]]);
+        let mut c = a.slice_sxd_mut::<{ 0..2 }>(0..3);
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    // ViewSxSMut
+    // --------------------------------------------------
+    #[test]
+    fn t_view_sxs() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let mut c = a.slice_sxs_mut::<{ 0..2 }, { 0..3 }>();
+        c *= 3.;
+        let d = MatrixSxS::from([
+            [1. * 3., 2. * 3., 3. * 3., 0.],
+            [4. * 3., 5. * 3., 6. * 3., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxs_dxd() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixDxD::try_from(vec![vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let mut c = a.slice_sxs_mut::<{ 0..2 }, { 0..3 }>();
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxs_dxs() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixDxS::from(vec![[7., 8., 9.], [10., 11., 12.]]);
+        let mut c = a.slice_sxs_mut::<{ 0..2 }, { 0..3 }>();
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxs_sxd() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixSxD::try_from([vec![7., 8., 9.], vec![10., 11., 12.]]).unwrap();
+        let mut c = a.slice_sxs_mut::<{ 0..2 }, { 0..3 }>();
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxs_sxs() {
+        let mut a = MatrixSxS::from([[1., 2., 3., 0.], [4., 5., 6., 0.], [0., 0., 0., 0.]]);
+        let b = MatrixSxS::from([[7., 8., 9.], [10., 11., 12.]]);
+        let mut c = a.slice_sxs_mut::<{ 0..2 }, { 0..3 }>();
+        c *= b;
+        let d = MatrixSxS::from([
+            [1. * 7., 2. * 8., 3. * 9., 0.],
+            [4. * 10., 5. * 11., 6. * 12., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        assert_eq!(a, d);
+    }
+    // ColumnMajor
+    // --------------------------------------------------
+    #[test]
+    fn column_major() {
+        let mut a = MatrixSxS::from([[1., 2., 3.], [4., 5., 6.]]);
+        a *= MatrixDxS::from(vec![[1., 4.], [2., 5.], [3., 6.]]).transpose();
+        assert_eq!(a, MatrixSxS::from([[1., 4., 9.], [16., 25., 36.]]));
+        let mut b = MatrixSxD::try_from([vec![1., 2., 3.], vec![4., 5., 6.]])
+            .unwrap()
+            .to_layout::<ColumnMajor>();
+        b *= 2.;
+        b *= a;
+        assert_eq!(b, MatrixSxS::from([[2., 8., 18.], [32., 50., 72.]]));
+    }
 }
@@ -0,0 +1,1156 @@
+//! Mirrors the subset of [`cblas`](https://docs.rs/cblas) used, such that it can replace it when
+//!  no BLAS backend is enabled.
+//!
+//! Functions are `unsafe` only to match the signatures they replace.
+use crate::matmul::{blocked, Strided};
+use crate::semiring::Over;
+use crate::Arithmetic;
+use num_complex::Complex;
+use num_traits::{Float, Num};
+use std::ops::Neg;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    RowMajor,
+    ColumnMajor,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transpose {
+    None,
+    Ordinary,
+    Conjugate,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    Upper,
+    Lower,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diagonal {
+    Generic,
+    Unit,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+/// An element of a BLAS operation.
+pub trait Scalar: Copy + Num {
+    /// Complex conjugate, the identity for real numbers.
+    fn conj(self) -> Self {
+        self
+    }
+}
+impl Scalar for f32 {}
+impl Scalar for f64 {}
+impl<T: Copy + Num + Neg<Output = T>> Scalar for Complex<T> {
+    fn conj(self) -> Self {
+        Complex::conj(&self)
+    }
+}
+/// Position of element `i` of an `n` element vector with elements `inc` apart, where a negative
+///  `inc` starts from the end as in BLAS.
+fn offset(i: usize, n: usize, inc: i32) -> usize {
+    match inc >= 0 {
+        true => i * inc as usize,
+        false => (n - 1 - i) * inc.unsigned_abs() as usize,
+    }
+}
+/// Element `i`,`j` of `op(x)` where `x` has rows (or columns if column-major) `ld` apart.
+fn element<T: Scalar>(
+    x: &[T],
+    layout: Layout,
+    ld: usize,
+    trans: Transpose,
+    i: usize,
+    j: usize,
+) -> T {
+    let (i, j) = match trans {
+        Transpose::None => (i, j),
+        Transpose::Ordinary | Transpose::Conjugate => (j, i),
+    };
+    let y = x[index(layout, ld, i, j)];
+    match trans {
+        Transpose::Conjugate => y.conj(),
+        _ => y,
+    }
+}
+/// Position of element `i`,`j` of `x` where `x` has rows (or columns if column-major) `ld` apart.
+fn index(layout: Layout, ld: usize, i: usize, j: usize) -> usize {
+    match layout {
+        Layout::RowMajor => i * ld + j,
+        Layout::ColumnMajor => j * ld + i,
+    }
+}
+/// `beta·y`, where as in BLAS `y` is not read when `beta` is zero.
+fn scale<T: Scalar>(beta: T, y: T) -> T {
+    match beta.is_zero() {
+        true => T::zero(),
+        false => beta * y,
+    }
+}
+/// Element `i`,`j` of the triangular `op(A)`, where `A` has only its `uplo` triangle read.
+#[allow(clippy::too_many_arguments)]
+fn triangular<T: Scalar>(
+    a: &[T],
+    layout: Layout,
+    lda: usize,
+    uplo: Part,
+    transa: Transpose,
+    diag: Diagonal,
+    i: usize,
+    j: usize,
+) -> T {
+    // Transposing swaps which triangle of `op(A)` is non-zero.
+    let inside = match (uplo == Part::Lower) == (transa == Transpose::None) {
+        true => j <= i,
+        false => j >= i,
+    };
+    match (i == j && diag == Diagonal::Unit, inside) {
+        (true, _) => T::one(),
+        (false, true) => element(a, layout, lda, transa, i, j),
+        (false, false) => T::zero(),
+    }
+}
+/// Element `i`,`j` of the symmetric `A`, where only its `uplo` triangle is read.
+fn symmetric<T: Scalar>(a: &[T], layout: Layout, lda: usize, uplo: Part, i: usize, j: usize) -> T {
+    let (i, j) = match (uplo, i >= j) {
+        (Part::Lower, true) | (Part::Upper, false) => (i, j),
+        _ => (j, i),
+    };
+    element(a, layout, lda, Transpose::None, i, j)
+}
+
+// Level 1
+// --------------------------------------------------
+pub use self::{
+    asum as dasum, asum as sasum, axpy as daxpy, axpy as saxpy, copy as dcopy, copy as scopy,
+    dot as ddot, dot as sdot, iamax as idamax, iamax as isamax, nrm2 as dnrm2, nrm2 as snrm2,
+    rot as drot, rot as srot, rotg as drotg, rotg as srotg, scal as dscal, scal as sscal,
+    swap as dswap, swap as sswap,
+};
+/// `y = alpha·x + y`
+pub unsafe fn axpy<T: Scalar>(n: i32, alpha: T, x: &[T], incx: i32, y: &mut [T], incy: i32) {
+    let n = n as usize;
+    for i in 0..n {
+        let j = offset(i, n, incy);
+        y[j] = alpha * x[offset(i, n, incx)] + y[j];
+    }
+}
+/// `xᵀ·y`
+pub unsafe fn dot<T: Scalar>(n: i32, x: &[T], incx: i32, y: &[T], incy: i32) -> T {
+    let n = n as usize;
+    (0..n).fold(T::zero(), |s, i| {
+        s + x[offset(i, n, incx)] * y[offset(i, n, incy)]
+    })
+}
+/// Euclidean norm of `x`.
+pub unsafe fn nrm2<T: Float>(n: i32, x: &[T], incx: i32) -> T {
+    let n = n as usize;
+    // Scales by the largest magnitude to avoid overflow, as in BLAS.
+    let scale = (0..n).fold(T::zero(), |s, i| s.max(x[offset(i, n, incx)].abs()));
+    if scale == T::zero() {
+        return scale;
+    }
+    let sum = (0..n).fold(T::zero(), |s, i| {
+        let y = x[offset(i, n, incx)] / scale;
+        s + y * y
+    });
+    scale * sum.sqrt()
+}
+/// Sum of the magnitudes of `x`.
+pub unsafe fn asum<T: Float>(n: i32, x: &[T], incx: i32) -> T {
+    let n = n as usize;
+    (0..n).fold(T::zero(), |s, i| s + x[offset(i, n, incx)].abs())
+}
+/// Index of the first element of `x` with the largest magnitude.
+pub unsafe fn iamax<T: Float>(n: i32, x: &[T], incx: i32) -> i32 {
+    let n = n as usize;
+    let mut max = (0, T::neg_infinity());
+    for i in 0..n {
+        let y = x[offset(i, n, incx)].abs();
+        if y > max.1 {
+            max = (i, y);
+        }
+    }
+    max.0 as i32
+}
+/// `x = alpha·x`
+pub unsafe fn scal<T: Scalar>(n: i32, alpha: T, x: &mut [T], incx: i32) {
+    let n = n as usize;
+    for i in 0..n {
+        let j = offset(i, n, incx);
+        x[j] = alpha * x[j];
+    }
+}
+/// Exchanges `x` and `y`.
+pub unsafe fn swap<T: Scalar>(n: i32, x: &mut [T], incx: i32, y: &mut [T], incy: i32) {
+    let n = n as usize;
+    for i in 0..n {
+        std::mem::swap(&mut x[offset(i, n, incx)], &mut y[offset(i, n, incy)]);
+    }
+}
+/// `y = x`
+pub unsafe fn copy<T: Scalar>(n: i32, x: &[T], incx: i32, y: &mut [T], incy: i32) {
+    let n = n as usize;
+    for i in 0..n {
+        y[offset(i, n, incy)] = x[offset(i, n, incx)];
+    }
+}
+/// Applies the plane rotation `[[c, s], [-s, c]]` to each pair of elements of `x` and `y`.
+pub unsafe fn rot<T: Float>(n: i32, x: &mut [T], incx: i32, y: &mut [T], incy: i32, c: T, s: T) {
+    let n = n as usize;
+    for i in 0..n {
+        let (j, k) = (offset(i, n, incx), offset(i, n, incy));
+        let (a, b) = (x[j], y[k]);
+        x[j] = c * a + s * b;
+        y[k] = c * b - s * a;
+    }
+}
+/// Constructs the plane rotation zeroing `b`, overwriting `a` with `r` and `b` with `z`.
+pub unsafe fn rotg<T: Float>(a: &mut [T], b: &mut [T], c: &mut [T], s: &mut [T]) {
+    let (x, y) = (a[0], b[0]);
+    // Scales by the sum of magnitudes to avoid overflow, as in BLAS.
+    let scale = x.abs() + y.abs();
+    if scale == T::zero() {
+        c[0] = T::one();
+        s[0] = T::zero();
+        a[0] = T::zero();
+        b[0] = T::zero();
+        return;
+    }
+    let roe = if x.abs() > y.abs() { x } else { y };
+    let r = scale * ((x / scale).powi(2) + (y / scale).powi(2)).sqrt();
+    let r = if roe < T::zero() { -r } else { r };
+    c[0] = x / r;
+    s[0] = y / r;
+    a[0] = r;
+    b[0] = if x.abs() > y.abs() {
+        s[0]
+    } else if c[0] != T::zero() {
+        T::one() / c[0]
+    } else {
+        T::one()
+    };
+}
+
+// Level 2
+// --------------------------------------------------
+pub use self::{
+    gemv as dgemv, gemv as sgemv, ger as dger, ger as sger, symv as dsymv, symv as ssymv,
+    trsv as dtrsv, trsv as strsv,
+};
+/// `y = alpha·op(A)·x + beta·y` where `A` is `m` by `n`.
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn gemv<T: Scalar>(
+    layout: Layout,
+    transa: Transpose,
+    m: i32,
+    n: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    x: &[T],
+    incx: i32,
+    beta: T,
+    y: &mut [T],
+    incy: i32,
+) {
+    let (rows, columns) = match transa {
+        Transpose::None => (m as usize, n as usize),
+        _ => (n as usize, m as usize),
+    };
+    for i in 0..rows {
+        let sum = (0..columns).fold(T::zero(), |s, j| {
+            s + element(a, layout, lda as usize, transa, i, j) * x[offset(j, columns, incx)]
+        });
+        let k = offset(i, rows, incy);
+        y[k] = alpha * sum + scale(beta, y[k]);
+    }
+}
+/// `A = alpha·x·yᵀ + A` where `A` is `m` by `n`.
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn ger<T: Scalar>(
+    layout: Layout,
+    m: i32,
+    n: i32,
+    alpha: T,
+    x: &[T],
+    incx: i32,
+    y: &[T],
+    incy: i32,
+    a: &mut [T],
+    lda: i32,
+) {
+    let (m, n, lda) = (m as usize, n as usize, lda as usize);
+    for i in 0..m {
+        let xi = alpha * x[offset(i, m, incx)];
+        for j in 0..n {
+            let k = index(layout, lda, i, j);
+            a[k] = xi * y[offset(j, n, incy)] + a[k];
+        }
+    }
+}
+/// `y = alpha·A·x + beta·y` where `A` is `n` by `n` symmetric and only the `uplo` triangle is
+///  read.
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn symv<T: Scalar>(
+    layout: Layout,
+    uplo: Part,
+    n: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    x: &[T],
+    incx: i32,
+    beta: T,
+    y: &mut [T],
+    incy: i32,
+) {
+    let n = n as usize;
+    for i in 0..n {
+        let sum = (0..n).fold(T::zero(), |s, j| {
+            s + symmetric(a, layout, lda as usize, uplo, i, j) * x[offset(j, n, incx)]
+        });
+        let k = offset(i, n, incy);
+        y[k] = alpha * sum + scale(beta, y[k]);
+    }
+}
+/// Solves `op(A)·x = b` overwriting `b` (given as `x`) where `A` is `n` by `n` and triangular.
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn trsv<T: Scalar>(
+    layout: Layout,
+    uplo: Part,
+    transa: Transpose,
+    diag: Diagonal,
+    n: i32,
+    a: &[T],
+    lda: i32,
+    x: &mut [T],
+    incx: i32,
+) {
+    let (n, lda) = (n as usize, lda as usize);
+    // Transposing swaps which triangle of `op(A)` is non-zero.
+    let lower = (uplo == Part::Lower) == (transa == Transpose::None);
+    let order = (0..n).collect::<Vec<_>>();
+    let order = match lower {
+        true => order,
+        false => order.into_iter().rev().collect(),
+    };
+    for (p, &i) in order.iter().enumerate() {
+        let mut y = x[offset(i, n, incx)];
+        for &j in &order[..p] {
+            y = y - element(a, layout, lda, transa, i, j) * x[offset(j, n, incx)];
+        }
+        if diag == Diagonal::Generic {
+            y = y / element(a, layout, lda, transa, i, i);
+        }
+        x[offset(i, n, incx)] = y;
+    }
+}
+
+// Level 3
+// --------------------------------------------------
+pub use self::{
+    gemm as cgemm, gemm as dgemm, gemm as sgemm, gemm as zgemm, symm as dsymm, symm as ssymm,
+    syrk as dsyrk, syrk as ssyrk, trmm as dtrmm, trmm as strmm, trsm as dtrsm, trsm as strsm,
+};
+/// `C = alpha·op(A)·op(B) + beta·C` where `op(A)` is `m` by `k` and `op(B)` is `k` by `n`.
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn gemm<T: Scalar>(
+    layout: Layout,
+    transa: Transpose,
+    transb: Transpose,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    b: &[T],
+    ldb: i32,
+    beta: T,
+    c: &mut [T],
+    ldc: i32,
+) {
+    if layout == Layout::ColumnMajor {
+        // The column-major data of `C = op(A)·op(B)` is the row-major data of
+        //  `Cᵀ = op(B)ᵀ·op(A)ᵀ`.
+        return gemm(
+            Layout::RowMajor,
+            transb,
+            transa,
+            n,
+            m,
+            k,
+            alpha,
+            b,
+            ldb,
+            a,
+            lda,
+            beta,
+            c,
+            ldc,
+        );
+    }
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+    for i in 0..m {
+        for y in c[i * ldc..i * ldc + n].iter_mut() {
+            *y = scale(beta, *y);
+        }
+    }
+    if alpha.is_zero() {
+        return;
+    }
+    // Conjugated operands are conjugated as they are packed.
+    let strided = |x, ld, trans| {
+        let x = Strided::new(x, ld, trans != Transpose::None);
+        match trans {
+            Transpose::Conjugate => x.conjugated(),
+            _ => x,
+        }
+    };
+    let (a, b) = (strided(a, lda, transa), strided(b, ldb, transb));
+    blocked::<T, Over<Arithmetic>>(a, b, c, ldc, m, n, k, |x| alpha * x);
+}
+/// Solves `op(A)·X = alpha·B` (or `X·op(A) = alpha·B` on the right) overwriting `B` with `X`,
+///  where `B` is `m` by `n` and `A` is triangular.
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn trsm<T: Scalar>(
+    layout: Layout,
+    side: Side,
+    uplo: Part,
+    transa: Transpose,
+    diag: Diagonal,
+    m: i32,
+    n: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    b: &mut [T],
+    ldb: i32,
+) {
+    let (m, n, lda, ldb) = (m as usize, n as usize, lda as usize, ldb as usize);
+    let get = |i, j| element(a, layout, lda, transa, i, j);
+    let at = |i, j| index(layout, ldb, i, j);
+    let lower = (uplo == Part::Lower) == (transa == Transpose::None);
+    // Each element is solved for after those it depends on.
+    let order = |len: usize, forward: bool| match forward {
+        true => (0..len).collect::<Vec<_>>(),
+        false => (0..len).rev().collect(),
+    };
+    match side {
+        Side::Left => {
+            let order = order(m, lower);
+            for j in 0..n {
+                for (p, &i) in order.iter().enumerate() {
+                    let mut y = alpha * b[at(i, j)];
+                    for &l in &order[..p] {
+                        y = y - get(i, l) * b[at(l, j)];
+                    }
+                    if diag == Diagonal::Generic {
+                        y = y / get(i, i);
+                    }
+                    b[at(i, j)] = y;
+                }
+            }
+        }
+        Side::Right => {
+            let order = order(n, !lower);
+            for i in 0..m {
+                for (p, &j) in order.iter().enumerate() {
+                    let mut y = alpha * b[at(i, j)];
+                    for &l in &order[..p] {
+                        y = y - b[at(i, l)] * get(l, j);
+                    }
+                    if diag == Diagonal::Generic {
+                        y = y / get(j, j);
+                    }
+                    b[at(i, j)] = y;
+                }
+            }
+        }
+    }
+}
+/// `B = alpha·op(A)·B` (or `B = alpha·B·op(A)` on the right) where `B` is `m` by `n` and `A` is
+///  triangular.
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn trmm<T: Scalar>(
+    layout: Layout,
+    side: Side,
+    uplo: Part,
+    transa: Transpose,
+    diag: Diagonal,
+    m: i32,
+    n: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    b: &mut [T],
+    ldb: i32,
+) {
+    let (m, n, lda, ldb) = (m as usize, n as usize, lda as usize, ldb as usize);
+    let get = |i, j| triangular(a, layout, lda, uplo, transa, diag, i, j);
+    let at = |i, j| index(layout, ldb, i, j);
+    match side {
+        Side::Left => {
+            for j in 0..n {
+                let column = (0..m)
+                    .map(|i| (0..m).fold(T::zero(), |s, l| s + get(i, l) * b[at(l, j)]))
+                    .collect::<Vec<_>>();
+                for (i, y) in column.into_iter().enumerate() {
+                    b[at(i, j)] = alpha * y;
+                }
+            }
+        }
+        Side::Right => {
+            for i in 0..m {
+                let row = (0..n)
+                    .map(|j| (0..n).fold(T::zero(), |s, l| s + b[at(i, l)] * get(l, j)))
+                    .collect::<Vec<_>>();
+                for (j, y) in row.into_iter().enumerate() {
+                    b[at(i, j)] = alpha * y;
+                }
+            }
+        }
+    }
+}
+/// `C = alpha·op(A)·op(A)ᵀ + beta·C` where `C` is `n` by `n` symmetric with only its `uplo`
+///  triangle written and `op(A)` is `n` by `k`.
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn syrk<T: Scalar>(
+    layout: Layout,
+    uplo: Part,
+    trans: Transpose,
+    n: i32,
+    k: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    beta: T,
+    c: &mut [T],
+    ldc: i32,
+) {
+    let (n, k, lda, ldc) = (n as usize, k as usize, lda as usize, ldc as usize);
+    let get = |i, j| element(a, layout, lda, trans, i, j);
+    for i in 0..n {
+        let columns = match uplo {
+            Part::Lower => 0..i + 1,
+            Part::Upper => i..n,
+        };
+        for j in columns {
+            let sum = (0..k).fold(T::zero(), |s, l| s + get(i, l) * get(j, l));
+            let y = &mut c[index(layout, ldc, i, j)];
+            *y = alpha * sum + scale(beta, *y);
+        }
+    }
+}
+/// `C = alpha·A·B + beta·C` (or `C = alpha·B·A + beta·C` on the right) where `B` and `C` are `m`
+///  by `n` and `A` is symmetric with only its `uplo` triangle read.
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn symm<T: Scalar>(
+    layout: Layout,
+    side: Side,
+    uplo: Part,
+    m: i32,
+    n: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    b: &[T],
+    ldb: i32,
+    beta: T,
+    c: &mut [T],
+    ldc: i32,
+) {
+    let (m, n) = (m as usize, n as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+    let get = |i, j| symmetric(a, layout, lda, uplo, i, j);
+    let b = |i, j| b[index(layout, ldb, i, j)];
+    for i in 0..m {
+        for j in 0..n {
+            let sum = match side {
+                Side::Left => (0..m).fold(T::zero(), |s, l| s + get(i, l) * b(l, j)),
+                Side::Right => (0..n).fold(T::zero(), |s, l| s + b(i, l) * get(l, j)),
+            };
+            let y = &mut c[index(layout, ldc, i, j)];
+            *y = alpha * sum + scale(beta, *y);
+        }
+    }
+}
+
+// Tests
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Level 1
+    // --------------------------------------------------
+    #[test]
+    fn axpy() {
+        let mut y = [1., 2., 3.];
+        unsafe { saxpy(3, 2., &[1., 1., 1.], 1, &mut y, 1) };
+        assert_eq!(y, [3., 4., 5.]);
+        let mut y = [1., 0., 2., 0.];
+        unsafe { daxpy(2, 1., &[1., 2.], -1, &mut y, 2) };
+        assert_eq!(y, [3., 0., 3., 0.]);
+    }
+    #[test]
+    fn dot() {
+        assert_eq!(unsafe { sdot(3, &[1., 2., 3.], 1, &[4., 5., 6.], 1) }, 32.);
+        assert_eq!(unsafe { ddot(2, &[1., 0., 2.], 2, &[3., 4.], 1) }, 11.);
+    }
+    #[test]
+    fn nrm2() {
+        assert_eq!(unsafe { snrm2(2, &[3., 4.], 1) }, 5.);
+        let x = 2f64.powi(1000);
+        assert_eq!(unsafe { dnrm2(2, &[3. * x, 4. * x], 1) }, 5. * x);
+        assert_eq!(unsafe { dnrm2(0, &[0f64; 0], 1) }, 0.);
+    }
+    #[test]
+    fn asum() {
+        assert_eq!(unsafe { sasum(3, &[1., -2., 3.], 1) }, 6.);
+    }
+    #[test]
+    fn iamax() {
+        assert_eq!(unsafe { isamax(4, &[1., -4., 3., 4.], 1) }, 1);
+        assert_eq!(unsafe { idamax(2, &[1., -4., 3., 4.], 2) }, 1);
+    }
+    #[test]
+    fn scal() {
+        let mut x = [1., 2., 3., 4.];
+        unsafe { sscal(2, 2., &mut x, 2) };
+        assert_eq!(x, [2., 2., 6., 4.]);
+    }
+    #[test]
+    fn swap() {
+        let (mut x, mut y) = ([1., 2., 3., 4.], [5., 6.]);
+        unsafe { dswap(2, &mut x, 2, &mut y, 1) };
+        assert_eq!(x, [5., 2., 6., 4.]);
+        assert_eq!(y, [1., 3.]);
+    }
+    #[test]
+    fn copy() {
+        let mut y = [0.; 4];
+        unsafe { dcopy(2, &[1., 2.], 1, &mut y, -2) };
+        assert_eq!(y, [2., 0., 1., 0.]);
+    }
+    #[test]
+    fn rot() {
+        let (mut x, mut y) = ([1., 2.], [3., 4.]);
+        unsafe { drot(2, &mut x, 1, &mut y, 1, 0., 1.) };
+        assert_eq!(x, [3., 4.]);
+        assert_eq!(y, [-1., -2.]);
+    }
+    #[test]
+    fn rotg() {
+        let (mut a, mut b, mut c, mut s) = ([3.], [4.], [0.], [0.]);
+        unsafe { drotg(&mut a, &mut b, &mut c, &mut s) };
+        let close = |x: f64, y: f64| (x - y).abs() < 1e-12;
+        assert!(close(a[0], 5.) && close(c[0], 0.6) && close(s[0], 0.8));
+        // `z = 1/c` when `|a| <= |b|`.
+        assert!(close(b[0], 1. / 0.6));
+
+        let (mut a, mut b, mut c, mut s) = ([0f32], [0.], [0.], [0.]);
+        unsafe { srotg(&mut a, &mut b, &mut c, &mut s) };
+        assert_eq!((a, b, c, s), ([0.], [0.], [1.], [0.]));
+    }
+    // Level 2
+    // --------------------------------------------------
+    #[test]
+    fn gemv() {
+        // [[1, 3, 5], [2, 4, 6]]
+        let a = [1., 3., 5., 2., 4., 6.];
+        let mut y = [1., 1.];
+        unsafe {
+            dgemv(
+                Layout::RowMajor,
+                Transpose::None,
+                2,
+                3,
+                1.,
+                &a,
+                3,
+                &[1., 2., 3.],
+                1,
+                2.,
+                &mut y,
+                1,
+            )
+        };
+        assert_eq!(y, [24., 30.]);
+        let mut y = [0., 0., 0.];
+        unsafe {
+            dgemv(
+                Layout::RowMajor,
+                Transpose::Ordinary,
+                2,
+                3,
+                1.,
+                &a,
+                3,
+                &[1., 0., 1.],
+                2,
+                0.,
+                &mut y,
+                1,
+            )
+        };
+        assert_eq!(y, [3., 7., 11.]);
+        // The same data as a column-major 3 by 2 matrix is the transpose.
+        let mut y = [f64::NAN, 0., f64::NAN, 0., f64::NAN, 0.];
+        unsafe {
+            dgemv(
+                Layout::ColumnMajor,
+                Transpose::None,
+                3,
+                2,
+                1.,
+                &a,
+                3,
+                &[1., 1.],
+                1,
+                0.,
+                &mut y,
+                2,
+            )
+        };
+        assert_eq!(
+            y[0..5].iter().step_by(2).collect::<Vec<_>>(),
+            [&3., &7., &11.]
+        );
+    }
+    #[test]
+    fn ger() {
+        let mut a = [1., 1., 1., 1., 1., 1.];
+        unsafe {
+            dger(
+                Layout::RowMajor,
+                2,
+                3,
+                2.,
+                &[1., 2.],
+                1,
+                &[1., 2., 3.],
+                1,
+                &mut a,
+                3,
+            )
+        };
+        assert_eq!(a, [3., 5., 7., 5., 9., 13.]);
+        let mut a = [0.; 6];
+        unsafe {
+            dger(
+                Layout::ColumnMajor,
+                2,
+                3,
+                1.,
+                &[1., 2.],
+                1,
+                &[1., 2., 3.],
+                1,
+                &mut a,
+                2,
+            )
+        };
+        assert_eq!(a, [1., 2., 2., 4., 3., 6.]);
+    }
+    #[test]
+    fn symv() {
+        // [[2, 1], [1, 3]] with only one triangle set.
+        let mut y = [0., 0.];
+        let lower = [2., f64::NAN, 1., 3.];
+        unsafe {
+            dsymv(
+                Layout::RowMajor,
+                Part::Lower,
+                2,
+                1.,
+                &lower,
+                2,
+                &[1., 1.],
+                1,
+                0.,
+                &mut y,
+                1,
+            )
+        };
+        assert_eq!(y, [3., 4.]);
+        let upper = [2., 1., f64::NAN, 3.];
+        unsafe {
+            dsymv(
+                Layout::RowMajor,
+                Part::Upper,
+                2,
+                2.,
+                &upper,
+                2,
+                &[1., 0.],
+                1,
+                1.,
+                &mut y,
+                1,
+            )
+        };
+        assert_eq!(y, [7., 6.]);
+    }
+    #[test]
+    fn trsv() {
+        // [[2, 0], [1, 4]]
+        let a = [2., f64::NAN, 1., 4.];
+        let mut x = [2., 9.];
+        unsafe {
+            dtrsv(
+                Layout::RowMajor,
+                Part::Lower,
+                Transpose::None,
+                Diagonal::Generic,
+                2,
+                &a,
+                2,
+                &mut x,
+                1,
+            )
+        };
+        assert_eq!(x, [1., 2.]);
+        // [[2, 1], [0, 4]]
+        let mut x = [4., 8.];
+        unsafe {
+            dtrsv(
+                Layout::RowMajor,
+                Part::Lower,
+                Transpose::Ordinary,
+                Diagonal::Generic,
+                2,
+                &a,
+                2,
+                &mut x,
+                1,
+            )
+        };
+        assert_eq!(x, [1., 2.]);
+        // [[1, 1], [0, 1]]
+        let mut x = [1., 3.];
+        unsafe {
+            dtrsv(
+                Layout::ColumnMajor,
+                Part::Upper,
+                Transpose::None,
+                Diagonal::Unit,
+                2,
+                &a,
+                2,
+                &mut x,
+                1,
+            )
+        };
+        assert_eq!(x, [-2., 3.]);
+    }
+    // Level 3
+    // --------------------------------------------------
+    const A: [f64; 6] = [1., 3., 5., 2., 4., 6.];
+    const B: [f64; 6] = [7., 10., 8., 11., 9., 12.];
+    #[test]
+    fn gemm_row_major() {
+        let mut c = [1., 1., 1., 1.];
+        unsafe {
+            dgemm(
+                Layout::RowMajor,
+                Transpose::None,
+                Transpose::None,
+                2,
+                2,
+                3,
+                1.,
+                &A,
+                3,
+                &B,
+                2,
+                1.,
+                &mut c,
+                2,
+            )
+        };
+        assert_eq!(c, [77., 104., 101., 137.]);
+    }
+    #[test]
+    fn gemm_column_major() {
+        // `A` and `B` as column-major data are `Aᵀ` (3 by 2) and `Bᵀ` (2 by 3).
+        let mut c = [0.; 9];
+        unsafe {
+            dgemm(
+                Layout::ColumnMajor,
+                Transpose::None,
+                Transpose::None,
+                3,
+                3,
+                2,
+                1.,
+                &A,
+                3,
+                &B,
+                2,
+                0.,
+                &mut c,
+                3,
+            )
+        };
+        assert_eq!(c, [27., 61., 95., 30., 68., 106., 33., 75., 117.]);
+    }
+    #[test]
+    fn gemm_transpose() {
+        let mut c = [0.; 4];
+        unsafe {
+            dgemm(
+                Layout::RowMajor,
+                Transpose::Ordinary,
+                Transpose::Ordinary,
+                2,
+                2,
+                3,
+                2.,
+                &[1., 2., 3., 4., 5., 6.],
+                2,
+                &[7., 8., 9., 10., 11., 12.],
+                3,
+                0.,
+                &mut c,
+                2,
+            )
+        };
+        assert_eq!(c, [152., 206., 200., 272.]);
+    }
+    #[test]
+    fn gemm_conjugate() {
+        let a = [Complex::new(1., 1.), Complex::new(0., 2.)];
+        let b = [Complex::new(1., 0.), Complex::new(0., 1.)];
+        let mut c = [Complex::new(0., 0.)];
+        unsafe {
+            zgemm(
+                Layout::RowMajor,
+                Transpose::Conjugate,
+                Transpose::None,
+                1,
+                1,
+                2,
+                Complex::new(1., 0.),
+                &a,
+                1,
+                &b,
+                1,
+                Complex::new(0., 0.),
+                &mut c,
+                1,
+            )
+        };
+        // (1-i)·1 + (-2i)·i = 3-i
+        assert_eq!(c, [Complex::new(3., -1.)]);
+    }
+    #[test]
+    fn trsm() {
+        // [[2, 0], [1, 4]]
+        let a = [2., f64::NAN, 1., 4.];
+        let mut b = [2., 4., 13., 18.];
+        unsafe {
+            dtrsm(
+                Layout::RowMajor,
+                Side::Left,
+                Part::Lower,
+                Transpose::None,
+                Diagonal::Generic,
+                2,
+                2,
+                1.,
+                &a,
+                2,
+                &mut b,
+                2,
+            )
+        };
+        assert_eq!(b, [1., 2., 3., 4.]);
+        let mut b = [4., 8., 10., 16.];
+        unsafe {
+            dtrsm(
+                Layout::RowMajor,
+                Side::Right,
+                Part::Lower,
+                Transpose::None,
+                Diagonal::Generic,
+                2,
+                2,
+                1.,
+                &a,
+                2,
+                &mut b,
+                2,
+            )
+        };
+        assert_eq!(b, [1., 2., 3., 4.]);
+        // [[2, 1], [0, 4]]
+        let mut b = [2.5, 6., 4., 8.];
+        unsafe {
+            dtrsm(
+                Layout::ColumnMajor,
+                Side::Left,
+                Part::Upper,
+                Transpose::None,
+                Diagonal::Generic,
+                2,
+                2,
+                2.,
+                &a,
+                2,
+                &mut b,
+                2,
+            )
+        };
+        assert_eq!(b, [1., 3., 2., 4.]);
+    }
+    #[test]
+    fn trmm() {
+        // [[2, 0], [1, 4]]
+        let a = [2., f64::NAN, 1., 4.];
+        let mut b = [1., 2., 3., 4.];
+        unsafe {
+            dtrmm(
+                Layout::RowMajor,
+                Side::Left,
+                Part::Lower,
+                Transpose::None,
+                Diagonal::Generic,
+                2,
+                2,
+                1.,
+                &a,
+                2,
+                &mut b,
+                2,
+            )
+        };
+        assert_eq!(b, [2., 4., 13., 18.]);
+        // [[1, 1], [0, 1]]
+        let mut b = [1., 2., 3., 4.];
+        unsafe {
+            dtrmm(
+                Layout::RowMajor,
+                Side::Right,
+                Part::Lower,
+                Transpose::Ordinary,
+                Diagonal::Unit,
+                2,
+                2,
+                1.,
+                &a,
+                2,
+                &mut b,
+                2,
+            )
+        };
+        assert_eq!(b, [1., 3., 3., 7.]);
+    }
+    #[test]
+    fn syrk() {
+        // [[1, 2], [3, 4], [5, 6]]
+        let a = [1., 2., 3., 4., 5., 6.];
+        let mut c = [0., -1., 0., 0.];
+        unsafe {
+            dsyrk(
+                Layout::RowMajor,
+                Part::Lower,
+                Transpose::Ordinary,
+                2,
+                3,
+                1.,
+                &a,
+                2,
+                0.,
+                &mut c,
+                2,
+            )
+        };
+        assert_eq!(c, [35., -1., 44., 56.]);
+        let mut c = [1., 1., 1., 1.];
+        unsafe {
+            dsyrk(
+                Layout::RowMajor,
+                Part::Upper,
+                Transpose::None,
+                2,
+                2,
+                1.,
+                &a,
+                2,
+                1.,
+                &mut c,
+                2,
+            )
+        };
+        assert_eq!(c, [6., 12., 1., 26.]);
+    }
+    #[test]
+    fn symm() {
+        // [[2, 1], [1, 3]]
+        let a = [2., f64::NAN, 1., 3.];
+        let b = [1., 2., 3., 4.];
+        let mut c = [0.; 4];
+        unsafe {
+            dsymm(
+                Layout::RowMajor,
+                Side::Left,
+                Part::Lower,
+                2,
+                2,
+                1.,
+                &a,
+                2,
+                &b,
+                2,
+                0.,
+                &mut c,
+                2,
+            )
+        };
+        assert_eq!(c, [5., 8., 10., 14.]);
+        let mut c = [1.; 4];
+        unsafe {
+            dsymm(
+                Layout::RowMajor,
+                Side::Right,
+                Part::Lower,
+                2,
+                2,
+                1.,
+                &a,
+                2,
+                &b,
+                2,
+                1.,
+                &mut c,
+                2,
+            )
+        };
+        assert_eq!(c, [5., 8., 11., 16.]);
+        let mut c = [0.; 4];
+        unsafe {
+            dsymm(
+                Layout::ColumnMajor,
+                Side::Left,
+                Part::Upper,
+                2,
+                2,
+                1.,
+                &a,
+                2,
+                &[1., 3., 2., 4.],
+                2,
+                0.,
+                &mut c,
+                2,
+            )
+        };
+        assert_eq!(c, [5., 10., 8., 14.]);
+    }
+}
@@ -49,6 +49,20 @@ where
         self
     }
 }
+// ColumnMajor
+// --------------------------------------------------
+impl<T: Neg<Output = T> + Copy, R: Dim, C: Dim> Neg for Matrix<T, R, C, ColumnMajor>
+where
+    (R, C, ColumnMajor): Storage<T>,
+{
+    type Output = Self;
+    fn neg(mut self) -> Self {
+        for a in self.data.as_mut().iter_mut() {
+            *a = -*a;
+        }
+        self
+    }
+}
 // TransposeDxD
 // --------------------------------------------------
 impl<'a, T: Neg<Output = T> + Copy> Neg for TransposeDxD<'a, T> {
@@ -69,6 +83,7 @@ impl<'a, T: Neg<Output = T> + Copy, const ROWS: usize> Neg for TransposeDxS<'a,
         Self::Output {
             data: self.iter().map(|v| -*v).collect(),
             columns: self.columns(),
+            rows: Static,
         }
     }
 }
@@ -80,6 +95,7 @@ impl<'a, T: Neg<Output = T> + Copy, const COLUMNS: usize> Neg for TransposeSxD<'
         Self::Output {
             data: self.iter().map(|v| -*v).collect(),
             rows: self.rows(),
+            columns: Static,
         }
     }
 }
@@ -100,10 +116,69 @@ where
                 .collect::<Vec<_>>()
                 .try_into()
                 .unwrap(),
+            rows: Static,
+            columns: Static,
         }
     }
 }
 
+// ViewDxD
+// --------------------------------------------------
+impl<'a, T: Neg<Output = T> + Copy> Neg for ViewDxD<'a, T> {
+    type Output = MatrixDxD<T>;
+    fn neg(self) -> Self::Output {
+        Self::Output {
+            data: self.iter().map(|v| -*v).collect(),
+            rows: self.rows(),
+            columns: self.columns(),
+        }
+    }
+}
+// ViewDxS
+// --------------------------------------------------
+impl<'a, T: Neg<Output = T> + Copy, const COLUMNS: usize> Neg for ViewDxS<'a, T, COLUMNS> {
+    type Output = MatrixDxS<T, COLUMNS>;
+    fn neg(self) -> Self::Output {
+        Self::Output {
+            data: self.iter().map(|v| -*v).collect(),
+            rows: self.rows(),
+            columns: Static,
+        }
+    }
+}
+// ViewSxD
+// --------------------------------------------------
+impl<'a, T: Neg<Output = T> + Copy, const ROWS: usize> Neg for ViewSxD<'a, T, ROWS> {
+    type Output = MatrixSxD<T, ROWS>;
+    fn neg(self) -> Self::Output {
+        Self::Output {
+            data: self.iter().map(|v| -*v).collect(),
+            columns: self.columns(),
+            rows: Static,
+        }
+    }
+}
+// ViewSxS
+// --------------------------------------------------
+impl<'a, T: Neg<Output = T> + Copy + Debug, const ROWS: usize, const COLUMNS: usize> Neg
+    for ViewSxS<'a, T, ROWS, COLUMNS>
+where
+    [(); ROWS * COLUMNS]:,
+{
+    type Output = MatrixSxS<T, ROWS, COLUMNS>;
+    fn neg(self) -> Self::Output {
+        Self::Output {
+            data: self
+                .iter()
+                .map(|v| -*v)
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+            rows: Static,
+            columns: Static,
+        }
+    }
+}
 // Tests
 // --------------------------------------------------
 #[cfg(test)]
@@ -174,4 +249,63 @@ mod tests {
         let b = MatrixSxS::<i32, 2, 3>::from([[-1, -2, -3], [-4, -5, -6]]);
         assert_eq!(-a.transpose_ref(), b);
     }
+    // ViewDxD
+    // --------------------------------------------------
+    #[test]
+    fn view_dxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 2, 3, 0],
+            [0, 4, 5, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixDxD::try_from(vec![vec![-1, -2, -3], vec![-4, -5, -6]]).unwrap();
+        assert_eq!(-a.slice_dxd((1..3, 1..4)), b);
+    }
+    // ViewDxS
+    // --------------------------------------------------
+    #[test]
+    fn view_dxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 2, 3, 0],
+            [0, 4, 5, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixDxS::from(vec![[-1, -2, -3], [-4, -5, -6]]);
+        assert_eq!(-a.slice_dxs::<{ 1..4 }>(1..3), b);
+    }
+    // ViewSxD
+    // --------------------------------------------------
+    #[test]
+    fn view_sxd() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 2, 3, 0],
+            [0, 4, 5, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxD::try_from([vec![-1, -2, -3], vec![-4, -5, -6]]).unwrap();
+        assert_eq!(-a.slice_sxd::<{ 1..3 }>(1..4), b);
+    }
+    // ViewSxS
+    // --------------------------------------------------
+    #[test]
+    fn view_sxs() {
+        let a = MatrixSxS::from([
+            [0, 0, 0, 0, 0],
+            [0, 1, 2, 3, 0],
+            [0, 4, 5, 6, 0],
+            [0, 0, 0, 0, 0],
+        ]);
+        let b = MatrixSxS::<i32, 2, 3>::from([[-1, -2, -3], [-4, -5, -6]]);
+        assert_eq!(-a.slice_sxs::<{ 1..3 }, { 1..4 }>(), b);
+    }
+    // ColumnMajor
+    // --------------------------------------------------
+    #[test]
+    fn column_major() {
+        let a = MatrixSxS::from([[1, 2, 3], [4, 5, 6]]).to_layout::<ColumnMajor>();
+        assert_eq!(-a, MatrixSxS::from([[-1, -2, -3], [-4, -5, -6]]));
+    }
 }
@@ -49,6 +49,20 @@ where
         self
     }
 }
+// ColumnMajor
+// --------------------------------------------------
+impl<T: Not<Output = T> + Copy, R: Dim, C: Dim> Not for Matrix<T, R, C, ColumnMajor>
+where
+    (R, C, ColumnMajor): Storage<T>,
+{
+    type Output = Self;
+    fn not(mut self) -> Self {
+        for a in self.data.as_mut().iter_mut() {
+            *a = !*a;
+        }
+        self
+    }
+}
 // TransposeDxD
 // --------------------------------------------------
 impl<'a, T: Not<Output = T> + Copy> Not for TransposeDxD<'a, T> {
@@ -69,6 +83,7 @@ impl<'a, T: Not<Output = T> + Copy, const ROWS: usize> Not for TransposeDxS<'a,
         Self::Output {
             data: self.iter().map(|v| !*v).collect(),
             columns: self.columns(),
+            rows: Static,
         }
     }
 }
@@ -80,6 +95,7 @@ impl<'a, T: Not<Output = T> + Copy, const COLUMNS: usize> Not for TransposeSxD<'
         Self::Output {
             data: self.iter().map(|v| !*v).collect(),
             rows: self.rows(),
+            columns: Static,
         }
     }
 }
@@ -100,6 +116,8 @@ where
                 .collect::<Vec<_>>()
                 .try_into()
                 .unwrap(),
+            rows: Static,
+            columns: Static,
         }
     }
 }
@@ -183,4 +201,15 @@ mod tests {
         let b = MatrixSxS::<bool, 2, 3>::from([[false, false, true], [false, true, true]]);
         assert_eq!(!a.transpose_ref(), b);
     }
+    // ColumnMajor
+    // --------------------------------------------------
+    #[test]
+    fn column_major() {
+        let a =
+            MatrixSxS::from([[true, false, true], [false, true, false]]).to_layout::<ColumnMajor>();
+        assert_eq!(
+            !a,
+            MatrixSxS::from([[false, true, false], [true, false, true]])
+        );
+    }
 }
@@ -1,6 +1,61 @@
 use crate::*;
 use std::cmp::PartialEq;
 
+// Matrix
+// --------------------------------------------------
+impl<T, R: Dim, C: Dim> PartialEq for Matrix<T, R, C, RowMajor>
+where
+    (R, C, RowMajor): Storage<T>,
+    <(R, C, RowMajor) as Storage<T>>::Buffer: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows && self.columns == other.columns && self.data == other.data
+    }
+}
+impl<T, R: Dim, C: Dim> Eq for Matrix<T, R, C, RowMajor>
+where
+    (R, C, RowMajor): Storage<T>,
+    <(R, C, RowMajor) as Storage<T>>::Buffer: Eq,
+{
+}
+// ColumnMajor
+// --------------------------------------------------
+/// Elements are compared by position, so matrices of different layouts can be equal.
+impl<T: PartialEq, R: Dim, C: Dim, R2: Dim, C2: Dim, L: Layout> PartialEq<Matrix<T, R2, C2, L>>
+    for Matrix<T, R, C, ColumnMajor>
+where
+    (R, C, ColumnMajor): Storage<T>,
+    (R2, C2, L): Storage<T>,
+{
+    fn eq(&self, other: &Matrix<T, R2, C2, L>) -> bool {
+        eq(self, other)
+    }
+}
+impl<T: PartialEq, R: Dim, C: Dim, R2: Dim, C2: Dim> PartialEq<Matrix<T, R2, C2, ColumnMajor>>
+    for Matrix<T, R, C, RowMajor>
+where
+    (R, C, RowMajor): Storage<T>,
+    (R2, C2, ColumnMajor): Storage<T>,
+{
+    fn eq(&self, other: &Matrix<T, R2, C2, ColumnMajor>) -> bool {
+        eq(other, self)
+    }
+}
+impl<T: Eq, R: Dim, C: Dim> Eq for Matrix<T, R, C, ColumnMajor> where (R, C, ColumnMajor): Storage<T>
+{}
+/// Whether `a` and `b` have the same shape and elements.
+fn eq<T: PartialEq, R: Dim, C: Dim, L: Layout, R2: Dim, C2: Dim, L2: Layout>(
+    a: &Matrix<T, R, C, L>,
+    b: &Matrix<T, R2, C2, L2>,
+) -> bool
+where
+    (R, C, L): Storage<T>,
+    (R2, C2, L2): Storage<T>,
+{
+    let (rows, columns) = a.shape();
+    (rows, columns) == b.shape()
+        && (0..rows).all(|i| (0..columns).all(|j| a.get(i, j) == b.get(i, j)))
+}
 // MatrixDxD
 // --------------------------------------------------
 impl<T: PartialEq, const ROWS: usize, const COLUMNS: usize> PartialEq<MatrixSxS<T, ROWS, COLUMNS>>
@@ -0,0 +1,948 @@
+use crate::*;
+use num_traits::Float;
+
+/// Internal QR decomposition trait.
+pub trait InternalQr: Sized {
+    /// Factors `a` such that `A = Q·R`, overwriting `a` with `R` and `q` with `Q`.
+    fn qr(
+        // An `m` by `n` row-major matrix.
+        a: &mut [Self],
+        // An `m` by `m` row-major matrix.
+        q: &mut [Self],
+        // Rows of `a`, rows and columns of `q`.
+        m: usize,
+        // Columns of `a`.
+        n: usize,
+    );
+    /// Factors `a` such that `A = Q·R`, overwriting the upper triangle of `a` with `R` and `b`
+    ///  with `Qᵀ·B`, applying the reflectors to `b` rather than forming `Q`.
+    fn qr_apply(
+        // An `m` by `n` row-major matrix.
+        a: &mut [Self],
+        // An `m` by `k` row-major matrix.
+        b: &mut [Self],
+        // Rows of `a` and rows of `b`.
+        m: usize,
+        // Columns of `a`.
+        n: usize,
+        // Columns of `b`.
+        k: usize,
+    );
+}
+/// Default QR decomposition implementation (Householder reflections).
+impl<T: Float> InternalQr for T {
+    default fn qr(a: &mut [T], q: &mut [T], m: usize, n: usize) {
+        debug_assert_eq!(a.len(), m * n);
+        debug_assert_eq!(q.len(), m * m);
+
+        let two = T::one() + T::one();
+        for (i, x) in q.iter_mut().enumerate() {
+            *x = if i % (m + 1) == 0 {
+                T::one()
+            } else {
+                T::zero()
+            };
+        }
+        for j in 0..n.min(m.saturating_sub(1)) {
+            let (v, v_norm) = match reflect(a, m, n, j) {
+                Some(reflector) => reflector,
+                None => continue,
+            };
+            // Accumulates `Q = Q·H`.
+            for r in 0..m {
+                let s = (0..v.len()).fold(T::zero(), |s, i| s + q[r * m + j + i] * v[i]);
+                let f = two * s / v_norm;
+                for (i, x) in v.iter().enumerate() {
+                    q[r * m + j + i] = q[r * m + j + i] - f * *x;
+                }
+            }
+        }
+    }
+    default fn qr_apply(a: &mut [T], b: &mut [T], m: usize, n: usize, k: usize) {
+        debug_assert_eq!(a.len(), m * n);
+        debug_assert_eq!(b.len(), m * k);
+
+        let two = T::one() + T::one();
+        for j in 0..n.min(m.saturating_sub(1)) {
+            let (v, v_norm) = match reflect(a, m, n, j) {
+                Some(reflector) => reflector,
+                None => continue,
+            };
+            // Applies `H` to the columns of `b`.
+            for c in 0..k {
+                let s = (0..v.len()).fold(T::zero(), |s, i| s + v[i] * b[(j + i) * k + c]);
+                let f = two * s / v_norm;
+                for (i, x) in v.iter().enumerate() {
+                    b[(j + i) * k + c] = b[(j + i) * k + c] - f * *x;
+                }
+            }
+        }
+    }
+}
+/// Applies the Householder reflection `H = I - 2·v·vᵀ/(vᵀ·v)` mapping column `j` of `a` below
+///  the diagonal onto the diagonal, returning `v` and `vᵀ·v`, or `None` if the column is zero.
+fn reflect<T: Float>(a: &mut [T], m: usize, n: usize, j: usize) -> Option<(Vec<T>, T)> {
+    let two = T::one() + T::one();
+    let norm = (j..m)
+        .fold(T::zero(), |s, i| s + a[i * n + j] * a[i * n + j])
+        .sqrt();
+    if norm == T::zero() {
+        return None;
+    }
+    let alpha = if a[j * n + j] > T::zero() {
+        -norm
+    } else {
+        norm
+    };
+    let mut v = (j..m).map(|i| a[i * n + j]).collect::<Vec<_>>();
+    v[0] = v[0] - alpha;
+    let v_norm = v.iter().fold(T::zero(), |s, x| s + *x * *x);
+    // Applies `H` to the remaining columns of `a`.
+    for c in j..n {
+        let s = (0..v.len()).fold(T::zero(), |s, i| s + v[i] * a[(j + i) * n + c]);
+        let f = two * s / v_norm;
+        for (i, x) in v.iter().enumerate() {
+            a[(j + i) * n + c] = a[(j + i) * n + c] - f * *x;
+        }
+    }
+    for i in j + 1..m {
+        a[i * n + j] = T::zero();
+    }
+    Some((v, v_norm))
+}
+/// `f32` QR decomposition specialization.
+#[cfg(feature = "lapacke")]
+impl InternalQr for f32 {
+    fn qr(a: &mut [f32], q: &mut [f32], m: usize, n: usize) {
+        assert_eq!(a.len(), m * n);
+        assert_eq!(q.len(), m * m);
+        let k = m.min(n);
+        let mut tau = vec![Default::default(); k];
+        let info = unsafe {
+            lapacke::sgeqrf(
+                lapacke::Layout::RowMajor,
+                m as i32,
+                n as i32,
+                a,
+                n as i32,
+                &mut tau,
+            )
+        };
+        assert_eq!(info, 0, "Illegal `sgeqrf` argument");
+        reflectors(a, q, m, n);
+        let info = unsafe {
+            lapacke::sorgqr(
+                lapacke::Layout::RowMajor,
+                m as i32,
+                m as i32,
+                k as i32,
+                q,
+                m as i32,
+                &tau,
+            )
+        };
+        assert_eq!(info, 0, "Illegal `sorgqr` argument");
+    }
+    fn qr_apply(a: &mut [f32], b: &mut [f32], m: usize, n: usize, k: usize) {
+        assert_eq!(a.len(), m * n);
+        assert_eq!(b.len(), m * k);
+        let mut tau = vec![Default::default(); m.min(n)];
+        let info = unsafe {
+            lapacke::sgeqrf(
+                lapacke::Layout::RowMajor,
+                m as i32,
+                n as i32,
+                a,
+                n as i32,
+                &mut tau,
+            )
+        };
+        assert_eq!(info, 0, "Illegal `sgeqrf` argument");
+        let info = unsafe {
+            lapacke::sormqr(
+                lapacke::Layout::RowMajor,
+                b'L',
+                b'T',
+                m as i32,
+                k as i32,
+                tau.len() as i32,
+                a,
+                n as i32,
+                &tau,
+                b,
+                k as i32,
+            )
+        };
+        assert_eq!(info, 0, "Illegal `sormqr` argument");
+    }
+}
+/// `f64` QR decomposition specialization.
+#[cfg(feature = "lapacke")]
+impl InternalQr for f64 {
+    fn qr(a: &mut [f64], q: &mut [f64], m: usize, n: usize) {
+        assert_eq!(a.len(), m * n);
+        assert_eq!(q.len(), m * m);
+        let k = m.min(n);
+        let mut tau = vec![Default::default(); k];
+        let info = unsafe {
+            lapacke::dgeqrf(
+                lapacke::Layout::RowMajor,
+                m as i32,
+                n as i32,
+                a,
+                n as i32,
+                &mut tau,
+            )
+        };
+        assert_eq!(info, 0, "Illegal `dgeqrf` argument");
+        reflectors(a, q, m, n);
+        let info = unsafe {
+            lapacke::dorgqr(
+                lapacke::Layout::RowMajor,
+                m as i32,
+                m as i32,
+                k as i32,
+                q,
+                m as i32,
+                &tau,
+            )
+        };
+        assert_eq!(info, 0, "Illegal `dorgqr` argument");
+    }
+    fn qr_apply(a: &mut [f64], b: &mut [f64], m: usize, n: usize, k: usize) {
+        assert_eq!(a.len(), m * n);
+        assert_eq!(b.len(), m * k);
+        let mut tau = vec![Default::default(); m.min(n)];
+        let info = unsafe {
+            lapacke::dgeqrf(
+                lapacke::Layout::RowMajor,
+                m as i32,
+                n as i32,
+                a,
+                n as i32,
+                &mut tau,
+            )
+        };
+        assert_eq!(info, 0, "Illegal `dgeqrf` argument");
+        let info = unsafe {
+            lapacke::dormqr(
+                lapacke::Layout::RowMajor,
+                b'L',
+                b'T',
+                m as i32,
+                k as i32,
+                tau.len() as i32,
+                a,
+                n as i32,
+                &tau,
+                b,
+                k as i32,
+            )
+        };
+        assert_eq!(info, 0, "Illegal `dormqr` argument");
+    }
+}
+/// Moves the Householder reflectors `geqrf` stores below the diagonal of `a` into the first
+///  columns of `q`, leaving `R` in `a`.
+fn reflectors<T: Float>(a: &mut [T], q: &mut [T], m: usize, n: usize) {
+    for i in 0..m {
+        for j in 0..m {
+            q[i * m + j] = if j < i && j < n {
+                std::mem::replace(&mut a[i * n + j], T::zero())
+            } else {
+                T::zero()
+            };
+        }
+    }
+}
+/// Solves `A·X = B` in the least squares sense where `a` is `m` by `n`, `b` is `m` by `k` and
+///  `x` is `n` by `k`.
+fn lstsq<T: Float>(
+    a: &[T],
+    b: &[T],
+    x: &mut [T],
+    m: usize,
+    n: usize,
+    k: usize,
+) -> Result<(), &'static str> {
+    assert!(m >= n, "Underdetermined system");
+
+    let mut r = Vec::from(a);
+    let mut qtb = Vec::from(b);
+    InternalQr::qr_apply(&mut r, &mut qtb, m, n, k);
+    // Diagonals of `R` this small relative to the largest are treated as zero.
+    let largest = (0..n).fold(T::zero(), |max, i| max.max(r[i * n + i].abs()));
+    let tolerance = largest * T::from(m.max(n)).unwrap() * T::epsilon();
+    if (0..n).any(|i| r[i * n + i].abs() <= tolerance) {
+        return Err("Rank deficient matrix");
+    }
+    // The first `n` rows of `Qᵀ·B`.
+    x.copy_from_slice(&qtb[..n * k]);
+    // Backward substitution with the upper triangular factor.
+    for i in (0..n).rev() {
+        for j in i + 1..n {
+            let u = r[i * n + j];
+            for c in 0..k {
+                x[i * k + c] = x[i * k + c] - u * x[j * k + c];
+            }
+        }
+        let diagonal = r[i * n + i];
+        for c in 0..k {
+            x[i * k + c] = x[i * k + c] / diagonal;
+        }
+    }
+    Ok(())
+}
+
+/// A QR decomposition such that `A = Q·R`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Qr<Q, R> {
+    /// Orthogonal factor.
+    pub q: Q,
+    /// Upper triangular factor.
+    pub r: R,
+}
+
+// MatrixDxD
+// --------------------------------------------------
+impl<T: Float> MatrixDxD<T> {
+    /// Computes the QR decomposition of `self`.
+    /// ```
+    /// use static_la::MatrixDxD;
+    /// use std::convert::TryFrom;
+    /// let a = MatrixDxD::try_from(vec![vec![3., 1.], vec![4., 2.]]).unwrap();
+    /// let qr = a.qr();
+    /// assert_eq!((qr.q.rows(), qr.q.columns()), (2, 2));
+    /// assert_eq!((qr.r.rows(), qr.r.columns()), (2, 2));
+    /// ```
+    pub fn qr(&self) -> Qr<MatrixDxD<T>, MatrixDxD<T>> {
+        let (m, n) = (self.rows, self.columns);
+        let mut r = self.data.clone();
+        let mut q = vec![T::zero(); m * m];
+        InternalQr::qr(&mut r, &mut q, m, n);
+        Qr {
+            q: MatrixDxD {
+                data: q,
+                rows: m,
+                columns: m,
+            },
+            r: MatrixDxD {
+                data: r,
+                rows: m,
+                columns: n,
+            },
+        }
+    }
+}
+// MatrixDxS
+// --------------------------------------------------
+impl<T: Float, const N: usize> MatrixDxS<T, N> {
+    /// Computes the QR decomposition of `self`.
+    pub fn qr(&self) -> Qr<MatrixDxD<T>, MatrixDxS<T, N>> {
+        let m = self.rows;
+        let mut r = self.data.clone();
+        let mut q = vec![T::zero(); m * m];
+        InternalQr::qr(&mut r, &mut q, m, N);
+        Qr {
+            q: MatrixDxD {
+                data: q,
+                rows: m,
+                columns: m,
+            },
+            r: MatrixDxS {
+                data: r,
+                rows: m,
+                columns: Static,
+            },
+        }
+    }
+}
+// MatrixSxD
+// --------------------------------------------------
+impl<T: Float, const M: usize> MatrixSxD<T, M>
+where
+    [(); M * M]:,
+{
+    /// Computes the QR decomposition of `self`.
+    pub fn qr(&self) -> Qr<MatrixSxS<T, M, M>, MatrixSxD<T, M>> {
+        let n = self.columns;
+        let mut r = self.data.clone();
+        let mut q = [T::zero(); M * M];
+        InternalQr::qr(&mut r, &mut q, M, n);
+        Qr {
+            q: MatrixSxS {
+                data: q,
+                rows: Static,
+                columns: Static,
+            },
+            r: MatrixSxD {
+                data: r,
+                columns: n,
+                rows: Static,
+            },
+        }
+    }
+}
+// MatrixSxS
+// --------------------------------------------------
+impl<T: Float, const M: usize, const N: usize> MatrixSxS<T, M, N>
+where
+    [(); M * N]:,
+    [(); M * M]:,
+{
+    /// Computes the QR decomposition of `self`.
+    /// ```
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use static_la::MatrixSxS;
+    /// let a = MatrixSxS::<f64, 3, 2>::from([[1., 0.], [1., 1.], [1., 2.]]);
+    /// // `Qr<MatrixSxS<f64, 3, 3>, MatrixSxS<f64, 3, 2>>`
+    /// let qr = a.qr();
+    /// ```
+    pub fn qr(&self) -> Qr<MatrixSxS<T, M, M>, MatrixSxS<T, M, N>> {
+        let mut r = self.data;
+        let mut q = [T::zero(); M * M];
+        InternalQr::qr(&mut r, &mut q, M, N);
+        Qr {
+            q: MatrixSxS {
+                data: q,
+                rows: Static,
+                columns: Static,
+            },
+            r: MatrixSxS {
+                data: r,
+                rows: Static,
+                columns: Static,
+            },
+        }
+    }
+}
+
+/// A trait for least squares solving of overdetermined systems of linear equations.
+pub trait Lstsq<B> {
+    type Output;
+    /// Finds the `X` minimizing `‖self·X - B‖` using the QR decomposition of `self`, returning
+    ///  an error if `self` is rank deficient.
+    /// ```text
+    /// ┌─────┐       ┌───┐  ┌───┐
+    /// │ 1 0 │       │ 1 │  │ 1 │
+    /// │ 1 1 │.lstsq(│ 3 │)=│ 2 │
+    /// │ 1 2 │       │ 5 │  └───┘
+    /// └─────┘       └───┘
+    /// ```
+    /// - M: Rows of `self` and rows of `B`.
+    /// - N: Columns of `self` and rows of `Self::Output`.
+    /// - K: Columns of `B` and columns of `Self::Output`.
+    fn lstsq(&self, b: &B) -> Result<Self::Output, &'static str>;
+}
+
+// MatrixDxD
+// --------------------------------------------------
+impl<T: Float, const M: usize, const K: usize> Lstsq<MatrixSxS<T, M, K>> for MatrixDxD<T>
+where
+    [(); M * K]:,
+{
+    type Output = MatrixDxS<T, K>;
+    fn lstsq(&self, b: &MatrixSxS<T, M, K>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, M, "Non-matching rows");
+
+        let n = self.columns;
+        let mut data = vec![T::zero(); n * K];
+        lstsq(&self.data, &b.data, &mut data, M, n, K)?;
+        Ok(Self::Output {
+            data,
+            rows: n,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const K: usize> Lstsq<MatrixDxS<T, K>> for MatrixDxD<T> {
+    type Output = MatrixDxS<T, K>;
+    fn lstsq(&self, b: &MatrixDxS<T, K>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, b.rows, "Non-matching rows");
+
+        let (m, n) = (self.rows, self.columns);
+        let mut data = vec![T::zero(); n * K];
+        lstsq(&self.data, &b.data, &mut data, m, n, K)?;
+        Ok(Self::Output {
+            data,
+            rows: n,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const M: usize> Lstsq<MatrixSxD<T, M>> for MatrixDxD<T> {
+    type Output = MatrixDxD<T>;
+    fn lstsq(&self, b: &MatrixSxD<T, M>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, M, "Non-matching rows");
+
+        let (n, k) = (self.columns, b.columns);
+        let mut data = vec![T::zero(); n * k];
+        lstsq(&self.data, &b.data, &mut data, M, n, k)?;
+        Ok(Self::Output {
+            data,
+            rows: n,
+            columns: k,
+        })
+    }
+}
+impl<T: Float> Lstsq<MatrixDxD<T>> for MatrixDxD<T> {
+    type Output = MatrixDxD<T>;
+    fn lstsq(&self, b: &MatrixDxD<T>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, b.rows, "Non-matching rows");
+
+        let (m, n, k) = (self.rows, self.columns, b.columns);
+        let mut data = vec![T::zero(); n * k];
+        lstsq(&self.data, &b.data, &mut data, m, n, k)?;
+        Ok(Self::Output {
+            data,
+            rows: n,
+            columns: k,
+        })
+    }
+}
+// MatrixDxS
+// --------------------------------------------------
+impl<T: Float, const M: usize, const N: usize, const K: usize> Lstsq<MatrixSxS<T, M, K>>
+    for MatrixDxS<T, N>
+where
+    [(); M * K]:,
+    [(); N * K]:,
+{
+    type Output = MatrixSxS<T, N, K>;
+    fn lstsq(&self, b: &MatrixSxS<T, M, K>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, M, "Non-matching rows");
+
+        let mut data = [T::zero(); N * K];
+        lstsq(&self.data, &b.data, &mut data, M, N, K)?;
+        Ok(Self::Output {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const N: usize, const K: usize> Lstsq<MatrixDxS<T, K>> for MatrixDxS<T, N>
+where
+    [(); N * K]:,
+{
+    type Output = MatrixSxS<T, N, K>;
+    fn lstsq(&self, b: &MatrixDxS<T, K>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, b.rows, "Non-matching rows");
+
+        let mut data = [T::zero(); N * K];
+        lstsq(&self.data, &b.data, &mut data, self.rows, N, K)?;
+        Ok(Self::Output {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const M: usize, const N: usize> Lstsq<MatrixSxD<T, M>> for MatrixDxS<T, N> {
+    type Output = MatrixSxD<T, N>;
+    fn lstsq(&self, b: &MatrixSxD<T, M>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, M, "Non-matching rows");
+
+        let k = b.columns;
+        let mut data = vec![T::zero(); N * k];
+        lstsq(&self.data, &b.data, &mut data, M, N, k)?;
+        Ok(Self::Output {
+            data,
+            columns: k,
+            rows: Static,
+        })
+    }
+}
+impl<T: Float, const N: usize> Lstsq<MatrixDxD<T>> for MatrixDxS<T, N> {
+    type Output = MatrixSxD<T, N>;
+    fn lstsq(&self, b: &MatrixDxD<T>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, b.rows, "Non-matching rows");
+
+        let k = b.columns;
+        let mut data = vec![T::zero(); N * k];
+        lstsq(&self.data, &b.data, &mut data, self.rows, N, k)?;
+        Ok(Self::Output {
+            data,
+            columns: k,
+            rows: Static,
+        })
+    }
+}
+// MatrixSxD
+// --------------------------------------------------
+impl<T: Float, const M: usize, const K: usize> Lstsq<MatrixSxS<T, M, K>> for MatrixSxD<T, M>
+where
+    [(); M * K]:,
+{
+    type Output = MatrixDxS<T, K>;
+    fn lstsq(&self, b: &MatrixSxS<T, M, K>) -> Result<Self::Output, &'static str> {
+        let n = self.columns;
+        let mut data = vec![T::zero(); n * K];
+        lstsq(&self.data, &b.data, &mut data, M, n, K)?;
+        Ok(Self::Output {
+            data,
+            rows: n,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const M: usize, const K: usize> Lstsq<MatrixDxS<T, K>> for MatrixSxD<T, M> {
+    type Output = MatrixDxS<T, K>;
+    fn lstsq(&self, b: &MatrixDxS<T, K>) -> Result<Self::Output, &'static str> {
+        assert_eq!(M, b.rows, "Non-matching rows");
+
+        let n = self.columns;
+        let mut data = vec![T::zero(); n * K];
+        lstsq(&self.data, &b.data, &mut data, M, n, K)?;
+        Ok(Self::Output {
+            data,
+            rows: n,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const M: usize> Lstsq<MatrixSxD<T, M>> for MatrixSxD<T, M> {
+    type Output = MatrixDxD<T>;
+    fn lstsq(&self, b: &MatrixSxD<T, M>) -> Result<Self::Output, &'static str> {
+        let (n, k) = (self.columns, b.columns);
+        let mut data = vec![T::zero(); n * k];
+        lstsq(&self.data, &b.data, &mut data, M, n, k)?;
+        Ok(Self::Output {
+            data,
+            rows: n,
+            columns: k,
+        })
+    }
+}
+impl<T: Float, const M: usize> Lstsq<MatrixDxD<T>> for MatrixSxD<T, M> {
+    type Output = MatrixDxD<T>;
+    fn lstsq(&self, b: &MatrixDxD<T>) -> Result<Self::Output, &'static str> {
+        assert_eq!(M, b.rows, "Non-matching rows");
+
+        let (n, k) = (self.columns, b.columns);
+        let mut data = vec![T::zero(); n * k];
+        lstsq(&self.data, &b.data, &mut data, M, n, k)?;
+        Ok(Self::Output {
+            data,
+            rows: n,
+            columns: k,
+        })
+    }
+}
+// MatrixSxS
+// --------------------------------------------------
+impl<T: Float, const M: usize, const N: usize, const K: usize> Lstsq<MatrixSxS<T, M, K>>
+    for MatrixSxS<T, M, N>
+where
+    [(); M * N]:,
+    [(); M * K]:,
+    [(); N * K]:,
+{
+    type Output = MatrixSxS<T, N, K>;
+    fn lstsq(&self, b: &MatrixSxS<T, M, K>) -> Result<Self::Output, &'static str> {
+        let mut data = [T::zero(); N * K];
+        lstsq(&self.data, &b.data, &mut data, M, N, K)?;
+        Ok(Self::Output {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const M: usize, const N: usize, const K: usize> Lstsq<MatrixDxS<T, K>>
+    for MatrixSxS<T, M, N>
+where
+    [(); M * N]:,
+    [(); N * K]:,
+{
+    type Output = MatrixSxS<T, N, K>;
+    fn lstsq(&self, b: &MatrixDxS<T, K>) -> Result<Self::Output, &'static str> {
+        assert_eq!(M, b.rows, "Non-matching rows");
+
+        let mut data = [T::zero(); N * K];
+        lstsq(&self.data, &b.data, &mut data, M, N, K)?;
+        Ok(Self::Output {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const M: usize, const N: usize> Lstsq<MatrixSxD<T, M>> for MatrixSxS<T, M, N>
+where
+    [(); M * N]:,
+{
+    type Output = MatrixSxD<T, N>;
+    fn lstsq(&self, b: &MatrixSxD<T, M>) -> Result<Self::Output, &'static str> {
+        let k = b.columns;
+        let mut data = vec![T::zero(); N * k];
+        lstsq(&self.data, &b.data, &mut data, M, N, k)?;
+        Ok(Self::Output {
+            data,
+            columns: k,
+            rows: Static,
+        })
+    }
+}
+impl<T: Float, const M: usize, const N: usize> Lstsq<MatrixDxD<T>> for MatrixSxS<T, M, N>
+where
+    [(); M * N]:,
+{
+    type Output = MatrixSxD<T, N>;
+    fn lstsq(&self, b: &MatrixDxD<T>) -> Result<Self::Output, &'static str> {
+        assert_eq!(M, b.rows, "Non-matching rows");
+
+        let k = b.columns;
+        let mut data = vec![T::zero(); N * k];
+        lstsq(&self.data, &b.data, &mut data, M, N, k)?;
+        Ok(Self::Output {
+            data,
+            columns: k,
+            rows: Static,
+        })
+    }
+}
+
+// Tests
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::convert::TryFrom;
+
+    /// Checks `A = Q·R`, `Qᵀ·Q = I` and that `R` is upper triangular.
+    fn check<T: num_traits::Float + std::fmt::Debug>(
+        a: &[T],
+        q: &[T],
+        r: &[T],
+        m: usize,
+        n: usize,
+    ) {
+        let mut qr = vec![T::zero(); m * n];
+        let mut qtq = vec![T::zero(); m * m];
+        for i in 0..m {
+            for j in 0..n {
+                qr[i * n + j] = (0..m).fold(T::zero(), |s, k| s + q[i * m + k] * r[k * n + j]);
+            }
+            for j in 0..m {
+                qtq[i * m + j] = (0..m).fold(T::zero(), |s, k| s + q[k * m + i] * q[k * m + j]);
+            }
+            for j in 0..n.min(i) {
+                assert_eq!(r[i * n + j], T::zero());
+            }
+        }
+        let identity = (0..m * m)
+            .map(|i| {
+                if i % (m + 1) == 0 {
+                    T::one()
+                } else {
+                    T::zero()
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_approx_eq(a, &qr);
+        assert_approx_eq(&qtq, &identity);
+    }
+    // f32
+    // --------------------------------------------------
+    #[test]
+    fn f32_dxd() {
+        let a = MatrixDxD::<f32>::try_from(vec![vec![1., 2.], vec![3., 4.], vec![5., 6.]]).unwrap();
+        let qr = a.qr();
+        check(&a.data, &qr.q.data, &qr.r.data, 3, 2);
+    }
+    // f64
+    // --------------------------------------------------
+    #[test]
+    fn f64_dxd() {
+        let a = MatrixDxD::<f64>::try_from(vec![vec![1., 2.], vec![3., 4.], vec![5., 6.]]).unwrap();
+        let qr = a.qr();
+        check(&a.data, &qr.q.data, &qr.r.data, 3, 2);
+    }
+    #[test]
+    fn f64_wide() {
+        let a = MatrixSxS::<f64, 2, 3>::from([[1., 2., 3.], [4., 5., 6.]]);
+        let qr = a.qr();
+        check(&a.data, &qr.q.data, &qr.r.data, 2, 3);
+    }
+    // f16
+    // --------------------------------------------------
+    #[test]
+    fn f16_sxs() {
+        let f = half::f16::from_f32;
+        let a =
+            MatrixSxS::<half::f16, 3, 2>::from([[f(1.), f(2.)], [f(3.), f(4.)], [f(5.), f(6.)]]);
+        let qr = a.qr();
+        check(&a.data, &qr.q.data, &qr.r.data, 3, 2);
+    }
+    #[test]
+    fn f16_lstsq() {
+        let f = half::f16::from_f32;
+        let a =
+            MatrixSxS::<half::f16, 3, 2>::from([[f(1.), f(0.)], [f(1.), f(1.)], [f(1.), f(2.)]]);
+        let b = MatrixSxS::<half::f16, 3, 1>::from([[f(1.)], [f(3.)], [f(5.)]]);
+        let x = a.lstsq(&b).unwrap();
+        assert_approx_eq(&x.data, &[f(1.), f(2.)]);
+    }
+    // Decomposition
+    // --------------------------------------------------
+    #[test]
+    fn dxs() {
+        let a = MatrixDxS::from(vec![[1., 2.], [3., 4.], [5., 6.]]);
+        let qr = a.qr();
+        assert_eq!((qr.q.rows(), qr.q.columns()), (3, 3));
+        check(&a.data, &qr.q.data, &qr.r.data, 3, 2);
+    }
+    #[test]
+    fn sxd() {
+        let a = MatrixSxD::try_from([vec![1., 2.], vec![3., 4.], vec![5., 6.]]).unwrap();
+        let qr = a.qr();
+        assert_eq!(qr.r.columns(), 2);
+        check(&a.data, &qr.q.data, &qr.r.data, 3, 2);
+    }
+    #[test]
+    fn sxs() {
+        let a = MatrixSxS::<f64, 3, 2>::from([[1., 2.], [3., 4.], [5., 6.]]);
+        let qr = a.qr();
+        check(&a.data, &qr.q.data, &qr.r.data, 3, 2);
+    }
+    // Least squares
+    // --------------------------------------------------
+    /// Fits `y = 1 + 2x` and `y = 3 - x` at `x = 0, 1, 2, 3`.
+    const A: [[f64; 2]; 4] = [[1., 0.], [1., 1.], [1., 2.], [1., 3.]];
+    const B: [[f64; 2]; 4] = [[1., 3.], [3., 2.], [5., 1.], [7., 0.]];
+    const X: [f64; 4] = [1., 3., 2., -1.];
+    // MatrixDxD
+    // --------------------------------------------------
+    #[test]
+    fn dxd_dxd() {
+        let a = MatrixDxD::try_from(A.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let b = MatrixDxD::try_from(B.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let c: MatrixDxD<f64> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn dxd_dxs() {
+        let a = MatrixDxD::try_from(A.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let b = MatrixDxS::from(B.to_vec());
+        let c: MatrixDxS<f64, 2> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn dxd_sxd() {
+        let a = MatrixDxD::try_from(A.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let b = MatrixSxD::try_from(B.map(|r| r.to_vec())).unwrap();
+        let c: MatrixDxD<f64> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn dxd_sxs() {
+        let a = MatrixDxD::try_from(A.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let b = MatrixSxS::from(B);
+        let c: MatrixDxS<f64, 2> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    // MatrixDxS
+    // --------------------------------------------------
+    #[test]
+    fn dxs_dxd() {
+        let a = MatrixDxS::from(A.to_vec());
+        let b = MatrixDxD::try_from(B.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let c: MatrixSxD<f64, 2> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn dxs_dxs() {
+        let a = MatrixDxS::from(A.to_vec());
+        let b = MatrixDxS::from(B.to_vec());
+        let c: MatrixSxS<f64, 2, 2> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn dxs_sxd() {
+        let a = MatrixDxS::from(A.to_vec());
+        let b = MatrixSxD::try_from(B.map(|r| r.to_vec())).unwrap();
+        let c: MatrixSxD<f64, 2> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn dxs_sxs() {
+        let a = MatrixDxS::from(A.to_vec());
+        let b = MatrixSxS::from(B);
+        let c: MatrixSxS<f64, 2, 2> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    // MatrixSxD
+    // --------------------------------------------------
+    #[test]
+    fn sxd_dxd() {
+        let a = MatrixSxD::try_from(A.map(|r| r.to_vec())).unwrap();
+        let b = MatrixDxD::try_from(B.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let c: MatrixDxD<f64> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn sxd_dxs() {
+        let a = MatrixSxD::try_from(A.map(|r| r.to_vec())).unwrap();
+        let b = MatrixDxS::from(B.to_vec());
+        let c: MatrixDxS<f64, 2> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn sxd_sxd() {
+        let a = MatrixSxD::try_from(A.map(|r| r.to_vec())).unwrap();
+        let b = MatrixSxD::try_from(B.map(|r| r.to_vec())).unwrap();
+        let c: MatrixDxD<f64> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn sxd_sxs() {
+        let a = MatrixSxD::try_from(A.map(|r| r.to_vec())).unwrap();
+        let b = MatrixSxS::from(B);
+        let c: MatrixDxS<f64, 2> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    // MatrixSxS
+    // --------------------------------------------------
+    #[test]
+    fn sxs_dxd() {
+        let a = MatrixSxS::from(A);
+        let b = MatrixDxD::try_from(B.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let c: MatrixSxD<f64, 2> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn sxs_dxs() {
+        let a = MatrixSxS::from(A);
+        let b = MatrixDxS::from(B.to_vec());
+        let c: MatrixSxS<f64, 2, 2> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn sxs_sxd() {
+        let a = MatrixSxS::from(A);
+        let b = MatrixSxD::try_from(B.map(|r| r.to_vec())).unwrap();
+        let c: MatrixSxD<f64, 2> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn sxs_sxs() {
+        let a = MatrixSxS::from(A);
+        let b = MatrixSxS::from(B);
+        let c: MatrixSxS<f64, 2, 2> = a.lstsq(&b).unwrap();
+        assert_approx_eq(&c.data, &X);
+    }
+    #[test]
+    fn rank_deficient() {
+        let a = MatrixSxS::<f64, 3, 2>::from([[1., 0.], [2., 0.], [3., 0.]]);
+        let b = MatrixSxS::<f64, 3, 1>::from([[1.], [2.], [3.]]);
+        assert_eq!(a.lstsq(&b), Err("Rank deficient matrix"));
+    }
+    #[test]
+    fn rank_deficient_collinear() {
+        let a = MatrixSxS::<f64, 3, 2>::from([[1., 2.], [2., 4.], [3., 6.]]);
+        let b = MatrixSxS::<f64, 3, 1>::from([[1.], [2.], [3.]]);
+        assert_eq!(a.lstsq(&b), Err("Rank deficient matrix"));
+    }
+}
@@ -1,230 +1,126 @@
+use crate::iter::zip_rows_apply;
 use crate::*;
 use std::ops::RemAssign;
 
 // T
 // --------------------------------------------------
-impl<T: RemAssign + Copy> RemAssign<T> for MatrixDxD<T> {
-    fn rem_assign(&mut self, x: T) {
-        for a in self.data.iter_mut() {
-            *a %= x;
-        }
-    }
-}
-impl<T: RemAssign + Copy, const COLUMNS: usize> RemAssign<T> for MatrixDxS<T, COLUMNS> {
-    fn rem_assign(&mut self, x: T) {
-        for a in self.data.iter_mut() {
-            *a %= x;
-        }
-    }
-}
-impl<T: RemAssign + Copy, const ROWS: usize> RemAssign<T> for MatrixSxD<T, ROWS> {
-    fn rem_assign(&mut self, x: T) {
-        for a in self.data.iter_mut() {
-            *a %= x;
-        }
-    }
-}
-impl<T: RemAssign + Copy, const ROWS: usize, const COLUMNS: usize> RemAssign<T>
-    for MatrixSxS<T, ROWS, COLUMNS>
+impl<T: RemAssign + Copy, R: Dim, C: Dim, L: Layout> RemAssign<T> for Matrix<T, R, C, L>
 where
-    [(); ROWS * COLUMNS]:,
+    (R, C, L): Storage<T>,
 {
     fn rem_assign(&mut self, x: T) {
-        for a in self.data.iter_mut() {
+        for a in self.data.as_mut().iter_mut() {
             *a %= x;
         }
     }
 }
-// MatrixDxD
+// Matrix
 // --------------------------------------------------
-impl<T: RemAssign + Copy> RemAssign<MatrixDxD<T>> for MatrixDxD<T> {
-    fn rem_assign(&mut self, other: Self) {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
-        }
-    }
-}
-impl<T: RemAssign + Copy, const COLUMNS: usize> RemAssign<MatrixDxS<T, COLUMNS>> for MatrixDxD<T> {
-    fn rem_assign(&mut self, other: MatrixDxS<T, COLUMNS>) {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
-        }
-    }
-}
-impl<T: RemAssign + Copy, const ROWS: usize> RemAssign<MatrixSxD<T, ROWS>> for MatrixDxD<T> {
-    fn rem_assign(&mut self, other: MatrixSxD<T, ROWS>) {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
-        }
-    }
-}
-impl<T: RemAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    RemAssign<MatrixSxS<T, ROWS, COLUMNS>> for MatrixDxD<T>
+impl<T: RemAssign + Copy, R: Dim, C: Dim, L: Layout, R2: Dim, C2: Dim, L2: Layout>
+    RemAssign<Matrix<T, R2, C2, L2>> for Matrix<T, R, C, L>
 where
-    [(); ROWS * COLUMNS]:,
+    R: Join<R2>,
+    C: Join<C2>,
+    (R, C, L): Storage<T>,
+    (R2, C2, L2): Storage<T>,
 {
-    fn rem_assign(&mut self, other: MatrixSxS<T, ROWS, COLUMNS>) {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
-        }
+    fn rem_assign(&mut self, other: Matrix<T, R2, C2, L2>) {
+        self.zip_apply(&other, |a, b| *a %= *b);
     }
 }
-// MatrixDxS
+// ViewDxDMut
 // --------------------------------------------------
-impl<T: RemAssign + Copy, const COLUMNS: usize> RemAssign<MatrixDxS<T, COLUMNS>>
-    for MatrixDxS<T, COLUMNS>
-{
-    fn rem_assign(&mut self, other: Self) {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
-        }
-    }
-}
-impl<T: RemAssign + Copy, const ROWS: usize, const COLUMNS: usize> RemAssign<MatrixSxD<T, ROWS>>
-    for MatrixDxS<T, COLUMNS>
-{
-    fn rem_assign(&mut self, other: MatrixSxD<T, ROWS>) {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
-        }
-    }
-}
-impl<T: RemAssign + Copy, const COLUMNS: usize> RemAssign<MatrixDxD<T>> for MatrixDxS<T, COLUMNS> {
-    fn rem_assign(&mut self, other: MatrixDxD<T>) {
-        assert_eq!(self.rows, other.rows, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
+impl<'a, T: RemAssign + Copy> RemAssign<T> for ViewDxDMut<'a, T> {
+    fn rem_assign(&mut self, x: T) {
+        for a in self.iter_mut() {
+            *a %= x;
         }
     }
 }
-impl<T: RemAssign + Copy + Default, const ROWS: usize, const COLUMNS: usize>
-    RemAssign<MatrixSxS<T, ROWS, COLUMNS>> for MatrixDxS<T, COLUMNS>
+impl<'a, T: RemAssign + Copy, R2: Dim, C2: Dim, L2: Layout> RemAssign<Matrix<T, R2, C2, L2>>
+    for ViewDxDMut<'a, T>
 where
-    [(); ROWS * COLUMNS]:,
+    Dynamic: Join<R2>,
+    Dynamic: Join<C2>,
+    (R2, C2, L2): Storage<T>,
 {
-    fn rem_assign(&mut self, other: MatrixSxS<T, ROWS, COLUMNS>) {
-        assert_eq!(self.rows, ROWS, "Non-matching rows");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
-        }
+    fn rem_assign(&mut self, other: Matrix<T, R2, C2, L2>) {
+        let shape = (self.rows(), self.columns());
+        zip_rows_apply(self.iter_mut(), shape, &other, |a, b| *a %= *b);
     }
 }
-// MatrixSxD
+// ViewDxSMut
 // --------------------------------------------------
-impl<T: RemAssign + Copy, const ROWS: usize> RemAssign<MatrixSxD<T, ROWS>> for MatrixSxD<T, ROWS> {
-    fn rem_assign(&mut self, other: Self) {
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
-        }
-    }
-}
-impl<T: RemAssign + Copy, const ROWS: usize, const COLUMNS: usize> RemAssign<MatrixDxS<T, COLUMNS>>
-    for MatrixSxD<T, ROWS>
-{
-    fn rem_assign(&mut self, other: MatrixDxS<T, COLUMNS>) {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
-        }
-    }
-}
-impl<T: RemAssign + Copy, const ROWS: usize> RemAssign<MatrixDxD<T>> for MatrixSxD<T, ROWS> {
-    fn rem_assign(&mut self, other: MatrixDxD<T>) {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(self.columns, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
+impl<'a, T: RemAssign + Copy, const COLUMNS: usize> RemAssign<T> for ViewDxSMut<'a, T, COLUMNS> {
+    fn rem_assign(&mut self, x: T) {
+        for a in self.iter_mut() {
+            *a %= x;
         }
     }
 }
-impl<T: RemAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    RemAssign<MatrixSxS<T, ROWS, COLUMNS>> for MatrixSxD<T, ROWS>
+impl<'a, T: RemAssign + Copy, const COLUMNS: usize, R2: Dim, C2: Dim, L2: Layout>
+    RemAssign<Matrix<T, R2, C2, L2>> for ViewDxSMut<'a, T, COLUMNS>
 where
-    [(); ROWS * COLUMNS]:,
+    Dynamic: Join<R2>,
+    Static<COLUMNS>: Join<C2>,
+    (R2, C2, L2): Storage<T>,
 {
-    fn rem_assign(&mut self, other: MatrixSxS<T, ROWS, COLUMNS>) {
-        assert_eq!(self.columns, COLUMNS, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
-        }
+    fn rem_assign(&mut self, other: Matrix<T, R2, C2, L2>) {
+        let shape = (self.rows(), self.columns());
+        zip_rows_apply(self.iter_mut(), shape, &other, |a, b| *a %= *b);
     }
 }
-// MatrixSxS
+// ViewSxDMut
 // --------------------------------------------------
-impl<T: RemAssign + Copy, const ROWS: usize, const COLUMNS: usize>
-    RemAssign<MatrixSxS<T, ROWS, COLUMNS>> for MatrixSxS<T, ROWS, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
-{
-    fn rem_assign(&mut self, other: Self) {
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
+impl<'a, T: RemAssign + Copy, const ROWS: usize> RemAssign<T> for ViewSxDMut<'a, T, ROWS> {
+    fn rem_assign(&mut self, x: T) {
+        for a in self.iter_mut() {
+            *a %= x;
         }
     }
 }
-impl<T: RemAssign + Copy, const ROWS: usize, const COLUMNS: usize> RemAssign<MatrixDxS<T, COLUMNS>>
-    for MatrixSxS<T, ROWS, COLUMNS>
+impl<'a, T: RemAssign + Copy, const ROWS: usize, R2: Dim, C2: Dim, L2: Layout>
+    RemAssign<Matrix<T, R2, C2, L2>> for ViewSxDMut<'a, T, ROWS>
 where
-    [(); ROWS * COLUMNS]:,
+    Static<ROWS>: Join<R2>,
+    Dynamic: Join<C2>,
+    (R2, C2, L2): Storage<T>,
 {
-    fn rem_assign(&mut self, other: MatrixDxS<T, COLUMNS>) {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
-        }
+    fn rem_assign(&mut self, other: Matrix<T, R2, C2, L2>) {
+        let shape = (self.rows(), self.columns());
+        zip_rows_apply(self.iter_mut(), shape, &other, |a, b| *a %= *b);
     }
 }
-impl<T: RemAssign + Copy, const ROWS: usize, const COLUMNS: usize> RemAssign<MatrixSxD<T, ROWS>>
-    for MatrixSxS<T, ROWS, COLUMNS>
-where
-    [(); ROWS * COLUMNS]:,
+// ViewSxSMut
+// --------------------------------------------------
+impl<'a, T: RemAssign + Copy, const ROWS: usize, const COLUMNS: usize> RemAssign<T>
+    for ViewSxSMut<'a, T, ROWS, COLUMNS>
 {
-    fn rem_assign(&mut self, other: MatrixSxD<T, ROWS>) {
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
+    fn rem_assign(&mut self, x: T) {
+        for a in self.iter_mut() {
+            *a %= x;
         }
     }
 }
-impl<T: RemAssign + Copy, const ROWS: usize, const COLUMNS: usize> RemAssign<MatrixDxD<T>>
-    for MatrixSxS<T, ROWS, COLUMNS>
+impl<
+        'a,
+        T: RemAssign + Copy,
+        const ROWS: usize,
+        const COLUMNS: usize,
+        R2: Dim,
+        C2: Dim,
+        L2: Layout,
+    > RemAssign<Matrix<T, R2, C2, L2>> for ViewSxSMut<'a, T, ROWS, COLUMNS>
 where
-    [(); ROWS * COLUMNS]:,
+    Static<ROWS>: Join<R2>,
+    Static<COLUMNS>: Join<C2>,
+    (R2, C2, L2): Storage<T>,
 {
-    fn rem_assign(&mut self, other: MatrixDxD<T>) {
-        assert_eq!(ROWS, other.rows, "Non-matching rows");
-        assert_eq!(COLUMNS, other.columns, "Non-matching columns");
-
-        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
-            *a %= *b;
-        }
+    fn rem_assign(&mut self, other: Matrix<T, R2, C2, L2>) {
+        let shape = (self.rows(), self.columns());
+        zip_rows_apply(self.iter_mut(), shape, &other, |a, b| *a %= *b);
     }
 }
-
 // Tests
 // --------------------------------------------------
 #[cfg(test)]
@@ -399,4 +295,202 @@ mod tests {
         let d = MatrixSxS::from([[4, 5, 3], [4, 5, 6]]);
         assert_eq!(a, d);
     }
+    // ViewDxDMut
+    // --------------------------------------------------
+    #[test]
+    fn t_view_dxd() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let mut c = a.slice_dxd_mut((0..2, 0..3));
+        c %= 3;
+        let d = MatrixSxS::from([[2, 0, 0, 0], [1, 2, 0, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxd_dxd() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixDxD::try_from(vec![vec![7, 8, 9], vec![10, 11, 12]]).unwrap();
+        let mut c = a.slice_dxd_mut((0..2, 0..3));
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxd_dxs() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixDxS::from(vec![[7, 8, 9], [10, 11, 12]]);
+        let mut c = a.slice_dxd_mut((0..2, 0..3));
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxd_sxd() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixSxD::try_from([vec![7, 8, 9], vec![10, 11, 12]]).unwrap();
+        let mut c = a.slice_dxd_mut((0..2, 0..3));
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxd_sxs() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixSxS::from([[7, 8, 9], [10, 11, 12]]);
+        let mut c = a.slice_dxd_mut((0..2, 0..3));
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    // ViewDxSMut
+    // --------------------------------------------------
+    #[test]
+    fn t_view_dxs() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let mut c = a.slice_dxs_mut::<{ 0..3 }>(0..2);
+        c %= 3;
+        let d = MatrixSxS::from([[2, 0, 0, 0], [1, 2, 0, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxs_dxd() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixDxD::try_from(vec![vec![7, 8, 9], vec![10, 11, 12]]).unwrap();
+        let mut c = a.slice_dxs_mut::<{ 0..3 }>(0..2);
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxs_dxs() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixDxS::from(vec![[7, 8, 9], [10, 11, 12]]);
+        let mut c = a.slice_dxs_mut::<{ 0..3 }>(0..2);
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxs_sxd() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixSxD::try_from([vec![7, 8, 9], vec![10, 11, 12]]).unwrap();
+        let mut c = a.slice_dxs_mut::<{ 0..3 }>(0..2);
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_dxs_sxs() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixSxS::from([[7, 8, 9], [10, 11, 12]]);
+        let mut c = a.slice_dxs_mut::<{ 0..3 }>(0..2);
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    // ViewSxDMut
+    // --------------------------------------------------
+    #[test]
+    fn t_view_sxd() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let mut c = a.slice_sxd_mut::<{ 0..2 }>(0..3);
+        c %= 3;
+        let d = MatrixSxS::from([[2, 0, 0, 0], [1, 2, 0, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxd_dxd() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixDxD::try_from(vec![vec![7, 8, 9], vec![10, 11, 12]]).unwrap();
+        let mut c = a.slice_sxd_mut::<{ 0..2 }>(0..3);
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxd_dxs() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixDxS::from(vec![[7, 8, 9], [10, 11, 12]]);
+        let mut c = a.slice_sxd_mut::<{ 0..2 }>(0..3);
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxd_sxd() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixSxD::try_from([vec![7, 8, 9], vec![10, 11, 12]]).unwrap();
+        let mut c = a.slice_sxd_mut::<{ 0..2 }>(0..3);
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxd_sxs() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixSxS::from([[7, 8, 9], [10, 11, 12]]);
+        let mut c = a.slice_sxd_mut::<{ 0..2 }>(0..3);
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    // ViewSxSMut
+    // --------------------------------------------------
+    #[test]
+    fn t_view_sxs() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let mut c = a.slice_sxs_mut::<{ 0..2 }, { 0..3 }>();
+        c %= 3;
+        let d = MatrixSxS::from([[2, 0, 0, 0], [1, 2, 0, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxs_dxd() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixDxD::try_from(vec![vec![7, 8, 9], vec![10, 11, 12]]).unwrap();
+        let mut c = a.slice_sxs_mut::<{ 0..2 }, { 0..3 }>();
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxs_dxs() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixDxS::from(vec![[7, 8, 9], [10, 11, 12]]);
+        let mut c = a.slice_sxs_mut::<{ 0..2 }, { 0..3 }>();
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxs_sxd() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixSxD::try_from([vec![7, 8, 9], vec![10, 11, 12]]).unwrap();
+        let mut c = a.slice_sxs_mut::<{ 0..2 }, { 0..3 }>();
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    #[test]
+    fn view_sxs_sxs() {
+        let mut a = MatrixSxS::from([[11, 45, 39, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        let b = MatrixSxS::from([[7, 8, 9], [10, 11, 12]]);
+        let mut c = a.slice_sxs_mut::<{ 0..2 }, { 0..3 }>();
+        c %= b;
+        let d = MatrixSxS::from([[4, 5, 3, 0], [4, 5, 6, 0], [0, 0, 0, 0]]);
+        assert_eq!(a, d);
+    }
+    // ColumnMajor
+    // --------------------------------------------------
+    #[test]
+    fn column_major() {
+        let mut a = MatrixSxS::from([[1, 2, 3], [4, 5, 6]]);
+        a %= MatrixDxS::from(vec![[7, 7], [7, 7], [7, 7]]).transpose();
+        assert_eq!(a, MatrixSxS::from([[1, 2, 3], [4, 5, 6]]));
+        let mut b = MatrixSxD::try_from([vec![1, 2, 3], vec![4, 5, 6]])
+            .unwrap()
+            .to_layout::<ColumnMajor>();
+        b %= 3;
+        b %= a;
+        assert_eq!(b, MatrixSxS::from([[0, 0, 0], [1, 2, 0]]));
+    }
 }
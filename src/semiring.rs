@@ -0,0 +1,397 @@
+use crate::matmul::{blocked, Ops, Strided};
+use crate::*;
+use num_traits::{Bounded, Num, Zero};
+use std::marker::PhantomData;
+
+/// The addition and multiplication a matrix product is taken over, along with their identities.
+///
+/// `zero` must be the identity of `add` and give itself when multiplied by anything.
+pub trait Semiring<T> {
+    /// The identity of [`Semiring::add`].
+    fn zero() -> T;
+    /// The identity of [`Semiring::mul`].
+    fn one() -> T;
+    fn add(x: T, y: T) -> T;
+    fn mul(x: T, y: T) -> T;
+}
+/// Ordinary arithmetic, giving the same product as [`Matmul`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Arithmetic;
+impl<T: Num + Copy> Semiring<T> for Arithmetic {
+    fn zero() -> T {
+        T::zero()
+    }
+    fn one() -> T {
+        T::one()
+    }
+    fn add(x: T, y: T) -> T {
+        x + y
+    }
+    fn mul(x: T, y: T) -> T {
+        x * y
+    }
+}
+/// The infinities of [`MinPlus`] and [`MaxPlus`], which stand for no path.
+///
+/// These are the infinities of floats and the greatest and least values of integers, which sums
+///  saturate to rather than overflowing.
+pub trait Infinity: Sized {
+    fn infinity() -> Self;
+    fn neg_infinity() -> Self;
+    /// `self + other`, saturating to the infinity of the sign of an overflow.
+    fn saturating_add(self, other: Self) -> Self;
+}
+impl<T: Bounded + Num + PartialOrd + Copy> Infinity for T {
+    default fn infinity() -> T {
+        T::max_value()
+    }
+    default fn neg_infinity() -> T {
+        T::min_value()
+    }
+    default fn saturating_add(self, other: T) -> T {
+        if other > T::zero() && self > T::max_value() - other {
+            T::max_value()
+        } else if other < T::zero() && self < T::min_value() - other {
+            T::min_value()
+        } else {
+            self + other
+        }
+    }
+}
+impl Infinity for f32 {
+    fn infinity() -> f32 {
+        f32::INFINITY
+    }
+    fn neg_infinity() -> f32 {
+        f32::NEG_INFINITY
+    }
+    fn saturating_add(self, other: f32) -> f32 {
+        self + other
+    }
+}
+impl Infinity for f64 {
+    fn infinity() -> f64 {
+        f64::INFINITY
+    }
+    fn neg_infinity() -> f64 {
+        f64::NEG_INFINITY
+    }
+    fn saturating_add(self, other: f64) -> f64 {
+        self + other
+    }
+}
+/// The tropical semiring of shortest paths, adding by `min` and multiplying by `+`.
+///
+/// [`Infinity::infinity`] stands for no path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinPlus;
+impl<T: Infinity + Zero + PartialOrd + Copy> Semiring<T> for MinPlus {
+    fn zero() -> T {
+        T::infinity()
+    }
+    fn one() -> T {
+        T::zero()
+    }
+    fn add(x: T, y: T) -> T {
+        if y < x {
+            y
+        } else {
+            x
+        }
+    }
+    fn mul(x: T, y: T) -> T {
+        let infinity = T::infinity();
+        if x == infinity || y == infinity {
+            infinity
+        } else {
+            x.saturating_add(y)
+        }
+    }
+}
+/// The tropical semiring of longest paths, adding by `max` and multiplying by `+`.
+///
+/// [`Infinity::neg_infinity`] stands for no path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxPlus;
+impl<T: Infinity + Zero + PartialOrd + Copy> Semiring<T> for MaxPlus {
+    fn zero() -> T {
+        T::neg_infinity()
+    }
+    fn one() -> T {
+        T::zero()
+    }
+    fn add(x: T, y: T) -> T {
+        if y > x {
+            y
+        } else {
+            x
+        }
+    }
+    fn mul(x: T, y: T) -> T {
+        let infinity = T::neg_infinity();
+        if x == infinity || y == infinity {
+            infinity
+        } else {
+            x.saturating_add(y)
+        }
+    }
+}
+/// The boolean semiring of reachability, adding by `||` and multiplying by `&&`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrAnd;
+impl Semiring<bool> for OrAnd {
+    fn zero() -> bool {
+        false
+    }
+    fn one() -> bool {
+        true
+    }
+    fn add(x: bool, y: bool) -> bool {
+        x || y
+    }
+    fn mul(x: bool, y: bool) -> bool {
+        x && y
+    }
+}
+
+/// The operations of the semiring `S` for the blocked kernel.
+pub(crate) struct Over<S>(PhantomData<S>);
+impl<T, S: Semiring<T>> Ops<T> for Over<S> {
+    fn zero() -> T {
+        S::zero()
+    }
+    fn add(x: T, y: T) -> T {
+        S::add(x, y)
+    }
+    fn mul(x: T, y: T) -> T {
+        S::mul(x, y)
+    }
+}
+
+/// A trait for matrix multiplication over a [`Semiring`].
+///
+/// Shapes are checked as with [`Matmul`], and any of matrices, views and transposes can be
+///  multiplied. E.g. all pairs shortest paths by repeatedly squaring a matrix of edge weights:
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// use static_la::*;
+/// const X: u32 = u32::MAX;
+/// // 0 → 1 → 2 → 3 with weights 1, 2 and 3, and 0 → 3 with weight 9.
+/// let mut a = MatrixSxS::<u32, 4, 4>::from([[0, 1, X, 9], [X, 0, 2, X], [X, X, 0, 3], [X, X, X, 0]]);
+/// for _ in 0..2 {
+///     a = a.matmul_over(&a, MinPlus);
+/// }
+/// assert_eq!(a, MatrixSxS::from([[0, 1, 3, 6], [X, 0, 2, 5], [X, X, 0, 3], [X, X, X, 0]]));
+/// ```
+pub trait SemiringMatmul<T>: blas::Operand<T> {
+    /// ```text
+    /// ┌───────┐             ┌─────┐           ┌─────┐
+    /// │ 0 1 ∞ │             │ 0 2 │           │ 0 1 │
+    /// │ 2 0 1 │.matmul_over(│ 1 0 │, MinPlus)=│ 1 0 │
+    /// └───────┘             │ ∞ 0 │           └─────┘
+    ///                       └─────┘
+    /// ```
+    fn matmul_over<B: blas::Operand<T>, S: Semiring<T>>(
+        &self,
+        other: &B,
+        semiring: S,
+    ) -> Matrix<T, Self::Rows, B::Columns>
+    where
+        Self::Columns: Join<B::Rows>,
+        (Self::Rows, B::Columns, RowMajor): Storage<T>;
+}
+impl<T: Copy, A: blas::Operand<T>> SemiringMatmul<T> for A {
+    fn matmul_over<B: blas::Operand<T>, S: Semiring<T>>(
+        &self,
+        other: &B,
+        _: S,
+    ) -> Matrix<T, A::Rows, B::Columns>
+    where
+        A::Columns: Join<B::Rows>,
+        (A::Rows, B::Columns, RowMajor): Storage<T>,
+    {
+        use blas::Matrix as _;
+        let (x, y) = (self.stored(), other.stored());
+        let shape = |t: bool, rows: usize, columns: usize| {
+            if t {
+                (columns, rows)
+            } else {
+                (rows, columns)
+            }
+        };
+        let (m, k) = shape(A::TRANSPOSE, x.rows(), x.columns());
+        let (rows, n) = shape(B::TRANSPOSE, y.rows(), y.columns());
+        assert_eq!(k, rows, "Non-matching columns to rows");
+
+        // Whether the row-major reading of the data of each operand is its transpose.
+        let a = Strided::new(
+            x.data(),
+            x.leading_dimension(),
+            A::TRANSPOSE ^ x.column_major(),
+        );
+        let b = Strided::new(
+            y.data(),
+            y.leading_dimension(),
+            B::TRANSPOSE ^ y.column_major(),
+        );
+        let mut data =
+            <(A::Rows, B::Columns, RowMajor)>::collect(std::iter::repeat(S::zero()).take(m * n));
+        blocked::<T, Over<S>>(a, b, data.as_mut(), n, m, n, k, |x| x);
+        Matrix {
+            data,
+            rows: <A::Rows as Dim>::value(m),
+            columns: <B::Columns as Dim>::value(n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::convert::TryFrom;
+    const X: u32 = u32::MAX;
+    /// `a·b` over `S` by the definition.
+    fn naive<T: Copy, S: Semiring<T>>(a: &[T], b: &[T], m: usize, n: usize, k: usize) -> Vec<T> {
+        let mut c = vec![S::zero(); m * n];
+        for i in 0..m {
+            for j in 0..n {
+                for l in 0..k {
+                    c[i * n + j] = S::add(c[i * n + j], S::mul(a[i * k + l], b[l * n + j]));
+                }
+            }
+        }
+        c
+    }
+    // Semirings
+    // --------------------------------------------------
+    #[test]
+    fn arithmetic() {
+        let a = MatrixDxD::<i32>::try_from(vec![vec![1, 3, 5], vec![2, 4, 6]]).unwrap();
+        let b = MatrixSxS::<i32, 3, 2>::from([[7, 10], [8, 11], [9, 12]]);
+        let c: MatrixDxS<i32, 2> = a.matmul_over(&b, Arithmetic);
+        assert_eq!(c, a.matmul(&b));
+    }
+    #[test]
+    fn min_plus() {
+        let a = MatrixSxS::<u32, 2, 3>::from([[0, 1, X], [2, 0, 1]]);
+        let b = MatrixSxS::<u32, 3, 2>::from([[0, 2], [1, 0], [X, 0]]);
+        assert_eq!(
+            a.matmul_over(&b, MinPlus),
+            MatrixSxS::from([[0, 1], [1, 0]])
+        );
+        let a = MatrixSxS::<f64, 2, 2>::from([[0., 1.5], [f64::INFINITY, 0.]]);
+        assert_eq!(
+            a.matmul_over(&a, MinPlus),
+            MatrixSxS::from([[0., 1.5], [f64::INFINITY, 0.]])
+        );
+    }
+    #[test]
+    fn max_plus() {
+        const Y: i32 = i32::MIN;
+        let a = MatrixSxS::<i32, 3, 3>::from([[0, 1, 4], [Y, 0, 2], [Y, Y, 0]]);
+        let c = a.matmul_over(&a, MaxPlus);
+        assert_eq!(c, MatrixSxS::from([[0, 1, 4], [Y, 0, 2], [Y, Y, 0]]));
+        let a = MatrixSxS::<i32, 3, 3>::from([[Y, 1, 1], [Y, Y, 2], [Y, Y, Y]]);
+        let c = a.matmul_over(&a, MaxPlus);
+        assert_eq!(c, MatrixSxS::from([[Y, Y, 3], [Y, Y, Y], [Y, Y, Y]]));
+    }
+    #[test]
+    fn no_path() {
+        const Z: f32 = f32::INFINITY;
+        // 2 is unreachable from 0 and 1.
+        let a = MatrixSxS::<f32, 3, 3>::from([[0., 1., Z], [Z, 0., Z], [Z, Z, 0.]]);
+        assert_eq!(a.matmul_over(&a, MinPlus), a);
+        let a = MatrixDxD::try_from(vec![vec![0., -Z], vec![-Z, 0.]]).unwrap();
+        assert_eq!(a.matmul_over(&a, MaxPlus), a);
+    }
+    #[test]
+    fn saturating() {
+        let a = MatrixSxS::<u32, 1, 2>::from([[X - 1, 7]]);
+        let b = MatrixSxS::<u32, 2, 1>::from([[2], [1]]);
+        assert_eq!(a.matmul_over(&b, MinPlus), MatrixSxS::from([[8]]));
+        let a = MatrixSxS::<u32, 1, 1>::from([[X - 1]]);
+        assert_eq!(a.matmul_over(&a, MinPlus), MatrixSxS::from([[X]]));
+        const Y: i32 = i32::MIN;
+        let a = MatrixSxS::<i32, 1, 2>::from([[Y + 1, 5]]);
+        let b = MatrixSxS::<i32, 2, 1>::from([[-2], [1]]);
+        assert_eq!(a.matmul_over(&b, MaxPlus), MatrixSxS::from([[6]]));
+        let a = MatrixSxS::<i32, 1, 1>::from([[i32::MAX]]);
+        assert_eq!(a.matmul_over(&a, MaxPlus), MatrixSxS::from([[i32::MAX]]));
+    }
+    #[test]
+    fn or_and() {
+        let a = MatrixSxS::from([
+            [false, true, false],
+            [false, false, true],
+            [false, false, false],
+        ]);
+        let c = a.matmul_over(&a, OrAnd);
+        assert_eq!(
+            c,
+            MatrixSxS::from([
+                [false, false, true],
+                [false, false, false],
+                [false, false, false]
+            ])
+        );
+    }
+    // Operands
+    // --------------------------------------------------
+    #[test]
+    fn shortest_paths() {
+        // A cycle 0 → 1 → … → 5 → 0 with weight `i + 1` from `i`.
+        let mut a = MatrixSxS::<u32, 6, 6>::from(X);
+        for i in 0..6 {
+            a[(i, i)] = 0;
+            a[(i, (i + 1) % 6)] = i as u32 + 1;
+        }
+        for _ in 0..3 {
+            a = a.matmul_over(&a, MinPlus);
+        }
+        for i in 0..6 {
+            for j in 0..6 {
+                let d = (i..i + (j + 6 - i) % 6)
+                    .map(|x| x as u32 % 6 + 1)
+                    .sum::<u32>();
+                assert_eq!(a[(i, j)], d);
+            }
+        }
+    }
+    #[test]
+    fn transposes() {
+        let a = MatrixSxS::<u32, 3, 2>::from([[0, 2], [1, 0], [X, 0]]);
+        let b = MatrixDxD::try_from(vec![vec![0, 1, X], vec![2, 0, 1]]).unwrap();
+        let c: MatrixSxD<u32, 2> = a.transpose_ref().matmul_over(&b.transpose_ref(), MinPlus);
+        assert_eq!(c, MatrixSxS::from([[0, 1], [1, 0]]));
+        let c = a.to_layout::<ColumnMajor>().matmul_over(&b, MinPlus);
+        assert_eq!(c, MatrixSxS::from([[0, 1, 3], [1, 0, 1], [2, 0, 1]]));
+    }
+    #[test]
+    fn blocked() {
+        // Large enough to cross the blocks of the kernel, each padded with `zero`, where every
+        //  fifth weight is `none`.
+        let (m, n, k) = (70, 9, 260);
+        let weights = |rows: usize, columns: usize, none: i64| {
+            let data = (0..rows * columns).map(|i| match i % 5 {
+                0 => none,
+                _ => (i * 7 % 19) as i64 - 9,
+            });
+            MatrixDxD::try_from((rows, columns, data.collect::<Vec<_>>())).unwrap()
+        };
+        let (a, b) = (weights(m, k, i64::MAX), weights(k, n, i64::MAX));
+        let c = a.matmul_over(&b, MinPlus);
+        let d = naive::<_, MinPlus>(a.as_slice(), b.as_slice(), m, n, k);
+        assert_eq!(c.as_slice(), &d[..]);
+        let (a, b) = (weights(m, k, i64::MIN), weights(k, n, i64::MIN));
+        let c = a.matmul_over(&b, MaxPlus);
+        let d = naive::<_, MaxPlus>(a.as_slice(), b.as_slice(), m, n, k);
+        assert_eq!(c.as_slice(), &d[..]);
+    }
+    #[test]
+    #[should_panic(expected = "Non-matching columns to rows")]
+    fn columns_to_rows() {
+        let a = MatrixDxD::<u32>::from((2, 3, 0));
+        a.matmul_over(&MatrixDxD::from((2, 3, 0)), MinPlus);
+    }
+}
@@ -1,8 +1,5 @@
 use crate::*;
-use std::{
-    convert::{TryFrom, TryInto},
-    ops::Range,
-};
+use std::{borrow::Cow, ops::Range};
 
 /// Internal function used for const slice sizing.
 pub const fn range_len(a: Range<usize>) -> usize {
@@ -10,366 +7,1055 @@ pub const fn range_len(a: Range<usize>) -> usize {
 }
 type Slice = (Range<usize>, Range<usize>);
 
+/// Compile-time bounds check of a static range against a static dimension.
+///
+/// Referencing [`InBounds::ASSERT`] fails compilation when `RANGE` does not lie within `0..LEN`.
+struct InBounds<const RANGE: Range<usize>, const LEN: usize>;
+impl<const RANGE: Range<usize>, const LEN: usize> InBounds<RANGE, LEN> {
+    const ASSERT: () = assert!(
+        RANGE.start <= RANGE.end && RANGE.end <= LEN,
+        "Slice out of bounds"
+    );
+}
+
+/// Number of underlying elements spanned by a strided view, from its first element to its last.
+const fn span(rows: usize, columns: usize, row_stride: usize, column_stride: usize) -> usize {
+    if rows == 0 || columns == 0 {
+        0
+    } else {
+        (rows - 1) * row_stride + (columns - 1) * column_stride + 1
+    }
+}
+/// The underlying data of a region of a row-major matrix with `stride` columns, starting from
+///  the first element of the region.
+fn region<'a, T>(
+    data: &'a [T],
+    stride: usize,
+    rows: &Range<usize>,
+    columns: &Range<usize>,
+) -> &'a [T] {
+    let start = rows.start * stride + columns.start;
+    &data[start..start + span(rows.len(), columns.len(), stride, 1)]
+}
+/// The mutable underlying data of a region of a row-major matrix with `stride` columns, starting
+///  from the first element of the region.
+fn region_mut<'a, T>(
+    data: &'a mut [T],
+    stride: usize,
+    rows: &Range<usize>,
+    columns: &Range<usize>,
+) -> &'a mut [T] {
+    let start = rows.start * stride + columns.start;
+    &mut data[start..start + span(rows.len(), columns.len(), stride, 1)]
+}
+
 /// A trait for dynamic slicing along rows and dynamic slicing along columns.
 pub trait SliceDxD<T> {
-    /// Given a range of rows and a range of columns returns a [`MatrixDxD`] holding
-    ///  references to the values within the ranges.
-    fn slice_dxd(&self, slice: Slice) -> MatrixDxD<&T>;
+    /// Given a range of rows and a range of columns returns a [`ViewDxD`] over the values within
+    ///  the ranges.
+    fn slice_dxd(&self, slice: Slice) -> ViewDxD<T>;
 }
 /// A trait for dynamic slicing along rows and static slicing along columns.
 pub trait SliceDxS<T> {
-    /// Given a range of rows and a constant range of columns returns a [`MatrixDxS`] holding
-    ///  references to the values within the ranges.
+    /// Given a range of rows and a constant range of columns returns a [`ViewDxS`] over the
+    ///  values within the ranges.
     fn slice_dxs<const COLUMNS: Range<usize>>(
         &self,
         rows: Range<usize>,
-    ) -> MatrixDxS<&T, { range_len(COLUMNS) }>;
+    ) -> ViewDxS<T, { range_len(COLUMNS) }>;
 }
 /// A trait for static slicing along rows and dynamic slicing along columns.
 pub trait SliceSxD<T> {
-    /// Given a constant range of rows and a range of columns returns a [`MatrixSxD`] holding
-    ///  references to the values within the ranges.
+    /// Given a constant range of rows and a range of columns returns a [`ViewSxD`] over the
+    ///  values within the ranges.
     fn slice_sxd<const ROWS: Range<usize>>(
         &self,
         columns: Range<usize>,
-    ) -> MatrixSxD<&T, { range_len(ROWS) }>;
+    ) -> ViewSxD<T, { range_len(ROWS) }>;
 }
 /// A trait for static slicing along rows and static slicing along columns.
+///
+/// Slicing a [`MatrixSxS`] checks both ranges against its dimensions at compile time:
+/// ```compile_fail
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// use static_la::*;
+/// let a = MatrixSxS::from([[1, 2, 3], [4, 5, 6]]);
+/// let b = a.slice_sxs::<{ 0..3 }, { 0..3 }>();
+/// ```
 pub trait SliceSxS<T> {
-    /// Given a constant range of rows and a constant range of columns returns a [`MatrixSxS`]
-    ///  holding references to the values within the ranges.
+    /// Given a constant range of rows and a constant range of columns returns a [`ViewSxS`]
+    ///  over the values within the ranges.
     fn slice_sxs<const ROWS: Range<usize>, const COLUMNS: Range<usize>>(
         &self,
-    ) -> MatrixSxS<&T, { range_len(ROWS) }, { range_len(COLUMNS) }>
+    ) -> ViewSxS<T, { range_len(ROWS) }, { range_len(COLUMNS) }>
+    where
+        [(); range_len(ROWS) * range_len(COLUMNS)]:;
+}
+
+/// A trait for mutable dynamic slicing along rows and dynamic slicing along columns.
+pub trait SliceDxDMut<T> {
+    /// Given a range of rows and a range of columns returns a [`ViewDxDMut`] over the values
+    ///  within the ranges.
+    fn slice_dxd_mut(&mut self, slice: Slice) -> ViewDxDMut<T>;
+}
+/// A trait for mutable dynamic slicing along rows and static slicing along columns.
+pub trait SliceDxSMut<T> {
+    /// Given a range of rows and a constant range of columns returns a [`ViewDxSMut`] over the
+    ///  values within the ranges.
+    fn slice_dxs_mut<const COLUMNS: Range<usize>>(
+        &mut self,
+        rows: Range<usize>,
+    ) -> ViewDxSMut<T, { range_len(COLUMNS) }>;
+}
+/// A trait for mutable static slicing along rows and dynamic slicing along columns.
+pub trait SliceSxDMut<T> {
+    /// Given a constant range of rows and a range of columns returns a [`ViewSxDMut`] over the
+    ///  values within the ranges.
+    fn slice_sxd_mut<const ROWS: Range<usize>>(
+        &mut self,
+        columns: Range<usize>,
+    ) -> ViewSxDMut<T, { range_len(ROWS) }>;
+}
+/// A trait for mutable static slicing along rows and static slicing along columns.
+pub trait SliceSxSMut<T> {
+    /// Given a constant range of rows and a constant range of columns returns a [`ViewSxSMut`]
+    ///  over the values within the ranges.
+    fn slice_sxs_mut<const ROWS: Range<usize>, const COLUMNS: Range<usize>>(
+        &mut self,
+    ) -> ViewSxSMut<T, { range_len(ROWS) }, { range_len(COLUMNS) }>
     where
         [(); range_len(ROWS) * range_len(COLUMNS)]:;
 }
 
+// Views
+// --------------------------------------------------
+/// A borrowed `dynamic x dynamic` strided view over a region of a matrix.
+///
+/// Creating a view does not allocate or copy, it only holds the underlying data starting from
+///  the first element of the region along with the distances between consecutive rows and
+///  columns.
+#[derive(Debug)]
+pub struct ViewDxD<'a, T> {
+    /// Underlying data starting from the first element of the view.
+    pub(crate) data: &'a [T],
+    pub(crate) rows: usize,
+    pub(crate) columns: usize,
+    /// Distance between the starts of consecutive rows.
+    pub(crate) row_stride: usize,
+    /// Distance between consecutive elements within a row.
+    pub(crate) column_stride: usize,
+}
+/// A borrowed `dynamic x static` strided view over a region of a matrix.
+///
+/// See [`ViewDxD`].
+#[derive(Debug)]
+pub struct ViewDxS<'a, T, const COLUMNS: usize> {
+    /// Underlying data starting from the first element of the view.
+    pub(crate) data: &'a [T],
+    pub(crate) rows: usize,
+    /// Distance between the starts of consecutive rows.
+    pub(crate) row_stride: usize,
+    /// Distance between consecutive elements within a row.
+    pub(crate) column_stride: usize,
+}
+/// A borrowed `static x dynamic` strided view over a region of a matrix.
+///
+/// See [`ViewDxD`].
+#[derive(Debug)]
+pub struct ViewSxD<'a, T, const ROWS: usize> {
+    /// Underlying data starting from the first element of the view.
+    pub(crate) data: &'a [T],
+    pub(crate) columns: usize,
+    /// Distance between the starts of consecutive rows.
+    pub(crate) row_stride: usize,
+    /// Distance between consecutive elements within a row.
+    pub(crate) column_stride: usize,
+}
+/// A borrowed `static x static` strided view over a region of a matrix.
+///
+/// See [`ViewDxD`].
+#[derive(Debug)]
+pub struct ViewSxS<'a, T, const ROWS: usize, const COLUMNS: usize> {
+    /// Underlying data starting from the first element of the view.
+    pub(crate) data: &'a [T],
+    /// Distance between the starts of consecutive rows.
+    pub(crate) row_stride: usize,
+    /// Distance between consecutive elements within a row.
+    pub(crate) column_stride: usize,
+}
+// ViewDxD
+// --------------------------------------------------
+impl<'a, T> Clone for ViewDxD<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, T> Copy for ViewDxD<'a, T> {}
+impl<'a, T> ViewDxD<'a, T> {
+    /// Number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+    /// Number of columns.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+    /// Distance between the starts of consecutive rows in the underlying data.
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+    /// Distance between consecutive elements within a row in the underlying data.
+    pub fn column_stride(&self) -> usize {
+        self.column_stride
+    }
+    /// Gets the element at `row` and `column` without bounds checking against the view shape.
+    pub(crate) fn get(&self, row: usize, column: usize) -> &'a T {
+        &self.data[row * self.row_stride + column * self.column_stride]
+    }
+    /// An iterator over all elements in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        (0..self.rows())
+            .map(move |i| (0..view.columns()).map(move |j| view.get(i, j)))
+            .flatten()
+    }
+}
+impl<'a, T: Clone> ViewDxD<'a, T> {
+    /// The underlying data along with the distance between the starts of consecutive rows,
+    ///  only copying when elements within a row are not contiguous.
+    pub(crate) fn strided(&self) -> (Cow<'a, [T]>, usize) {
+        match self.column_stride {
+            1 => (Cow::Borrowed(self.data), self.row_stride),
+            _ => (Cow::Owned(self.iter().cloned().collect()), self.columns()),
+        }
+    }
+    /// Returns the elements of `self` as a new matrix.
+    pub fn to_matrix(&self) -> MatrixDxD<T> {
+        MatrixDxD {
+            data: self.iter().cloned().collect(),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+}
+// ViewDxS
+// --------------------------------------------------
+impl<'a, T, const COLUMNS: usize> Clone for ViewDxS<'a, T, COLUMNS> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, T, const COLUMNS: usize> Copy for ViewDxS<'a, T, COLUMNS> {}
+impl<'a, T, const COLUMNS: usize> ViewDxS<'a, T, COLUMNS> {
+    /// Number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+    /// Number of columns.
+    pub const fn columns(&self) -> usize {
+        COLUMNS
+    }
+    /// Distance between the starts of consecutive rows in the underlying data.
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+    /// Distance between consecutive elements within a row in the underlying data.
+    pub fn column_stride(&self) -> usize {
+        self.column_stride
+    }
+    /// Gets the element at `row` and `column` without bounds checking against the view shape.
+    pub(crate) fn get(&self, row: usize, column: usize) -> &'a T {
+        &self.data[row * self.row_stride + column * self.column_stride]
+    }
+    /// An iterator over all elements in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        (0..self.rows())
+            .map(move |i| (0..view.columns()).map(move |j| view.get(i, j)))
+            .flatten()
+    }
+}
+impl<'a, T: Clone, const COLUMNS: usize> ViewDxS<'a, T, COLUMNS> {
+    /// The underlying data along with the distance between the starts of consecutive rows,
+    ///  only copying when elements within a row are not contiguous.
+    pub(crate) fn strided(&self) -> (Cow<'a, [T]>, usize) {
+        match self.column_stride {
+            1 => (Cow::Borrowed(self.data), self.row_stride),
+            _ => (Cow::Owned(self.iter().cloned().collect()), self.columns()),
+        }
+    }
+    /// Returns the elements of `self` as a new matrix.
+    pub fn to_matrix(&self) -> MatrixDxS<T, COLUMNS> {
+        MatrixDxS {
+            data: self.iter().cloned().collect(),
+            rows: self.rows,
+            columns: Static,
+        }
+    }
+}
+// ViewSxD
+// --------------------------------------------------
+impl<'a, T, const ROWS: usize> Clone for ViewSxD<'a, T, ROWS> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, T, const ROWS: usize> Copy for ViewSxD<'a, T, ROWS> {}
+impl<'a, T, const ROWS: usize> ViewSxD<'a, T, ROWS> {
+    /// Number of rows.
+    pub const fn rows(&self) -> usize {
+        ROWS
+    }
+    /// Number of columns.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+    /// Distance between the starts of consecutive rows in the underlying data.
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+    /// Distance between consecutive elements within a row in the underlying data.
+    pub fn column_stride(&self) -> usize {
+        self.column_stride
+    }
+    /// Gets the element at `row` and `column` without bounds checking against the view shape.
+    pub(crate) fn get(&self, row: usize, column: usize) -> &'a T {
+        &self.data[row * self.row_stride + column * self.column_stride]
+    }
+    /// An iterator over all elements in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        (0..self.rows())
+            .map(move |i| (0..view.columns()).map(move |j| view.get(i, j)))
+            .flatten()
+    }
+}
+impl<'a, T: Clone, const ROWS: usize> ViewSxD<'a, T, ROWS> {
+    /// The underlying data along with the distance between the starts of consecutive rows,
+    ///  only copying when elements within a row are not contiguous.
+    pub(crate) fn strided(&self) -> (Cow<'a, [T]>, usize) {
+        match self.column_stride {
+            1 => (Cow::Borrowed(self.data), self.row_stride),
+            _ => (Cow::Owned(self.iter().cloned().collect()), self.columns()),
+        }
+    }
+    /// Returns the elements of `self` as a new matrix.
+    pub fn to_matrix(&self) -> MatrixSxD<T, ROWS> {
+        MatrixSxD {
+            data: self.iter().cloned().collect(),
+            columns: self.columns,
+            rows: Static,
+        }
+    }
+}
+// ViewSxS
+// --------------------------------------------------
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> Clone for ViewSxS<'a, T, ROWS, COLUMNS> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> Copy for ViewSxS<'a, T, ROWS, COLUMNS> {}
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> ViewSxS<'a, T, ROWS, COLUMNS> {
+    /// Number of rows.
+    pub const fn rows(&self) -> usize {
+        ROWS
+    }
+    /// Number of columns.
+    pub const fn columns(&self) -> usize {
+        COLUMNS
+    }
+    /// Distance between the starts of consecutive rows in the underlying data.
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+    /// Distance between consecutive elements within a row in the underlying data.
+    pub fn column_stride(&self) -> usize {
+        self.column_stride
+    }
+    /// Gets the element at `row` and `column` without bounds checking against the view shape.
+    pub(crate) fn get(&self, row: usize, column: usize) -> &'a T {
+        &self.data[row * self.row_stride + column * self.column_stride]
+    }
+    /// An iterator over all elements in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        (0..self.rows())
+            .map(move |i| (0..view.columns()).map(move |j| view.get(i, j)))
+            .flatten()
+    }
+}
+impl<'a, T: Clone, const ROWS: usize, const COLUMNS: usize> ViewSxS<'a, T, ROWS, COLUMNS> {
+    /// The underlying data along with the distance between the starts of consecutive rows,
+    ///  only copying when elements within a row are not contiguous.
+    pub(crate) fn strided(&self) -> (Cow<'a, [T]>, usize) {
+        match self.column_stride {
+            1 => (Cow::Borrowed(self.data), self.row_stride),
+            _ => (Cow::Owned(self.iter().cloned().collect()), self.columns()),
+        }
+    }
+}
+impl<'a, T: Clone, const ROWS: usize, const COLUMNS: usize> ViewSxS<'a, T, ROWS, COLUMNS>
+where
+    [(); ROWS * COLUMNS]:,
+{
+    /// Returns the elements of `self` as a new matrix.
+    pub fn to_matrix(&self) -> MatrixSxS<T, ROWS, COLUMNS> {
+        MatrixSxS {
+            data: std::array::from_fn(|i| self.get(i / COLUMNS, i % COLUMNS).clone()),
+            rows: Static,
+            columns: Static,
+        }
+    }
+}
+// Mutable views
+// --------------------------------------------------
+/// A mutably borrowed `dynamic x dynamic` strided view over a region of a matrix.
+///
+/// Writing through the view updates the matrix it was sliced from. The `*Assign` operators are
+///  implemented for it, although as with any value returned from a function it has to be bound
+///  before being assigned to:
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// use static_la::*;
+/// let mut a = MatrixSxS::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+/// let mut b = a.slice_dxd_mut((0..2, 1..2));
+/// b += MatrixSxS::from([[10], [20]]);
+/// assert_eq!(a, MatrixSxS::from([[1, 12, 3], [4, 25, 6], [7, 8, 9]]));
+/// ```
+#[derive(Debug)]
+pub struct ViewDxDMut<'a, T> {
+    /// Underlying data starting from the first element of the view.
+    pub(crate) data: &'a mut [T],
+    pub(crate) rows: usize,
+    pub(crate) columns: usize,
+    /// Distance between the starts of consecutive rows.
+    pub(crate) row_stride: usize,
+    /// Distance between consecutive elements within a row.
+    pub(crate) column_stride: usize,
+}
+/// A mutably borrowed `dynamic x static` strided view over a region of a matrix.
+///
+/// See [`ViewDxDMut`].
+#[derive(Debug)]
+pub struct ViewDxSMut<'a, T, const COLUMNS: usize> {
+    /// Underlying data starting from the first element of the view.
+    pub(crate) data: &'a mut [T],
+    pub(crate) rows: usize,
+    /// Distance between the starts of consecutive rows.
+    pub(crate) row_stride: usize,
+    /// Distance between consecutive elements within a row.
+    pub(crate) column_stride: usize,
+}
+/// A mutably borrowed `static x dynamic` strided view over a region of a matrix.
+///
+/// See [`ViewDxDMut`].
+#[derive(Debug)]
+pub struct ViewSxDMut<'a, T, const ROWS: usize> {
+    /// Underlying data starting from the first element of the view.
+    pub(crate) data: &'a mut [T],
+    pub(crate) columns: usize,
+    /// Distance between the starts of consecutive rows.
+    pub(crate) row_stride: usize,
+    /// Distance between consecutive elements within a row.
+    pub(crate) column_stride: usize,
+}
+/// A mutably borrowed `static x static` strided view over a region of a matrix.
+///
+/// See [`ViewDxDMut`].
+#[derive(Debug)]
+pub struct ViewSxSMut<'a, T, const ROWS: usize, const COLUMNS: usize> {
+    /// Underlying data starting from the first element of the view.
+    pub(crate) data: &'a mut [T],
+    /// Distance between the starts of consecutive rows.
+    pub(crate) row_stride: usize,
+    /// Distance between consecutive elements within a row.
+    pub(crate) column_stride: usize,
+}
+// ViewDxDMut
+// --------------------------------------------------
+impl<'a, T> ViewDxDMut<'a, T> {
+    /// Number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+    /// Number of columns.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+    /// Distance between the starts of consecutive rows in the underlying data.
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+    /// Distance between consecutive elements within a row in the underlying data.
+    pub fn column_stride(&self) -> usize {
+        self.column_stride
+    }
+    /// Returns a shared view over the same region.
+    pub fn view(&self) -> ViewDxD<'_, T> {
+        ViewDxD {
+            data: self.data,
+            rows: self.rows,
+            columns: self.columns,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+        }
+    }
+    /// Gets the element at `row` and `column` without bounds checking against the view shape.
+    pub(crate) fn get_mut(&mut self, row: usize, column: usize) -> &mut T {
+        &mut self.data[row * self.row_stride + column * self.column_stride]
+    }
+    /// An iterator over all elements in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.view().iter()
+    }
+    /// A mutable iterator over all elements in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        debug_assert!(self.row_stride >= self.columns() * self.column_stride);
+        let (rows, columns, column_stride) = (self.rows(), self.columns(), self.column_stride);
+        self.data
+            .chunks_mut(self.row_stride)
+            .take(rows)
+            .map(move |r| r.iter_mut().step_by(column_stride).take(columns))
+            .flatten()
+    }
+}
+// ViewDxSMut
+// --------------------------------------------------
+impl<'a, T, const COLUMNS: usize> ViewDxSMut<'a, T, COLUMNS> {
+    /// Number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+    /// Number of columns.
+    pub const fn columns(&self) -> usize {
+        COLUMNS
+    }
+    /// Distance between the starts of consecutive rows in the underlying data.
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+    /// Distance between consecutive elements within a row in the underlying data.
+    pub fn column_stride(&self) -> usize {
+        self.column_stride
+    }
+    /// Returns a shared view over the same region.
+    pub fn view(&self) -> ViewDxS<'_, T, COLUMNS> {
+        ViewDxS {
+            data: self.data,
+            rows: self.rows,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+        }
+    }
+    /// Gets the element at `row` and `column` without bounds checking against the view shape.
+    pub(crate) fn get_mut(&mut self, row: usize, column: usize) -> &mut T {
+        &mut self.data[row * self.row_stride + column * self.column_stride]
+    }
+    /// An iterator over all elements in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.view().iter()
+    }
+    /// A mutable iterator over all elements in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        debug_assert!(self.row_stride >= self.columns() * self.column_stride);
+        let (rows, columns, column_stride) = (self.rows(), self.columns(), self.column_stride);
+        self.data
+            .chunks_mut(self.row_stride)
+            .take(rows)
+            .map(move |r| r.iter_mut().step_by(column_stride).take(columns))
+            .flatten()
+    }
+}
+// ViewSxDMut
+// --------------------------------------------------
+impl<'a, T, const ROWS: usize> ViewSxDMut<'a, T, ROWS> {
+    /// Number of rows.
+    pub const fn rows(&self) -> usize {
+        ROWS
+    }
+    /// Number of columns.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+    /// Distance between the starts of consecutive rows in the underlying data.
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+    /// Distance between consecutive elements within a row in the underlying data.
+    pub fn column_stride(&self) -> usize {
+        self.column_stride
+    }
+    /// Returns a shared view over the same region.
+    pub fn view(&self) -> ViewSxD<'_, T, ROWS> {
+        ViewSxD {
+            data: self.data,
+            columns: self.columns,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+        }
+    }
+    /// Gets the element at `row` and `column` without bounds checking against the view shape.
+    pub(crate) fn get_mut(&mut self, row: usize, column: usize) -> &mut T {
+        &mut self.data[row * self.row_stride + column * self.column_stride]
+    }
+    /// An iterator over all elements in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.view().iter()
+    }
+    /// A mutable iterator over all elements in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        debug_assert!(self.row_stride >= self.columns() * self.column_stride);
+        let (rows, columns, column_stride) = (self.rows(), self.columns(), self.column_stride);
+        self.data
+            .chunks_mut(self.row_stride)
+            .take(rows)
+            .map(move |r| r.iter_mut().step_by(column_stride).take(columns))
+            .flatten()
+    }
+}
+// ViewSxSMut
+// --------------------------------------------------
+impl<'a, T, const ROWS: usize, const COLUMNS: usize> ViewSxSMut<'a, T, ROWS, COLUMNS> {
+    /// Number of rows.
+    pub const fn rows(&self) -> usize {
+        ROWS
+    }
+    /// Number of columns.
+    pub const fn columns(&self) -> usize {
+        COLUMNS
+    }
+    /// Distance between the starts of consecutive rows in the underlying data.
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+    /// Distance between consecutive elements within a row in the underlying data.
+    pub fn column_stride(&self) -> usize {
+        self.column_stride
+    }
+    /// Returns a shared view over the same region.
+    pub fn view(&self) -> ViewSxS<'_, T, ROWS, COLUMNS> {
+        ViewSxS {
+            data: self.data,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+        }
+    }
+    /// Gets the element at `row` and `column` without bounds checking against the view shape.
+    pub(crate) fn get_mut(&mut self, row: usize, column: usize) -> &mut T {
+        &mut self.data[row * self.row_stride + column * self.column_stride]
+    }
+    /// An iterator over all elements in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.view().iter()
+    }
+    /// A mutable iterator over all elements in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        debug_assert!(self.row_stride >= self.columns() * self.column_stride);
+        let (rows, columns, column_stride) = (self.rows(), self.columns(), self.column_stride);
+        self.data
+            .chunks_mut(self.row_stride)
+            .take(rows)
+            .map(move |r| r.iter_mut().step_by(column_stride).take(columns))
+            .flatten()
+    }
+}
 // MatrixDxD
 // --------------------------------------------------
 impl<T> SliceDxD<T> for MatrixDxD<T> {
-    fn slice_dxd(&self, (rows, columns): Slice) -> MatrixDxD<&T> {
-        assert!(rows.end < self.rows, "Row out of bounds");
-        assert!(columns.end < self.columns, "Columns out of bounds");
-        let data: Vec<Vec<&T>> = self
-            .data
-            .chunks_exact(self.columns)
-            .map(|r| {
-                r.iter()
-                    .skip(columns.start)
-                    .take(columns.end - columns.start)
-                    .collect::<Vec<_>>()
-            })
-            .skip(rows.start)
-            .take(rows.end - rows.start)
-            .collect::<Vec<_>>();
-
-        MatrixDxD::try_from(data).unwrap()
+    fn slice_dxd(&self, (rows, columns): Slice) -> ViewDxD<T> {
+        assert!(rows.end <= self.rows, "Row out of bounds");
+        assert!(columns.end <= self.columns, "Columns out of bounds");
+        ViewDxD {
+            data: region(&self.data, self.columns, &rows, &columns),
+            rows: rows.len(),
+            columns: columns.len(),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
     }
 }
-impl<T: std::fmt::Debug> SliceSxD<T> for MatrixDxD<T> {
+impl<T> SliceSxD<T> for MatrixDxD<T> {
     fn slice_sxd<const ROWS: Range<usize>>(
         &self,
         columns: Range<usize>,
-    ) -> MatrixSxD<&T, { range_len(ROWS) }>
+    ) -> ViewSxD<T, { range_len(ROWS) }>
     where
         [(); range_len(ROWS)]:,
     {
-        assert!(ROWS.end < self.rows, "Row out of bounds");
-        assert!(columns.end < self.columns, "Columns out of bounds");
-        let data: [Vec<&T>; range_len(ROWS)] = self
-            .data
-            .chunks_exact(self.columns)
-            .map(|r| {
-                r.iter()
-                    .skip(columns.start)
-                    .take(columns.end - columns.start)
-                    .collect::<Vec<_>>()
-            })
-            .skip(ROWS.start)
-            .take(range_len(ROWS))
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
-
-        MatrixSxD::try_from(data).unwrap()
+        assert!(ROWS.end <= self.rows, "Row out of bounds");
+        assert!(columns.end <= self.columns, "Columns out of bounds");
+        ViewSxD {
+            data: region(&self.data, self.columns, &ROWS, &columns),
+            columns: columns.len(),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
     }
 }
-impl<T: std::fmt::Debug> SliceDxS<T> for MatrixDxD<T> {
+impl<T> SliceDxS<T> for MatrixDxD<T> {
     fn slice_dxs<const COLUMNS: Range<usize>>(
         &self,
         rows: Range<usize>,
-    ) -> MatrixDxS<&T, { range_len(COLUMNS) }>
+    ) -> ViewDxS<T, { range_len(COLUMNS) }>
     where
         [(); range_len(COLUMNS)]:,
     {
-        assert!(rows.end < self.rows, "Row out of bounds");
-        assert!(COLUMNS.end < self.columns, "Columns out of bounds");
-        let data: Vec<[&T; range_len(COLUMNS)]> = self
-            .data
-            .chunks_exact(self.columns)
-            .map(|r| {
-                r.iter()
-                    .skip(COLUMNS.start)
-                    .take(range_len(COLUMNS))
-                    .collect::<Vec<_>>()
-                    .try_into()
-                    .unwrap()
-            })
-            .skip(rows.start)
-            .take(rows.end - rows.start)
-            .collect::<Vec<_>>();
-
-        MatrixDxS::from(data)
+        assert!(rows.end <= self.rows, "Row out of bounds");
+        assert!(COLUMNS.end <= self.columns, "Columns out of bounds");
+        ViewDxS {
+            data: region(&self.data, self.columns, &rows, &COLUMNS),
+            rows: rows.len(),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
     }
 }
-impl<T: std::fmt::Debug> SliceSxS<T> for MatrixDxD<T> {
+impl<T> SliceSxS<T> for MatrixDxD<T> {
     fn slice_sxs<const ROWS: Range<usize>, const COLUMNS: Range<usize>>(
         &self,
-    ) -> MatrixSxS<&T, { range_len(ROWS) }, { range_len(COLUMNS) }>
+    ) -> ViewSxS<T, { range_len(ROWS) }, { range_len(COLUMNS) }>
     where
         [(); { range_len(ROWS) } * { range_len(COLUMNS) }]:,
     {
-        assert!(ROWS.end < self.rows, "Row out of bounds");
-        assert!(COLUMNS.end < self.columns, "Columns out of bounds");
-        let data: [[&T; range_len(COLUMNS)]; range_len(ROWS)] = self
-            .data
-            .chunks_exact(self.columns)
-            .map(|r| {
-                r.iter()
-                    .skip(COLUMNS.start)
-                    .take(range_len(COLUMNS))
-                    .collect::<Vec<_>>()
-                    .try_into()
-                    .unwrap()
-            })
-            .skip(ROWS.start)
-            .take(range_len(ROWS))
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
-
-        MatrixSxS::from(data)
+        assert!(ROWS.end <= self.rows, "Row out of bounds");
+        assert!(COLUMNS.end <= self.columns, "Columns out of bounds");
+        ViewSxS {
+            data: region(&self.data, self.columns, &ROWS, &COLUMNS),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
+    }
+}
+impl<T> SliceDxDMut<T> for MatrixDxD<T> {
+    fn slice_dxd_mut(&mut self, (rows, columns): Slice) -> ViewDxDMut<T> {
+        assert!(rows.end <= self.rows, "Row out of bounds");
+        assert!(columns.end <= self.columns, "Columns out of bounds");
+        ViewDxDMut {
+            data: region_mut(&mut self.data, self.columns, &rows, &columns),
+            rows: rows.len(),
+            columns: columns.len(),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
+    }
+}
+impl<T> SliceSxDMut<T> for MatrixDxD<T> {
+    fn slice_sxd_mut<const ROWS: Range<usize>>(
+        &mut self,
+        columns: Range<usize>,
+    ) -> ViewSxDMut<T, { range_len(ROWS) }>
+    where
+        [(); range_len(ROWS)]:,
+    {
+        assert!(ROWS.end <= self.rows, "Row out of bounds");
+        assert!(columns.end <= self.columns, "Columns out of bounds");
+        ViewSxDMut {
+            data: region_mut(&mut self.data, self.columns, &ROWS, &columns),
+            columns: columns.len(),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
+    }
+}
+impl<T> SliceDxSMut<T> for MatrixDxD<T> {
+    fn slice_dxs_mut<const COLUMNS: Range<usize>>(
+        &mut self,
+        rows: Range<usize>,
+    ) -> ViewDxSMut<T, { range_len(COLUMNS) }>
+    where
+        [(); range_len(COLUMNS)]:,
+    {
+        assert!(rows.end <= self.rows, "Row out of bounds");
+        assert!(COLUMNS.end <= self.columns, "Columns out of bounds");
+        ViewDxSMut {
+            data: region_mut(&mut self.data, self.columns, &rows, &COLUMNS),
+            rows: rows.len(),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
+    }
+}
+impl<T> SliceSxSMut<T> for MatrixDxD<T> {
+    fn slice_sxs_mut<const ROWS: Range<usize>, const COLUMNS: Range<usize>>(
+        &mut self,
+    ) -> ViewSxSMut<T, { range_len(ROWS) }, { range_len(COLUMNS) }>
+    where
+        [(); { range_len(ROWS) } * { range_len(COLUMNS) }]:,
+    {
+        assert!(ROWS.end <= self.rows, "Row out of bounds");
+        assert!(COLUMNS.end <= self.columns, "Columns out of bounds");
+        ViewSxSMut {
+            data: region_mut(&mut self.data, self.columns, &ROWS, &COLUMNS),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
     }
 }
 // MatrixDxS
 // --------------------------------------------------
 impl<T, const C: usize> SliceDxD<T> for MatrixDxS<T, C> {
-    fn slice_dxd(&self, (rows, columns): Slice) -> MatrixDxD<&T> {
-        assert!(rows.end < self.rows, "Row out of bounds");
-        assert!(columns.end < C, "Columns out of bounds");
-        let data: Vec<Vec<&T>> = self
-            .data
-            .chunks_exact(C)
-            .map(|r| {
-                r.iter()
-                    .skip(columns.start)
-                    .take(columns.end - columns.start)
-                    .collect::<Vec<_>>()
-            })
-            .skip(rows.start)
-            .take(rows.end - rows.start)
-            .collect::<Vec<_>>();
-
-        MatrixDxD::try_from(data).unwrap()
+    fn slice_dxd(&self, (rows, columns): Slice) -> ViewDxD<T> {
+        assert!(rows.end <= self.rows, "Row out of bounds");
+        assert!(columns.end <= C, "Columns out of bounds");
+        ViewDxD {
+            data: region(&self.data, C, &rows, &columns),
+            rows: rows.len(),
+            columns: columns.len(),
+            row_stride: C,
+            column_stride: 1,
+        }
     }
 }
-impl<T: std::fmt::Debug, const C: usize> SliceSxD<T> for MatrixDxS<T, C> {
+impl<T, const C: usize> SliceSxD<T> for MatrixDxS<T, C> {
     fn slice_sxd<const ROWS: Range<usize>>(
         &self,
         columns: Range<usize>,
-    ) -> MatrixSxD<&T, { range_len(ROWS) }>
+    ) -> ViewSxD<T, { range_len(ROWS) }>
     where
         [(); range_len(ROWS)]:,
     {
-        assert!(ROWS.end < self.rows, "Row out of bounds");
-        assert!(columns.end < C, "Columns out of bounds");
-        let data: [Vec<&T>; range_len(ROWS)] = self
-            .data
-            .array_chunks::<C>()
-            .map(|r| {
-                r.iter()
-                    .skip(columns.start)
-                    .take(columns.end - columns.start)
-                    .collect::<Vec<_>>()
-            })
-            .skip(ROWS.start)
-            .take(range_len(ROWS))
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
-
-        MatrixSxD::try_from(data).unwrap()
+        assert!(ROWS.end <= self.rows, "Row out of bounds");
+        assert!(columns.end <= C, "Columns out of bounds");
+        ViewSxD {
+            data: region(&self.data, C, &ROWS, &columns),
+            columns: columns.len(),
+            row_stride: C,
+            column_stride: 1,
+        }
     }
 }
-impl<T: std::fmt::Debug, const C: usize> SliceDxS<T> for MatrixDxS<T, C>
-where
-    [(); C]:,
-{
+impl<T, const C: usize> SliceDxS<T> for MatrixDxS<T, C> {
     fn slice_dxs<const COLUMNS: Range<usize>>(
         &self,
         rows: Range<usize>,
-    ) -> MatrixDxS<&T, { range_len(COLUMNS) }>
+    ) -> ViewDxS<T, { range_len(COLUMNS) }>
     where
         [(); range_len(COLUMNS)]:,
     {
-        assert!(rows.end < self.rows, "Row out of bounds");
-        assert!(COLUMNS.end < C, "Columns out of bounds");
-        let data: Vec<[&T; range_len(COLUMNS)]> = self
-            .data
-            .array_chunks::<C>()
-            .map(|r| {
-                r.iter()
-                    .skip(COLUMNS.start)
-                    .take(range_len(COLUMNS))
-                    .collect::<Vec<_>>()
-                    .try_into()
-                    .unwrap()
-            })
-            .skip(rows.start)
-            .take(rows.end - rows.start)
-            .collect::<Vec<_>>();
-
-        MatrixDxS::from(data)
+        assert!(rows.end <= self.rows, "Row out of bounds");
+        let () = InBounds::<COLUMNS, C>::ASSERT;
+        ViewDxS {
+            data: region(&self.data, C, &rows, &COLUMNS),
+            rows: rows.len(),
+            row_stride: C,
+            column_stride: 1,
+        }
     }
 }
-impl<T: std::fmt::Debug, const C: usize> SliceSxS<T> for MatrixDxS<T, C> {
+impl<T, const C: usize> SliceSxS<T> for MatrixDxS<T, C> {
     fn slice_sxs<const ROWS: Range<usize>, const COLUMNS: Range<usize>>(
         &self,
-    ) -> MatrixSxS<&T, { range_len(ROWS) }, { range_len(COLUMNS) }>
+    ) -> ViewSxS<T, { range_len(ROWS) }, { range_len(COLUMNS) }>
     where
         [(); { range_len(ROWS) } * { range_len(COLUMNS) }]:,
     {
-        assert!(ROWS.end < self.rows, "Row out of bounds");
-        assert!(COLUMNS.end < C, "Columns out of bounds");
-        let data: [[&T; range_len(COLUMNS)]; range_len(ROWS)] = self
-            .data
-            .array_chunks::<C>()
-            .map(|r| {
-                r.iter()
-                    .skip(COLUMNS.start)
-                    .take(range_len(COLUMNS))
-                    .collect::<Vec<_>>()
-                    .try_into()
-                    .unwrap()
-            })
-            .skip(ROWS.start)
-            .take(range_len(ROWS))
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
-
-        MatrixSxS::from(data)
+        assert!(ROWS.end <= self.rows, "Row out of bounds");
+        let () = InBounds::<COLUMNS, C>::ASSERT;
+        ViewSxS {
+            data: region(&self.data, C, &ROWS, &COLUMNS),
+            row_stride: C,
+            column_stride: 1,
+        }
+    }
+}
+impl<T, const C: usize> SliceDxDMut<T> for MatrixDxS<T, C> {
+    fn slice_dxd_mut(&mut self, (rows, columns): Slice) -> ViewDxDMut<T> {
+        assert!(rows.end <= self.rows, "Row out of bounds");
+        assert!(columns.end <= C, "Columns out of bounds");
+        ViewDxDMut {
+            data: region_mut(&mut self.data, C, &rows, &columns),
+            rows: rows.len(),
+            columns: columns.len(),
+            row_stride: C,
+            column_stride: 1,
+        }
+    }
+}
+impl<T, const C: usize> SliceSxDMut<T> for MatrixDxS<T, C> {
+    fn slice_sxd_mut<const ROWS: Range<usize>>(
+        &mut self,
+        columns: Range<usize>,
+    ) -> ViewSxDMut<T, { range_len(ROWS) }>
+    where
+        [(); range_len(ROWS)]:,
+    {
+        assert!(ROWS.end <= self.rows, "Row out of bounds");
+        assert!(columns.end <= C, "Columns out of bounds");
+        ViewSxDMut {
+            data: region_mut(&mut self.data, C, &ROWS, &columns),
+            columns: columns.len(),
+            row_stride: C,
+            column_stride: 1,
+        }
+    }
+}
+impl<T, const C: usize> SliceDxSMut<T> for MatrixDxS<T, C> {
+    fn slice_dxs_mut<const COLUMNS: Range<usize>>(
+        &mut self,
+        rows: Range<usize>,
+    ) -> ViewDxSMut<T, { range_len(COLUMNS) }>
+    where
+        [(); range_len(COLUMNS)]:,
+    {
+        assert!(rows.end <= self.rows, "Row out of bounds");
+        let () = InBounds::<COLUMNS, C>::ASSERT;
+        ViewDxSMut {
+            data: region_mut(&mut self.data, C, &rows, &COLUMNS),
+            rows: rows.len(),
+            row_stride: C,
+            column_stride: 1,
+        }
+    }
+}
+impl<T, const C: usize> SliceSxSMut<T> for MatrixDxS<T, C> {
+    fn slice_sxs_mut<const ROWS: Range<usize>, const COLUMNS: Range<usize>>(
+        &mut self,
+    ) -> ViewSxSMut<T, { range_len(ROWS) }, { range_len(COLUMNS) }>
+    where
+        [(); { range_len(ROWS) } * { range_len(COLUMNS) }]:,
+    {
+        assert!(ROWS.end <= self.rows, "Row out of bounds");
+        let () = InBounds::<COLUMNS, C>::ASSERT;
+        ViewSxSMut {
+            data: region_mut(&mut self.data, C, &ROWS, &COLUMNS),
+            row_stride: C,
+            column_stride: 1,
+        }
     }
 }
 // MatrixSxD
 // --------------------------------------------------
 impl<T, const R: usize> SliceDxD<T> for MatrixSxD<T, R> {
-    fn slice_dxd(&self, (rows, columns): Slice) -> MatrixDxD<&T> {
-        assert!(rows.end < R, "Row out of bounds");
-        assert!(columns.end < self.columns, "Columns out of bounds");
-        let data: Vec<Vec<&T>> = self
-            .data
-            .chunks_exact(self.columns)
-            .map(|r| {
-                r.iter()
-                    .skip(columns.start)
-                    .take(columns.end - columns.start)
-                    .collect::<Vec<_>>()
-            })
-            .skip(rows.start)
-            .take(rows.end - rows.start)
-            .collect::<Vec<_>>();
-
-        MatrixDxD::try_from(data).unwrap()
+    fn slice_dxd(&self, (rows, columns): Slice) -> ViewDxD<T> {
+        assert!(rows.end <= R, "Row out of bounds");
+        assert!(columns.end <= self.columns, "Columns out of bounds");
+        ViewDxD {
+            data: region(&self.data, self.columns, &rows, &columns),
+            rows: rows.len(),
+            columns: columns.len(),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
     }
 }
-impl<T: std::fmt::Debug, const R: usize> SliceSxD<T> for MatrixSxD<T, R> {
+impl<T, const R: usize> SliceSxD<T> for MatrixSxD<T, R> {
     fn slice_sxd<const ROWS: Range<usize>>(
         &self,
         columns: Range<usize>,
-    ) -> MatrixSxD<&T, { range_len(ROWS) }>
+    ) -> ViewSxD<T, { range_len(ROWS) }>
     where
         [(); range_len(ROWS)]:,
     {
-        assert!(ROWS.end < R, "Row out of bounds");
-        assert!(columns.end < self.columns, "Columns out of bounds");
-        let data: [Vec<&T>; range_len(ROWS)] = self
-            .data
-            .chunks_exact(self.columns)
-            .map(|r| {
-                r.iter()
-                    .skip(columns.start)
-                    .take(columns.end - columns.start)
-                    .collect::<Vec<_>>()
-            })
-            .skip(ROWS.start)
-            .take(range_len(ROWS))
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
-
-        MatrixSxD::try_from(data).unwrap()
+        let () = InBounds::<ROWS, R>::ASSERT;
+        assert!(columns.end <= self.columns, "Columns out of bounds");
+        ViewSxD {
+            data: region(&self.data, self.columns, &ROWS, &columns),
+            columns: columns.len(),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
     }
 }
-impl<T: std::fmt::Debug, const R: usize> SliceDxS<T> for MatrixSxD<T, R>
-where
-    [(); R]:,
-{
+impl<T, const R: usize> SliceDxS<T> for MatrixSxD<T, R> {
     fn slice_dxs<const COLUMNS: Range<usize>>(
         &self,
         rows: Range<usize>,
-    ) -> MatrixDxS<&T, { range_len(COLUMNS) }>
+    ) -> ViewDxS<T, { range_len(COLUMNS) }>
     where
         [(); range_len(COLUMNS)]:,
     {
-        assert!(rows.end < R, "Row out of bounds");
-        assert!(COLUMNS.end < self.columns, "Columns out of bounds");
-        let data: Vec<[&T; range_len(COLUMNS)]> = self
-            .data
-            .chunks_exact(self.columns)
-            .map(|r| {
-                r.iter()
-                    .skip(COLUMNS.start)
-                    .take(range_len(COLUMNS))
-                    .collect::<Vec<_>>()
-                    .try_into()
-                    .unwrap()
-            })
-            .skip(rows.start)
-            .take(rows.end - rows.start)
-            .collect::<Vec<_>>();
-
-        MatrixDxS::from(data)
+        assert!(rows.end <= R, "Row out of bounds");
+        assert!(COLUMNS.end <= self.columns, "Columns out of bounds");
+        ViewDxS {
+            data: region(&self.data, self.columns, &rows, &COLUMNS),
+            rows: rows.len(),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
     }
 }
-impl<T: std::fmt::Debug, const R: usize> SliceSxS<T> for MatrixSxD<T, R> {
+impl<T, const R: usize> SliceSxS<T> for MatrixSxD<T, R> {
     fn slice_sxs<const ROWS: Range<usize>, const COLUMNS: Range<usize>>(
         &self,
-    ) -> MatrixSxS<&T, { range_len(ROWS) }, { range_len(COLUMNS) }>
+    ) -> ViewSxS<T, { range_len(ROWS) }, { range_len(COLUMNS) }>
     where
         [(); { range_len(ROWS) } * { range_len(COLUMNS) }]:,
     {
-        assert!(ROWS.end < R, "Row out of bounds");
-        assert!(COLUMNS.end < self.columns, "Columns out of bounds");
-        let data: [[&T; range_len(COLUMNS)]; range_len(ROWS)] = self
-            .data
-            .chunks_exact(self.columns)
-            .map(|r| {
-                r.iter()
-                    .skip(COLUMNS.start)
-                    .take(range_len(COLUMNS))
-                    .collect::<Vec<_>>()
-                    .try_into()
-                    .unwrap()
-            })
-            .skip(ROWS.start)
-            .take(range_len(ROWS))
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
-
-        MatrixSxS::from(data)
+        let () = InBounds::<ROWS, R>::ASSERT;
+        assert!(COLUMNS.end <= self.columns, "Columns out of bounds");
+        ViewSxS {
+            data: region(&self.data, self.columns, &ROWS, &COLUMNS),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
+    }
+}
+impl<T, const R: usize> SliceDxDMut<T> for MatrixSxD<T, R> {
+    fn slice_dxd_mut(&mut self, (rows, columns): Slice) -> ViewDxDMut<T> {
+        assert!(rows.end <= R, "Row out of bounds");
+        assert!(columns.end <= self.columns, "Columns out of bounds");
+        ViewDxDMut {
+            data: region_mut(&mut self.data, self.columns, &rows, &columns),
+            rows: rows.len(),
+            columns: columns.len(),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
+    }
+}
+impl<T, const R: usize> SliceSxDMut<T> for MatrixSxD<T, R> {
+    fn slice_sxd_mut<const ROWS: Range<usize>>(
+        &mut self,
+        columns: Range<usize>,
+    ) -> ViewSxDMut<T, { range_len(ROWS) }>
+    where
+        [(); range_len(ROWS)]:,
+    {
+        let () = InBounds::<ROWS, R>::ASSERT;
+        assert!(columns.end <= self.columns, "Columns out of bounds");
+        ViewSxDMut {
+            data: region_mut(&mut self.data, self.columns, &ROWS, &columns),
+            columns: columns.len(),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
+    }
+}
+impl<T, const R: usize> SliceDxSMut<T> for MatrixSxD<T, R> {
+    fn slice_dxs_mut<const COLUMNS: Range<usize>>(
+        &mut self,
+        rows: Range<usize>,
+    ) -> ViewDxSMut<T, { range_len(COLUMNS) }>
+    where
+        [(); range_len(COLUMNS)]:,
+    {
+        assert!(rows.end <= R, "Row out of bounds");
+        assert!(COLUMNS.end <= self.columns, "Columns out of bounds");
+        ViewDxSMut {
+            data: region_mut(&mut self.data, self.columns, &rows, &COLUMNS),
+            rows: rows.len(),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
+    }
+}
+impl<T, const R: usize> SliceSxSMut<T> for MatrixSxD<T, R> {
+    fn slice_sxs_mut<const ROWS: Range<usize>, const COLUMNS: Range<usize>>(
+        &mut self,
+    ) -> ViewSxSMut<T, { range_len(ROWS) }, { range_len(COLUMNS) }>
+    where
+        [(); { range_len(ROWS) } * { range_len(COLUMNS) }]:,
+    {
+        let () = InBounds::<ROWS, R>::ASSERT;
+        assert!(COLUMNS.end <= self.columns, "Columns out of bounds");
+        ViewSxSMut {
+            data: region_mut(&mut self.data, self.columns, &ROWS, &COLUMNS),
+            row_stride: self.columns,
+            column_stride: 1,
+        }
     }
 }
 // MatrixSxS
@@ -378,117 +1064,154 @@ impl<T, const R: usize, const C: usize> SliceDxD<T> for MatrixSxS<T, R, C>
 where
     [(); R * C]:,
 {
-    fn slice_dxd(&self, (rows, columns): Slice) -> MatrixDxD<&T> {
-        assert!(rows.end < R, "Row out of bounds");
-        assert!(columns.end < C, "Columns out of bounds");
-        let data: Vec<Vec<&T>> = self
-            .data
-            .array_chunks::<C>()
-            .map(|r| {
-                r.iter()
-                    .skip(columns.start)
-                    .take(columns.end - columns.start)
-                    .collect::<Vec<_>>()
-            })
-            .skip(rows.start)
-            .take(rows.end - rows.start)
-            .collect::<Vec<_>>();
-
-        MatrixDxD::try_from(data).unwrap()
+    fn slice_dxd(&self, (rows, columns): Slice) -> ViewDxD<T> {
+        assert!(rows.end <= R, "Row out of bounds");
+        assert!(columns.end <= C, "Columns out of bounds");
+        ViewDxD {
+            data: region(&self.data, C, &rows, &columns),
+            rows: rows.len(),
+            columns: columns.len(),
+            row_stride: C,
+            column_stride: 1,
+        }
     }
 }
-impl<T: std::fmt::Debug, const R: usize, const C: usize> SliceSxD<T> for MatrixSxS<T, R, C>
+impl<T, const R: usize, const C: usize> SliceSxD<T> for MatrixSxS<T, R, C>
 where
     [(); R * C]:,
 {
     fn slice_sxd<const ROWS: Range<usize>>(
         &self,
         columns: Range<usize>,
-    ) -> MatrixSxD<&T, { range_len(ROWS) }>
+    ) -> ViewSxD<T, { range_len(ROWS) }>
     where
         [(); range_len(ROWS)]:,
     {
-        assert!(ROWS.end < R, "Row out of bounds");
-        assert!(columns.end < C, "Columns out of bounds");
-        let data: [Vec<&T>; range_len(ROWS)] = self
-            .data
-            .array_chunks::<C>()
-            .map(|r| {
-                r.iter()
-                    .skip(columns.start)
-                    .take(columns.end - columns.start)
-                    .collect::<Vec<_>>()
-            })
-            .skip(ROWS.start)
-            .take(range_len(ROWS))
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
-
-        MatrixSxD::try_from(data).unwrap()
+        let () = InBounds::<ROWS, R>::ASSERT;
+        assert!(columns.end <= C, "Columns out of bounds");
+        ViewSxD {
+            data: region(&self.data, C, &ROWS, &columns),
+            columns: columns.len(),
+            row_stride: C,
+            column_stride: 1,
+        }
     }
 }
-impl<T: std::fmt::Debug, const R: usize, const C: usize> SliceDxS<T> for MatrixSxS<T, R, C>
+impl<T, const R: usize, const C: usize> SliceDxS<T> for MatrixSxS<T, R, C>
 where
     [(); R * C]:,
 {
     fn slice_dxs<const COLUMNS: Range<usize>>(
         &self,
         rows: Range<usize>,
-    ) -> MatrixDxS<&T, { range_len(COLUMNS) }>
+    ) -> ViewDxS<T, { range_len(COLUMNS) }>
     where
         [(); range_len(COLUMNS)]:,
     {
-        assert!(rows.end < R, "Row out of bounds");
-        assert!(COLUMNS.end < C, "Columns out of bounds");
-        let data: Vec<[&T; range_len(COLUMNS)]> = self
-            .data
-            .array_chunks::<C>()
-            .map(|r| {
-                r.iter()
-                    .skip(COLUMNS.start)
-                    .take(range_len(COLUMNS))
-                    .collect::<Vec<_>>()
-                    .try_into()
-                    .unwrap()
-            })
-            .skip(rows.start)
-            .take(rows.end - rows.start)
-            .collect::<Vec<_>>();
-
-        MatrixDxS::from(data)
+        assert!(rows.end <= R, "Row out of bounds");
+        let () = InBounds::<COLUMNS, C>::ASSERT;
+        ViewDxS {
+            data: region(&self.data, C, &rows, &COLUMNS),
+            rows: rows.len(),
+            row_stride: C,
+            column_stride: 1,
+        }
     }
 }
-impl<T: std::fmt::Debug, const R: usize, const C: usize> SliceSxS<T> for MatrixSxS<T, R, C>
+impl<T, const R: usize, const C: usize> SliceSxS<T> for MatrixSxS<T, R, C>
 where
     [(); R * C]:,
 {
     fn slice_sxs<const ROWS: Range<usize>, const COLUMNS: Range<usize>>(
         &self,
-    ) -> MatrixSxS<&T, { range_len(ROWS) }, { range_len(COLUMNS) }>
+    ) -> ViewSxS<T, { range_len(ROWS) }, { range_len(COLUMNS) }>
     where
         [(); { range_len(ROWS) } * { range_len(COLUMNS) }]:,
     {
-        assert!(ROWS.end < R, "Row out of bounds");
-        assert!(COLUMNS.end < C, "Columns out of bounds");
-        let data: [[&T; range_len(COLUMNS)]; range_len(ROWS)] = self
-            .data
-            .array_chunks::<C>()
-            .map(|r| {
-                r.iter()
-                    .skip(COLUMNS.start)
-                    .take(range_len(COLUMNS))
-                    .collect::<Vec<_>>()
-                    .try_into()
-                    .unwrap()
-            })
-            .skip(ROWS.start)
-            .take(range_len(ROWS))
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
-
-        MatrixSxS::from(data)
+        let () = InBounds::<ROWS, R>::ASSERT;
+        let () = InBounds::<COLUMNS, C>::ASSERT;
+        ViewSxS {
+            data: region(&self.data, C, &ROWS, &COLUMNS),
+            row_stride: C,
+            column_stride: 1,
+        }
+    }
+}
+impl<T, const R: usize, const C: usize> SliceDxDMut<T> for MatrixSxS<T, R, C>
+where
+    [(); R * C]:,
+{
+    fn slice_dxd_mut(&mut self, (rows, columns): Slice) -> ViewDxDMut<T> {
+        assert!(rows.end <= R, "Row out of bounds");
+        assert!(columns.end <= C, "Columns out of bounds");
+        ViewDxDMut {
+            data: region_mut(&mut self.data, C, &rows, &columns),
+            rows: rows.len(),
+            columns: columns.len(),
+            row_stride: C,
+            column_stride: 1,
+        }
+    }
+}
+impl<T, const R: usize, const C: usize> SliceSxDMut<T> for MatrixSxS<T, R, C>
+where
+    [(); R * C]:,
+{
+    fn slice_sxd_mut<const ROWS: Range<usize>>(
+        &mut self,
+        columns: Range<usize>,
+    ) -> ViewSxDMut<T, { range_len(ROWS) }>
+    where
+        [(); range_len(ROWS)]:,
+    {
+        let () = InBounds::<ROWS, R>::ASSERT;
+        assert!(columns.end <= C, "Columns out of bounds");
+        ViewSxDMut {
+            data: region_mut(&mut self.data, C, &ROWS, &columns),
+            columns: columns.len(),
+            row_stride: C,
+            column_stride: 1,
+        }
+    }
+}
+impl<T, const R: usize, const C: usize> SliceDxSMut<T> for MatrixSxS<T, R, C>
+where
+    [(); R * C]:,
+{
+    fn slice_dxs_mut<const COLUMNS: Range<usize>>(
+        &mut self,
+        rows: Range<usize>,
+    ) -> ViewDxSMut<T, { range_len(COLUMNS) }>
+    where
+        [(); range_len(COLUMNS)]:,
+    {
+        assert!(rows.end <= R, "Row out of bounds");
+        let () = InBounds::<COLUMNS, C>::ASSERT;
+        ViewDxSMut {
+            data: region_mut(&mut self.data, C, &rows, &COLUMNS),
+            rows: rows.len(),
+            row_stride: C,
+            column_stride: 1,
+        }
+    }
+}
+impl<T, const R: usize, const C: usize> SliceSxSMut<T> for MatrixSxS<T, R, C>
+where
+    [(); R * C]:,
+{
+    fn slice_sxs_mut<const ROWS: Range<usize>, const COLUMNS: Range<usize>>(
+        &mut self,
+    ) -> ViewSxSMut<T, { range_len(ROWS) }, { range_len(COLUMNS) }>
+    where
+        [(); { range_len(ROWS) } * { range_len(COLUMNS) }]:,
+    {
+        let () = InBounds::<ROWS, R>::ASSERT;
+        let () = InBounds::<COLUMNS, C>::ASSERT;
+        ViewSxSMut {
+            data: region_mut(&mut self.data, C, &ROWS, &COLUMNS),
+            row_stride: C,
+            column_stride: 1,
+        }
     }
 }
 
@@ -504,32 +1227,32 @@ mod tests {
     fn dxd_dxd() {
         let a = MatrixDxD::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
         assert_eq!(
-            a.slice_dxd((0..1, 0..2)),
-            MatrixDxD::try_from(vec![vec![&1, &2]]).unwrap()
+            a.slice_dxd((0..1, 0..2)).to_matrix(),
+            MatrixDxD::try_from(vec![vec![1, 2]]).unwrap()
         );
     }
     #[test]
     fn dxd_dxs() {
         let a = MatrixDxD::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
         assert_eq!(
-            a.slice_dxs::<{ 0..2 }>(0..1),
-            MatrixDxS::from(vec![[&1, &2]])
+            a.slice_dxs::<{ 0..2 }>(0..1).to_matrix(),
+            MatrixDxS::from(vec![[1, 2]])
         );
     }
     #[test]
     fn dxd_sxd() {
         let a = MatrixDxD::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
         assert_eq!(
-            a.slice_sxd::<{ 0..1 }>(0..2),
-            MatrixSxD::try_from([vec![&1, &2]]).unwrap()
+            a.slice_sxd::<{ 0..1 }>(0..2).to_matrix(),
+            MatrixSxD::try_from([vec![1, 2]]).unwrap()
         );
     }
     #[test]
     fn dxd_sxs() {
         let a = MatrixDxD::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
         assert_eq!(
-            a.slice_sxs::<{ 0..1 }, { 0..2 }>(),
-            MatrixSxS::from([[&1, &2]])
+            a.slice_sxs::<{ 0..1 }, { 0..2 }>().to_matrix(),
+            MatrixSxS::from([[1, 2]])
         );
     }
     // MatrixDxS
@@ -538,32 +1261,32 @@ mod tests {
     fn dxs_dxd() {
         let a = MatrixDxS::from(vec![[1, 2, 3], [4, 5, 6]]);
         assert_eq!(
-            a.slice_dxd((0..1, 0..2)),
-            MatrixDxD::try_from(vec![vec![&1, &2]]).unwrap()
+            a.slice_dxd((0..1, 0..2)).to_matrix(),
+            MatrixDxD::try_from(vec![vec![1, 2]]).unwrap()
         );
     }
     #[test]
     fn dxs_dxs() {
         let a = MatrixDxS::from(vec![[1, 2, 3], [4, 5, 6]]);
         assert_eq!(
-            a.slice_dxs::<{ 0..2 }>(0..1),
-            MatrixDxS::from(vec![[&1, &2]])
+            a.slice_dxs::<{ 0..2 }>(0..1).to_matrix(),
+            MatrixDxS::from(vec![[1, 2]])
         );
     }
     #[test]
     fn dxs_sxd() {
         let a = MatrixDxS::from(vec![[1, 2, 3], [4, 5, 6]]);
         assert_eq!(
-            a.slice_sxd::<{ 0..1 }>(0..2),
-            MatrixSxD::try_from([vec![&1, &2]]).unwrap()
+            a.slice_sxd::<{ 0..1 }>(0..2).to_matrix(),
+            MatrixSxD::try_from([vec![1, 2]]).unwrap()
         );
     }
     #[test]
     fn dxs_sxs() {
         let a = MatrixDxS::from(vec![[1, 2, 3], [4, 5, 6]]);
         assert_eq!(
-            a.slice_sxs::<{ 0..1 }, { 0..2 }>(),
-            MatrixSxS::from([[&1, &2]])
+            a.slice_sxs::<{ 0..1 }, { 0..2 }>().to_matrix(),
+            MatrixSxS::from([[1, 2]])
         );
     }
     // MatrixSxD
@@ -572,32 +1295,32 @@ mod tests {
     fn sxd_dxd() {
         let a = MatrixSxD::try_from([vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
         assert_eq!(
-            a.slice_dxd((0..1, 0..2)),
-            MatrixDxD::try_from(vec![vec![&1, &2]]).unwrap()
+            a.slice_dxd((0..1, 0..2)).to_matrix(),
+            MatrixDxD::try_from(vec![vec![1, 2]]).unwrap()
         );
     }
     #[test]
     fn sxd_dxs() {
         let a = MatrixSxD::try_from([vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
         assert_eq!(
-            a.slice_dxs::<{ 0..2 }>(0..1),
-            MatrixDxS::from(vec![[&1, &2]])
+            a.slice_dxs::<{ 0..2 }>(0..1).to_matrix(),
+            MatrixDxS::from(vec![[1, 2]])
         );
     }
     #[test]
     fn sxd_sxd() {
         let a = MatrixSxD::try_from([vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
         assert_eq!(
-            a.slice_sxd::<{ 0..1 }>(0..2),
-            MatrixSxD::try_from([vec![&1, &2]]).unwrap()
+            a.slice_sxd::<{ 0..1 }>(0..2).to_matrix(),
+            MatrixSxD::try_from([vec![1, 2]]).unwrap()
         );
     }
     #[test]
     fn sxd_sxs() {
         let a = MatrixSxD::try_from([vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
         assert_eq!(
-            a.slice_sxs::<{ 0..1 }, { 0..2 }>(),
-            MatrixSxS::from([[&1, &2]])
+            a.slice_sxs::<{ 0..1 }, { 0..2 }>().to_matrix(),
+            MatrixSxS::from([[1, 2]])
         );
     }
     // MatrixSxS
@@ -606,32 +1329,113 @@ mod tests {
     fn sxs_dxd() {
         let a = MatrixSxS::from([[1, 2, 3], [4, 5, 6]]);
         assert_eq!(
-            a.slice_dxd((0..1, 0..2)),
-            MatrixDxD::try_from(vec![vec![&1, &2]]).unwrap()
+            a.slice_dxd((0..1, 0..2)).to_matrix(),
+            MatrixDxD::try_from(vec![vec![1, 2]]).unwrap()
         );
     }
     #[test]
     fn sxs_dxs() {
         let a = MatrixSxS::from([[1, 2, 3], [4, 5, 6]]);
         assert_eq!(
-            a.slice_dxs::<{ 0..2 }>(0..1),
-            MatrixDxS::from(vec![[&1, &2]])
+            a.slice_dxs::<{ 0..2 }>(0..1).to_matrix(),
+            MatrixDxS::from(vec![[1, 2]])
         );
     }
     #[test]
     fn sxs_sxd() {
         let a = MatrixSxS::from([[1, 2, 3], [4, 5, 6]]);
         assert_eq!(
-            a.slice_sxd::<{ 0..1 }>(0..2),
-            MatrixSxD::try_from([vec![&1, &2]]).unwrap()
+            a.slice_sxd::<{ 0..1 }>(0..2).to_matrix(),
+            MatrixSxD::try_from([vec![1, 2]]).unwrap()
         );
     }
     #[test]
     fn sxs_sxs() {
         let a = MatrixSxS::from([[1, 2, 3], [4, 5, 6]]);
         assert_eq!(
-            a.slice_sxs::<{ 0..1 }, { 0..2 }>(),
-            MatrixSxS::from([[&1, &2]])
+            a.slice_sxs::<{ 0..1 }, { 0..2 }>().to_matrix(),
+            MatrixSxS::from([[1, 2]])
+        );
+    }
+    // Strides
+    // --------------------------------------------------
+    #[test]
+    fn strides() {
+        let a = MatrixSxS::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+        let b = a.slice_sxs::<{ 0..2 }, { 1..3 }>();
+        assert_eq!(b.row_stride(), 4);
+        assert_eq!(b.column_stride(), 1);
+        assert!(b.iter().eq([2, 3, 6, 7].iter()));
+        let c = a.slice_dxd((1..2, 0..3));
+        assert_eq!((c.rows(), c.columns()), (1, 3));
+        assert!(c.iter().eq([5, 6, 7].iter()));
+    }
+    // Bounds
+    // --------------------------------------------------
+    #[test]
+    fn full() {
+        let mut a = MatrixSxS::from([[1, 2, 3], [4, 5, 6]]);
+        let b = a.slice_sxs::<{ 0..2 }, { 0..3 }>().to_matrix();
+        assert_eq!(b, a);
+        assert!(a.slice_dxd((0..2, 0..3)).iter().eq(a.iter()));
+        assert_eq!(
+            a.slice_sxd::<{ 1..2 }>(2..3).to_matrix(),
+            MatrixSxD::try_from([vec![6]]).unwrap()
         );
+        a.slice_dxs_mut::<{ 2..3 }>(0..2)[(1, 0)] = 0;
+        assert_eq!(a, MatrixSxS::from([[1, 2, 3], [4, 5, 0]]));
+    }
+    #[test]
+    #[should_panic(expected = "Row out of bounds")]
+    fn dxd_rows_out_of_bounds() {
+        let a = MatrixDxD::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let _ = a.slice_dxd((0..3, 0..3));
+    }
+    #[test]
+    #[should_panic(expected = "Columns out of bounds")]
+    fn sxs_columns_out_of_bounds() {
+        let a = MatrixSxS::from([[1, 2, 3], [4, 5, 6]]);
+        let _ = a.slice_sxd::<{ 0..2 }>(1..4);
+    }
+    // Mutable
+    // --------------------------------------------------
+    #[test]
+    fn dxd_dxd_mut() {
+        let mut a = MatrixDxD::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        for x in a.slice_dxd_mut((0..1, 1..2)).iter_mut() {
+            *x = 0;
+        }
+        assert_eq!(
+            a,
+            MatrixDxD::try_from(vec![vec![1, 0, 3], vec![4, 5, 6]]).unwrap()
+        );
+    }
+    #[test]
+    fn dxs_dxs_mut() {
+        let mut a = MatrixDxS::from(vec![[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let mut b = a.slice_dxs_mut::<{ 0..2 }>(1..2);
+        b[(0, 1)] = 0;
+        assert_eq!(b.view().to_matrix(), MatrixDxS::from(vec![[4, 0]]));
+        assert_eq!(a, MatrixDxS::from(vec![[1, 2, 3], [4, 0, 6], [7, 8, 9]]));
+    }
+    #[test]
+    fn sxd_sxd_mut() {
+        let mut a = MatrixSxD::try_from([vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+        for x in a.slice_sxd_mut::<{ 0..2 }>(1..2).iter_mut() {
+            *x *= 10;
+        }
+        let b = MatrixSxD::try_from([vec![1, 20, 3], vec![4, 50, 6], vec![7, 8, 9]]).unwrap();
+        assert_eq!(a, b);
+    }
+    #[test]
+    fn sxs_sxs_mut() {
+        let mut a = MatrixSxS::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let mut b = a.slice_sxs_mut::<{ 1..2 }, { 0..2 }>();
+        assert_eq!((b.rows(), b.columns(), b.row_stride()), (1, 2, 3));
+        for x in b.iter_mut() {
+            *x = -*x;
+        }
+        assert!(b.iter().eq([-4, -5].iter()));
+        assert_eq!(a, MatrixSxS::from([[1, 2, 3], [-4, -5, 6], [7, 8, 9]]));
     }
 }
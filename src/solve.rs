@@ -0,0 +1,594 @@
+use crate::lu::InternalLu;
+use crate::*;
+use num_traits::Float;
+
+/// Internal linear system solver trait.
+pub trait InternalSolve: Sized {
+    /// Solves `A·X = B` overwriting `a` with its LU factorization and `b` with `X`.
+    fn solve(
+        // An `n` by `n` row-major matrix.
+        a: &mut [Self],
+        // An `n` by `k` row-major matrix.
+        b: &mut [Self],
+        // Rows and columns of `a` and rows of `b`.
+        n: usize,
+        // Columns of `b`.
+        k: usize,
+    ) -> Result<(), &'static str>;
+}
+/// Default linear system solver implementation.
+impl<T: Float> InternalSolve for T {
+    default fn solve(a: &mut [T], b: &mut [T], n: usize, k: usize) -> Result<(), &'static str> {
+        debug_assert_eq!(a.len(), n * n);
+        debug_assert_eq!(b.len(), n * k);
+
+        let mut permutation = vec![0; n];
+        InternalLu::lu(a, &mut permutation, n);
+        if (0..n).any(|i| a[i * n + i] == T::zero()) {
+            return Err("Singular matrix");
+        }
+        // Applies the row permutation to `b`.
+        let permuted = permutation
+            .iter()
+            .flat_map(|p| b[p * k..(p + 1) * k].iter().cloned())
+            .collect::<Vec<_>>();
+        b.copy_from_slice(&permuted);
+        // Forward substitution with the unit lower triangular factor.
+        for i in 0..n {
+            for j in 0..i {
+                let l = a[i * n + j];
+                for c in 0..k {
+                    b[i * k + c] = b[i * k + c] - l * b[j * k + c];
+                }
+            }
+        }
+        // Backward substitution with the upper triangular factor.
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let u = a[i * n + j];
+                for c in 0..k {
+                    b[i * k + c] = b[i * k + c] - u * b[j * k + c];
+                }
+            }
+            let diagonal = a[i * n + i];
+            for c in 0..k {
+                b[i * k + c] = b[i * k + c] / diagonal;
+            }
+        }
+        Ok(())
+    }
+}
+/// `f32` linear system solver specialization.
+#[cfg(feature = "lapacke")]
+impl InternalSolve for f32 {
+    fn solve(a: &mut [f32], b: &mut [f32], n: usize, k: usize) -> Result<(), &'static str> {
+        assert_eq!(a.len(), n * n);
+        assert_eq!(b.len(), n * k);
+        let mut ipiv = vec![Default::default(); n];
+        let info = unsafe {
+            lapacke::sgesv(
+                lapacke::Layout::RowMajor,
+                n as i32,
+                k as i32,
+                a,
+                n as i32,
+                &mut ipiv,
+                b,
+                k as i32,
+            )
+        };
+        assert!(info >= 0, "Illegal `sgesv` argument");
+        match info {
+            0 => Ok(()),
+            _ => Err("Singular matrix"),
+        }
+    }
+}
+/// `f64` linear system solver specialization.
+#[cfg(feature = "lapacke")]
+impl InternalSolve for f64 {
+    fn solve(a: &mut [f64], b: &mut [f64], n: usize, k: usize) -> Result<(), &'static str> {
+        assert_eq!(a.len(), n * n);
+        assert_eq!(b.len(), n * k);
+        let mut ipiv = vec![Default::default(); n];
+        let info = unsafe {
+            lapacke::dgesv(
+                lapacke::Layout::RowMajor,
+                n as i32,
+                k as i32,
+                a,
+                n as i32,
+                &mut ipiv,
+                b,
+                k as i32,
+            )
+        };
+        assert!(info >= 0, "Illegal `dgesv` argument");
+        match info {
+            0 => Ok(()),
+            _ => Err("Singular matrix"),
+        }
+    }
+}
+/// Solves `A·X = B` without modifying `a`, overwriting `b` with `X`.
+fn solve<T: Float>(a: &[T], b: &mut [T], n: usize, k: usize) -> Result<(), &'static str> {
+    let mut a = Vec::from(a);
+    InternalSolve::solve(&mut a, b, n, k)
+}
+
+/// A trait for solving systems of linear equations.
+pub trait Solve<B> {
+    type Output;
+    /// Solves `self·X = B` for `X`, returning an error if `self` is singular.
+    /// ```text
+    /// ┌─────┐       ┌──────┐  ┌──────┐
+    /// │ 2 1 │.solve(│ 3  1 │)=│ 1  1 │
+    /// │ 1 3 │       │ 4 -2 │  │ 1 -1 │
+    /// └─────┘       └──────┘  └──────┘
+    /// ```
+    /// - N: Rows and columns of `self`, rows of `B` and rows of `Self::Output`.
+    /// - K: Columns of `B` and columns of `Self::Output`.
+    fn solve(&self, b: &B) -> Result<Self::Output, &'static str>;
+}
+
+// MatrixDxD
+// --------------------------------------------------
+impl<T: Float, const N: usize, const K: usize> Solve<MatrixSxS<T, N, K>> for MatrixDxD<T>
+where
+    [(); N * K]:,
+{
+    type Output = MatrixSxS<T, N, K>;
+    fn solve(&self, b: &MatrixSxS<T, N, K>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, self.columns, "Non-square matrix");
+        assert_eq!(self.rows, N, "Non-matching rows");
+
+        let mut data = b.data;
+        solve(&self.data, &mut data, N, K)?;
+        Ok(Self::Output {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const K: usize> Solve<MatrixDxS<T, K>> for MatrixDxD<T> {
+    type Output = MatrixDxS<T, K>;
+    fn solve(&self, b: &MatrixDxS<T, K>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, self.columns, "Non-square matrix");
+        assert_eq!(self.rows, b.rows, "Non-matching rows");
+
+        let mut data = b.data.clone();
+        solve(&self.data, &mut data, self.rows, K)?;
+        Ok(Self::Output {
+            data,
+            rows: b.rows,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const N: usize> Solve<MatrixSxD<T, N>> for MatrixDxD<T> {
+    type Output = MatrixSxD<T, N>;
+    fn solve(&self, b: &MatrixSxD<T, N>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, self.columns, "Non-square matrix");
+        assert_eq!(self.rows, N, "Non-matching rows");
+
+        let mut data = b.data.clone();
+        solve(&self.data, &mut data, N, b.columns)?;
+        Ok(Self::Output {
+            data,
+            columns: b.columns,
+            rows: Static,
+        })
+    }
+}
+impl<T: Float> Solve<MatrixDxD<T>> for MatrixDxD<T> {
+    type Output = MatrixDxD<T>;
+    fn solve(&self, b: &MatrixDxD<T>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, self.columns, "Non-square matrix");
+        assert_eq!(self.rows, b.rows, "Non-matching rows");
+
+        let mut data = b.data.clone();
+        solve(&self.data, &mut data, b.rows, b.columns)?;
+        Ok(Self::Output {
+            data,
+            rows: b.rows,
+            columns: b.columns,
+        })
+    }
+}
+// MatrixDxS
+// --------------------------------------------------
+impl<T: Float, const N: usize, const K: usize> Solve<MatrixSxS<T, N, K>> for MatrixDxS<T, N>
+where
+    [(); N * K]:,
+{
+    type Output = MatrixSxS<T, N, K>;
+    fn solve(&self, b: &MatrixSxS<T, N, K>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, N, "Non-square matrix");
+
+        let mut data = b.data;
+        solve(&self.data, &mut data, N, K)?;
+        Ok(Self::Output {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const N: usize, const K: usize> Solve<MatrixDxS<T, K>> for MatrixDxS<T, N>
+where
+    [(); N * K]:,
+{
+    type Output = MatrixSxS<T, N, K>;
+    fn solve(&self, b: &MatrixDxS<T, K>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, N, "Non-square matrix");
+        assert_eq!(N, b.rows, "Non-matching rows");
+
+        let mut data = [T::zero(); N * K];
+        data.copy_from_slice(&b.data);
+        solve(&self.data, &mut data, N, K)?;
+        Ok(Self::Output {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const N: usize> Solve<MatrixSxD<T, N>> for MatrixDxS<T, N> {
+    type Output = MatrixSxD<T, N>;
+    fn solve(&self, b: &MatrixSxD<T, N>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, N, "Non-square matrix");
+
+        let mut data = b.data.clone();
+        solve(&self.data, &mut data, N, b.columns)?;
+        Ok(Self::Output {
+            data,
+            columns: b.columns,
+            rows: Static,
+        })
+    }
+}
+impl<T: Float, const N: usize> Solve<MatrixDxD<T>> for MatrixDxS<T, N> {
+    type Output = MatrixSxD<T, N>;
+    fn solve(&self, b: &MatrixDxD<T>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.rows, N, "Non-square matrix");
+        assert_eq!(N, b.rows, "Non-matching rows");
+
+        let mut data = b.data.clone();
+        solve(&self.data, &mut data, N, b.columns)?;
+        Ok(Self::Output {
+            data,
+            columns: b.columns,
+            rows: Static,
+        })
+    }
+}
+// MatrixSxD
+// --------------------------------------------------
+impl<T: Float, const N: usize, const K: usize> Solve<MatrixSxS<T, N, K>> for MatrixSxD<T, N>
+where
+    [(); N * K]:,
+{
+    type Output = MatrixSxS<T, N, K>;
+    fn solve(&self, b: &MatrixSxS<T, N, K>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.columns, N, "Non-square matrix");
+
+        let mut data = b.data;
+        solve(&self.data, &mut data, N, K)?;
+        Ok(Self::Output {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const N: usize, const K: usize> Solve<MatrixDxS<T, K>> for MatrixSxD<T, N>
+where
+    [(); N * K]:,
+{
+    type Output = MatrixSxS<T, N, K>;
+    fn solve(&self, b: &MatrixDxS<T, K>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.columns, N, "Non-square matrix");
+        assert_eq!(N, b.rows, "Non-matching rows");
+
+        let mut data = [T::zero(); N * K];
+        data.copy_from_slice(&b.data);
+        solve(&self.data, &mut data, N, K)?;
+        Ok(Self::Output {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const N: usize> Solve<MatrixSxD<T, N>> for MatrixSxD<T, N> {
+    type Output = MatrixSxD<T, N>;
+    fn solve(&self, b: &MatrixSxD<T, N>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.columns, N, "Non-square matrix");
+
+        let mut data = b.data.clone();
+        solve(&self.data, &mut data, N, b.columns)?;
+        Ok(Self::Output {
+            data,
+            columns: b.columns,
+            rows: Static,
+        })
+    }
+}
+impl<T: Float, const N: usize> Solve<MatrixDxD<T>> for MatrixSxD<T, N> {
+    type Output = MatrixSxD<T, N>;
+    fn solve(&self, b: &MatrixDxD<T>) -> Result<Self::Output, &'static str> {
+        assert_eq!(self.columns, N, "Non-square matrix");
+        assert_eq!(N, b.rows, "Non-matching rows");
+
+        let mut data = b.data.clone();
+        solve(&self.data, &mut data, N, b.columns)?;
+        Ok(Self::Output {
+            data,
+            columns: b.columns,
+            rows: Static,
+        })
+    }
+}
+// MatrixSxS
+// --------------------------------------------------
+impl<T: Float, const N: usize, const K: usize> Solve<MatrixSxS<T, N, K>> for MatrixSxS<T, N, N>
+where
+    [(); N * N]:,
+    [(); N * K]:,
+{
+    type Output = MatrixSxS<T, N, K>;
+    fn solve(&self, b: &MatrixSxS<T, N, K>) -> Result<Self::Output, &'static str> {
+        let mut data = b.data;
+        solve(&self.data, &mut data, N, K)?;
+        Ok(Self::Output {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const N: usize, const K: usize> Solve<MatrixDxS<T, K>> for MatrixSxS<T, N, N>
+where
+    [(); N * N]:,
+    [(); N * K]:,
+{
+    type Output = MatrixSxS<T, N, K>;
+    fn solve(&self, b: &MatrixDxS<T, K>) -> Result<Self::Output, &'static str> {
+        assert_eq!(N, b.rows, "Non-matching rows");
+
+        let mut data = [T::zero(); N * K];
+        data.copy_from_slice(&b.data);
+        solve(&self.data, &mut data, N, K)?;
+        Ok(Self::Output {
+            data,
+            rows: Static,
+            columns: Static,
+        })
+    }
+}
+impl<T: Float, const N: usize> Solve<MatrixSxD<T, N>> for MatrixSxS<T, N, N>
+where
+    [(); N * N]:,
+{
+    type Output = MatrixSxD<T, N>;
+    fn solve(&self, b: &MatrixSxD<T, N>) -> Result<Self::Output, &'static str> {
+        let mut data = b.data.clone();
+        solve(&self.data, &mut data, N, b.columns)?;
+        Ok(Self::Output {
+            data,
+            columns: b.columns,
+            rows: Static,
+        })
+    }
+}
+impl<T: Float, const N: usize> Solve<MatrixDxD<T>> for MatrixSxS<T, N, N>
+where
+    [(); N * N]:,
+{
+    type Output = MatrixSxD<T, N>;
+    fn solve(&self, b: &MatrixDxD<T>) -> Result<Self::Output, &'static str> {
+        assert_eq!(N, b.rows, "Non-matching rows");
+
+        let mut data = b.data.clone();
+        solve(&self.data, &mut data, N, b.columns)?;
+        Ok(Self::Output {
+            data,
+            columns: b.columns,
+            rows: Static,
+        })
+    }
+}
+// Tests
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::convert::TryFrom;
+    // f32
+    // --------------------------------------------------
+    #[test]
+    fn f32_dxd() {
+        let a = MatrixDxD::<f32>::try_from(vec![vec![2., 1.], vec![1., 3.]]).unwrap();
+        let b = MatrixDxD::<f32>::try_from(vec![vec![3., 1.], vec![4., -2.]]).unwrap();
+        let c = a.solve(&b).unwrap();
+        let d = MatrixDxD::<f32>::try_from(vec![vec![1., 1.], vec![1., -1.]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn f32_singular() {
+        let a = MatrixDxD::<f32>::try_from(vec![vec![1., 2.], vec![2., 4.]]).unwrap();
+        let b = MatrixDxD::<f32>::try_from(vec![vec![1.], vec![2.]]).unwrap();
+        assert_eq!(a.solve(&b), Err("Singular matrix"));
+    }
+    // f64
+    // --------------------------------------------------
+    #[test]
+    fn f64_dxd() {
+        let a = MatrixDxD::<f64>::try_from(vec![vec![2., 1.], vec![1., 3.]]).unwrap();
+        let b = MatrixDxD::<f64>::try_from(vec![vec![3., 1.], vec![4., -2.]]).unwrap();
+        let c = a.solve(&b).unwrap();
+        let d = MatrixDxD::<f64>::try_from(vec![vec![1., 1.], vec![1., -1.]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn f64_singular() {
+        let a = MatrixSxS::<f64, 2, 2>::from([[1., 2.], [2., 4.]]);
+        let b = MatrixSxS::<f64, 2, 1>::from([[1.], [2.]]);
+        assert_eq!(a.solve(&b), Err("Singular matrix"));
+    }
+    // f16
+    // --------------------------------------------------
+    #[test]
+    fn f16_sxs() {
+        let f = half::f16::from_f32;
+        let a = MatrixSxS::<half::f16, 2, 2>::from([[f(2.), f(1.)], [f(1.), f(3.)]]);
+        let b = MatrixSxS::<half::f16, 2, 2>::from([[f(3.), f(1.)], [f(4.), f(-2.)]]);
+        let c = a.solve(&b).unwrap();
+        let d = MatrixSxS::<half::f16, 2, 2>::from([[f(1.), f(1.)], [f(1.), f(-1.)]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn f16_singular() {
+        let f = half::f16::from_f32;
+        let a = MatrixSxS::<half::f16, 2, 2>::from([[f(1.), f(2.)], [f(2.), f(4.)]]);
+        let b = MatrixSxS::<half::f16, 2, 1>::from([[f(1.)], [f(2.)]]);
+        assert_eq!(a.solve(&b), Err("Singular matrix"));
+    }
+    // MatrixDxD
+    // --------------------------------------------------
+    #[test]
+    fn dxd_dxd() {
+        let a = MatrixDxD::try_from(vec![vec![2., 1.], vec![1., 3.]]).unwrap();
+        let b = MatrixDxD::try_from(vec![vec![3., 1.], vec![4., -2.]]).unwrap();
+        let c = a.solve(&b).unwrap();
+        let d = MatrixDxD::try_from(vec![vec![1., 1.], vec![1., -1.]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn dxd_dxs() {
+        let a = MatrixDxD::try_from(vec![vec![2., 1.], vec![1., 3.]]).unwrap();
+        let b = MatrixDxS::from(vec![[3., 1.], [4., -2.]]);
+        let c = a.solve(&b).unwrap();
+        let d = MatrixDxS::from(vec![[1., 1.], [1., -1.]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn dxd_sxd() {
+        let a = MatrixDxD::try_from(vec![vec![2., 1.], vec![1., 3.]]).unwrap();
+        let b = MatrixSxD::try_from([vec![3., 1.], vec![4., -2.]]).unwrap();
+        let c = a.solve(&b).unwrap();
+        let d = MatrixSxD::try_from([vec![1., 1.], vec![1., -1.]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn dxd_sxs() {
+        let a = MatrixDxD::try_from(vec![vec![2., 1.], vec![1., 3.]]).unwrap();
+        let b = MatrixSxS::from([[3., 1.], [4., -2.]]);
+        let c = a.solve(&b).unwrap();
+        let d = MatrixSxS::from([[1., 1.], [1., -1.]]);
+        assert_eq!(c, d);
+    }
+    // MatrixDxS
+    // --------------------------------------------------
+    #[test]
+    fn dxs_dxd() {
+        let a = MatrixDxS::from(vec![[2., 1.], [1., 3.]]);
+        let b = MatrixDxD::try_from(vec![vec![3., 1.], vec![4., -2.]]).unwrap();
+        let c = a.solve(&b).unwrap();
+        let d = MatrixSxD::try_from([vec![1., 1.], vec![1., -1.]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn dxs_dxs() {
+        let a = MatrixDxS::from(vec![[2., 1.], [1., 3.]]);
+        let b = MatrixDxS::from(vec![[3., 1.], [4., -2.]]);
+        let c = a.solve(&b).unwrap();
+        let d = MatrixSxS::from([[1., 1.], [1., -1.]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn dxs_sxd() {
+        let a = MatrixDxS::from(vec![[2., 1.], [1., 3.]]);
+        let b = MatrixSxD::try_from([vec![3., 1.], vec![4., -2.]]).unwrap();
+        let c = a.solve(&b).unwrap();
+        let d = MatrixSxD::try_from([vec![1., 1.], vec![1., -1.]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn dxs_sxs() {
+        let a = MatrixDxS::from(vec![[2., 1.], [1., 3.]]);
+        let b = MatrixSxS::from([[3., 1.], [4., -2.]]);
+        let c = a.solve(&b).unwrap();
+        let d = MatrixSxS::from([[1., 1.], [1., -1.]]);
+        assert_eq!(c, d);
+    }
+    // MatrixSxD
+    // --------------------------------------------------
+    #[test]
+    fn sxd_dxd() {
+        let a = MatrixSxD::try_from([vec![2., 1.], vec![1., 3.]]).unwrap();
+        let b = MatrixDxD::try_from(vec![vec![3., 1.], vec![4., -2.]]).unwrap();
+        let c = a.solve(&b).unwrap();
+        let d = MatrixSxD::try_from([vec![1., 1.], vec![1., -1.]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn sxd_dxs() {
+        let a = MatrixSxD::try_from([vec![2., 1.], vec![1., 3.]]).unwrap();
+        let b = MatrixDxS::from(vec![[3., 1.], [4., -2.]]);
+        let c = a.solve(&b).unwrap();
+        let d = MatrixSxS::from([[1., 1.], [1., -1.]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn sxd_sxd() {
+        let a = MatrixSxD::try_from([vec![2., 1.], vec![1., 3.]]).unwrap();
+        let b = MatrixSxD::try_from([vec![3., 1.], vec![4., -2.]]).unwrap();
+        let c = a.solve(&b).unwrap();
+        let d = MatrixSxD::try_from([vec![1., 1.], vec![1., -1.]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn sxd_sxs() {
+        let a = MatrixSxD::try_from([vec![2., 1.], vec![1., 3.]]).unwrap();
+        let b = MatrixSxS::from([[3., 1.], [4., -2.]]);
+        let c = a.solve(&b).unwrap();
+        let d = MatrixSxS::from([[1., 1.], [1., -1.]]);
+        assert_eq!(c, d);
+    }
+    // MatrixSxS
+    // --------------------------------------------------
+    #[test]
+    fn sxs_dxd() {
+        let a = MatrixSxS::from([[2., 1.], [1., 3.]]);
+        let b = MatrixDxD::try_from(vec![vec![3., 1.], vec![4., -2.]]).unwrap();
+        let c = a.solve(&b).unwrap();
+        let d = MatrixSxD::try_from([vec![1., 1.], vec![1., -1.]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn sxs_dxs() {
+        let a = MatrixSxS::from([[2., 1.], [1., 3.]]);
+        let b = MatrixDxS::from(vec![[3., 1.], [4., -2.]]);
+        let c = a.solve(&b).unwrap();
+        let d = MatrixSxS::from([[1., 1.], [1., -1.]]);
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn sxs_sxd() {
+        let a = MatrixSxS::from([[2., 1.], [1., 3.]]);
+        let b = MatrixSxD::try_from([vec![3., 1.], vec![4., -2.]]).unwrap();
+        let c = a.solve(&b).unwrap();
+        let d = MatrixSxD::try_from([vec![1., 1.], vec![1., -1.]]).unwrap();
+        assert_eq!(c, d);
+    }
+    #[test]
+    fn sxs_sxs() {
+        let a = MatrixSxS::<f64, 2, 2>::from([[2., 1.], [1., 3.]]);
+        let b = MatrixSxS::<f64, 2, 2>::from([[3., 1.], [4., -2.]]);
+        let c = a.solve(&b).unwrap();
+        let d = MatrixSxS::<f64, 2, 2>::from([[1., 1.], [1., -1.]]);
+        assert_eq!(c, d);
+    }
+}
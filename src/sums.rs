@@ -120,3 +120,58 @@ where
         RowVectorS::from([sums])
     }
 }
+impl<T: std::iter::Sum<T> + Clone, R: Dim, C: Dim> Matrix<T, R, C, ColumnMajor>
+where
+    (R, C, ColumnMajor): Storage<T>,
+{
+    /// Gets sum of all elements.
+    pub fn sum(&self) -> T {
+        self.data.as_ref().iter().cloned().sum()
+    }
+    /// Gets the sum of each row.
+    pub fn row_sum(&self) -> Matrix<T, R, Static<1>>
+    where
+        (R, Static<1>, RowMajor): Storage<T>,
+    {
+        let (rows, columns) = self.shape();
+        let data = self.data.as_ref();
+        Matrix {
+            data: <(R, Static<1>, RowMajor)>::collect((0..rows).map(|i| {
+                data.iter()
+                    .skip(i)
+                    .step_by(rows)
+                    .take(columns)
+                    .cloned()
+                    .sum()
+            })),
+            rows: self.rows,
+            columns: Static,
+        }
+    }
+    /// Gets the sum of each column.
+    /// ```
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use static_la::*;
+    /// use std::convert::TryFrom;
+    /// let a = MatrixDxS::from(vec![[1, 2], [3, 4], [5, 6]]).transpose();
+    /// assert_eq!(a.column_sum(), RowVectorD::try_from([vec![3, 7, 11]]).unwrap());
+    /// assert_eq!(a.row_sum(), ColumnVectorS::from([[9], [12]]));
+    /// ```
+    pub fn column_sum(&self) -> Matrix<T, Static<1>, C>
+    where
+        (Static<1>, C, RowMajor): Storage<T>,
+    {
+        let rows = self.shape().0;
+        Matrix {
+            data: <(Static<1>, C, RowMajor)>::collect(
+                self.data
+                    .as_ref()
+                    .chunks_exact(rows.max(1))
+                    .map(|c| c.iter().cloned().sum()),
+            ),
+            rows: Static,
+            columns: self.columns,
+        }
+    }
+}
@@ -0,0 +1,757 @@
+use crate::*;
+use num_traits::Float;
+
+/// The smaller of `a` and `b`, used for const singular value decomposition sizing.
+///
+/// Exported so generic code can name `{ min(M, N) }` in output types and bounds.
+pub const fn min(a: usize, b: usize) -> usize {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Internal singular value decomposition trait.
+pub trait InternalSvd: Sized {
+    /// Factors `a` such that `A = U·diag(s)·Vᵀ` with `s` in descending order, overwriting `a`.
+    ///
+    /// Where `k = min(m, n)`, when `thin` `u` is `m` by `k` and `vt` is `k` by `n`, otherwise
+    ///  `u` is `m` by `m` and `vt` is `n` by `n`.
+    fn svd(
+        // An `m` by `n` row-major matrix.
+        a: &mut [Self],
+        // A `k` element vector.
+        s: &mut [Self],
+        // An `m` by `m` or `m` by `k` row-major matrix.
+        u: &mut [Self],
+        // An `n` by `n` or `k` by `n` row-major matrix.
+        vt: &mut [Self],
+        // Rows of `a`.
+        m: usize,
+        // Columns of `a`.
+        n: usize,
+        // Whether to compute the thin decomposition.
+        thin: bool,
+    );
+}
+/// Default singular value decomposition implementation (one-sided Jacobi).
+impl<T: Float> InternalSvd for T {
+    default fn svd(
+        a: &mut [T],
+        s: &mut [T],
+        u: &mut [T],
+        vt: &mut [T],
+        m: usize,
+        n: usize,
+        thin: bool,
+    ) {
+        let k = m.min(n);
+        debug_assert_eq!(a.len(), m * n);
+        debug_assert_eq!(s.len(), k);
+        debug_assert_eq!(u.len(), if thin { m * k } else { m * m });
+        debug_assert_eq!(vt.len(), if thin { k * n } else { n * n });
+
+        // Orthogonalizes the columns of `w`, a `p` by `q` row-major matrix where `p >= q`, so
+        //  `W = L·diag(σ)·Rᵀ` where `L` is `p` by `p` and `R` is `q` by `q`.
+        let transposed = m < n;
+        let (p, q) = if transposed { (n, m) } else { (m, n) };
+        let mut w = if transposed {
+            (0..n * m)
+                .map(|i| a[(i % m) * n + i / m])
+                .collect::<Vec<_>>()
+        } else {
+            Vec::from(&*a)
+        };
+        let mut r = identity(q);
+        let two = T::one() + T::one();
+        for _ in 0..JACOBI_SWEEPS {
+            let mut rotated = false;
+            for i in 0..q {
+                for j in i + 1..q {
+                    let (alpha, beta, gamma) = (0..p).fold(
+                        (T::zero(), T::zero(), T::zero()),
+                        |(alpha, beta, gamma), x| {
+                            let (wi, wj) = (w[x * q + i], w[x * q + j]);
+                            (alpha + wi * wi, beta + wj * wj, gamma + wi * wj)
+                        },
+                    );
+                    if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    // The rotation orthogonalizing columns `i` and `j`.
+                    let zeta = (beta - alpha) / (two * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (zeta * zeta + T::one()).sqrt());
+                    let c = T::one() / (t * t + T::one()).sqrt();
+                    let s = t * c;
+                    for (z, d) in [(&mut w, p), (&mut r, q)] {
+                        for x in 0..d {
+                            let (zi, zj) = (z[x * q + i], z[x * q + j]);
+                            z[x * q + i] = c * zi - s * zj;
+                            z[x * q + j] = s * zi + c * zj;
+                        }
+                    }
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+        // Sorts by descending singular value.
+        let sigma = (0..q)
+            .map(|j| {
+                (0..p)
+                    .fold(T::zero(), |s, x| s + w[x * q + j] * w[x * q + j])
+                    .sqrt()
+            })
+            .collect::<Vec<_>>();
+        let mut order = (0..q).collect::<Vec<_>>();
+        order.sort_by(|&i, &j| {
+            sigma[j]
+                .partial_cmp(&sigma[i])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let largest = order.first().map_or(T::zero(), |&o| sigma[o]);
+        let tolerance = T::epsilon() * T::from(p).unwrap() * largest;
+        let rank = order.iter().filter(|&&j| sigma[j] > tolerance).count();
+        let mut left = vec![T::zero(); p * p];
+        for (i, &o) in order.iter().enumerate().take(rank) {
+            for x in 0..p {
+                left[x * p + i] = w[x * q + o] / sigma[o];
+            }
+        }
+        complete(&mut left, p, rank);
+        for (i, &o) in order.iter().enumerate() {
+            s[i] = sigma[o];
+        }
+        // Where `A = W` then `U = L` and `Vᵀ = Rᵀ`, where `A = Wᵀ` then `U = R` and `Vᵀ = Lᵀ`.
+        let (u_columns, vt_rows) = if thin { (k, k) } else { (m, n) };
+        for x in 0..m {
+            for i in 0..u_columns {
+                u[x * u_columns + i] = if transposed {
+                    r[x * q + order[i]]
+                } else {
+                    left[x * p + i]
+                };
+            }
+        }
+        for i in 0..vt_rows {
+            for x in 0..n {
+                vt[i * n + x] = if transposed {
+                    left[x * p + i]
+                } else {
+                    r[x * q + order[i]]
+                };
+            }
+        }
+    }
+}
+/// Maximum number of Jacobi sweeps, convergence is quadratic so this is rarely approached.
+const JACOBI_SWEEPS: usize = 64;
+/// An `n` by `n` identity matrix.
+fn identity<T: Float>(n: usize) -> Vec<T> {
+    (0..n * n)
+        .map(|i| {
+            if i % (n + 1) == 0 {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+        .collect()
+}
+/// Fills columns `r..p` of the `p` by `p` matrix `a`, whose first `r` columns are
+///  orthonormal, to make `a` orthogonal.
+fn complete<T: Float>(a: &mut [T], p: usize, r: usize) {
+    for c in r..p {
+        // The unit vector with the largest component orthogonal to the first `c` columns.
+        let (_, column) = (0..p)
+            .map(|e| {
+                let mut x = vec![T::zero(); p];
+                x[e] = T::one();
+                // Orthogonalizing twice for numerical stability.
+                for _ in 0..2 {
+                    for j in 0..c {
+                        let d = (0..p).fold(T::zero(), |s, i| s + a[i * p + j] * x[i]);
+                        for (i, y) in x.iter_mut().enumerate() {
+                            *y = *y - d * a[i * p + j];
+                        }
+                    }
+                }
+                let norm = x.iter().fold(T::zero(), |s, y| s + *y * *y).sqrt();
+                (norm, x)
+            })
+            .fold((T::neg_infinity(), Vec::new()), |best, x| {
+                if x.0 > best.0 {
+                    x
+                } else {
+                    best
+                }
+            });
+        let norm = column.iter().fold(T::zero(), |s, y| s + *y * *y).sqrt();
+        for (i, y) in column.iter().enumerate() {
+            a[i * p + c] = *y / norm;
+        }
+    }
+}
+/// `f32` singular value decomposition specialization.
+#[cfg(feature = "lapacke")]
+impl InternalSvd for f32 {
+    fn svd(
+        a: &mut [f32],
+        s: &mut [f32],
+        u: &mut [f32],
+        vt: &mut [f32],
+        m: usize,
+        n: usize,
+        thin: bool,
+    ) {
+        let k = m.min(n);
+        assert_eq!(a.len(), m * n);
+        assert_eq!(s.len(), k);
+        let (jobz, u_columns) = if thin { (b'S', k) } else { (b'A', m) };
+        let info = unsafe {
+            lapacke::sgesdd(
+                lapacke::Layout::RowMajor,
+                jobz,
+                m as i32,
+                n as i32,
+                a,
+                n as i32,
+                s,
+                u,
+                u_columns as i32,
+                vt,
+                n as i32,
+            )
+        };
+        assert!(info >= 0, "Illegal `sgesdd` argument");
+        assert_eq!(info, 0, "Singular value decomposition failed to converge");
+    }
+}
+/// `f64` singular value decomposition specialization.
+#[cfg(feature = "lapacke")]
+impl InternalSvd for f64 {
+    fn svd(
+        a: &mut [f64],
+        s: &mut [f64],
+        u: &mut [f64],
+        vt: &mut [f64],
+        m: usize,
+        n: usize,
+        thin: bool,
+    ) {
+        let k = m.min(n);
+        assert_eq!(a.len(), m * n);
+        assert_eq!(s.len(), k);
+        let (jobz, u_columns) = if thin { (b'S', k) } else { (b'A', m) };
+        let info = unsafe {
+            lapacke::dgesdd(
+                lapacke::Layout::RowMajor,
+                jobz,
+                m as i32,
+                n as i32,
+                a,
+                n as i32,
+                s,
+                u,
+                u_columns as i32,
+                vt,
+                n as i32,
+            )
+        };
+        assert!(info >= 0, "Illegal `dgesdd` argument");
+        assert_eq!(info, 0, "Singular value decomposition failed to converge");
+    }
+}
+
+/// A singular value decomposition such that `A = U·diag(s)·Vᵀ`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svd<U, S, Vt> {
+    /// Left singular vectors as columns.
+    pub u: U,
+    /// Singular values in descending order.
+    pub s: S,
+    /// Right singular vectors as rows.
+    pub vt: Vt,
+}
+
+// MatrixDxD
+// --------------------------------------------------
+impl<T: Float> MatrixDxD<T> {
+    /// Computes the full singular value decomposition of `self`.
+    /// ```
+    /// use static_la::MatrixDxD;
+    /// use std::convert::TryFrom;
+    /// let a = MatrixDxD::try_from(vec![vec![3., 0.], vec![0., -2.], vec![0., 0.]]).unwrap();
+    /// let svd = a.svd();
+    /// assert_eq!((svd.u.rows(), svd.u.columns()), (3, 3));
+    /// assert_eq!(svd.s.rows(), 2);
+    /// assert_eq!((svd.vt.rows(), svd.vt.columns()), (2, 2));
+    /// ```
+    pub fn svd(&self) -> Svd<MatrixDxD<T>, ColumnVectorD<T>, MatrixDxD<T>> {
+        let (m, n) = (self.rows, self.columns);
+        let k = m.min(n);
+        let mut a = self.data.clone();
+        let (mut s, mut u, mut vt) = (
+            vec![T::zero(); k],
+            vec![T::zero(); m * m],
+            vec![T::zero(); n * n],
+        );
+        InternalSvd::svd(&mut a, &mut s, &mut u, &mut vt, m, n, false);
+        Svd {
+            u: MatrixDxD {
+                data: u,
+                rows: m,
+                columns: m,
+            },
+            s: ColumnVectorD {
+                data: s,
+                rows: k,
+                columns: Static,
+            },
+            vt: MatrixDxD {
+                data: vt,
+                rows: n,
+                columns: n,
+            },
+        }
+    }
+    /// Computes the thin singular value decomposition of `self`.
+    ///
+    /// Where `k = min(rows, columns)`, `U` is `rows` by `k` and `Vᵀ` is `k` by `columns`.
+    pub fn svd_thin(&self) -> Svd<MatrixDxD<T>, ColumnVectorD<T>, MatrixDxD<T>> {
+        let (m, n) = (self.rows, self.columns);
+        let k = m.min(n);
+        let mut a = self.data.clone();
+        let (mut s, mut u, mut vt) = (
+            vec![T::zero(); k],
+            vec![T::zero(); m * k],
+            vec![T::zero(); k * n],
+        );
+        InternalSvd::svd(&mut a, &mut s, &mut u, &mut vt, m, n, true);
+        Svd {
+            u: MatrixDxD {
+                data: u,
+                rows: m,
+                columns: k,
+            },
+            s: ColumnVectorD {
+                data: s,
+                rows: k,
+                columns: Static,
+            },
+            vt: MatrixDxD {
+                data: vt,
+                rows: k,
+                columns: n,
+            },
+        }
+    }
+}
+// MatrixDxS
+// --------------------------------------------------
+impl<T: Float, const N: usize> MatrixDxS<T, N>
+where
+    [(); N * N]:,
+{
+    /// Computes the full singular value decomposition of `self`.
+    pub fn svd(&self) -> Svd<MatrixDxD<T>, ColumnVectorD<T>, MatrixSxS<T, N, N>> {
+        let m = self.rows;
+        let k = m.min(N);
+        let mut a = self.data.clone();
+        let (mut s, mut u, mut vt) = (
+            vec![T::zero(); k],
+            vec![T::zero(); m * m],
+            [T::zero(); N * N],
+        );
+        InternalSvd::svd(&mut a, &mut s, &mut u, &mut vt, m, N, false);
+        Svd {
+            u: MatrixDxD {
+                data: u,
+                rows: m,
+                columns: m,
+            },
+            s: ColumnVectorD {
+                data: s,
+                rows: k,
+                columns: Static,
+            },
+            vt: MatrixSxS {
+                data: vt,
+                rows: Static,
+                columns: Static,
+            },
+        }
+    }
+}
+impl<T: Float, const N: usize> MatrixDxS<T, N> {
+    /// Computes the thin singular value decomposition of `self`.
+    ///
+    /// Where `k = min(rows, N)`, `U` is `rows` by `k` and `Vᵀ` is `k` by `N`.
+    pub fn svd_thin(&self) -> Svd<MatrixDxD<T>, ColumnVectorD<T>, MatrixDxS<T, N>> {
+        let m = self.rows;
+        let k = m.min(N);
+        let mut a = self.data.clone();
+        let (mut s, mut u, mut vt) = (
+            vec![T::zero(); k],
+            vec![T::zero(); m * k],
+            vec![T::zero(); k * N],
+        );
+        InternalSvd::svd(&mut a, &mut s, &mut u, &mut vt, m, N, true);
+        Svd {
+            u: MatrixDxD {
+                data: u,
+                rows: m,
+                columns: k,
+            },
+            s: ColumnVectorD {
+                data: s,
+                rows: k,
+                columns: Static,
+            },
+            vt: MatrixDxS {
+                data: vt,
+                rows: k,
+                columns: Static,
+            },
+        }
+    }
+}
+// MatrixSxD
+// --------------------------------------------------
+impl<T: Float, const M: usize> MatrixSxD<T, M>
+where
+    [(); M * M]:,
+{
+    /// Computes the full singular value decomposition of `self`.
+    pub fn svd(&self) -> Svd<MatrixSxS<T, M, M>, ColumnVectorD<T>, MatrixDxD<T>> {
+        let n = self.columns;
+        let k = n.min(M);
+        let mut a = self.data.clone();
+        let (mut s, mut u, mut vt) = (
+            vec![T::zero(); k],
+            [T::zero(); M * M],
+            vec![T::zero(); n * n],
+        );
+        InternalSvd::svd(&mut a, &mut s, &mut u, &mut vt, M, n, false);
+        Svd {
+            u: MatrixSxS {
+                data: u,
+                rows: Static,
+                columns: Static,
+            },
+            s: ColumnVectorD {
+                data: s,
+                rows: k,
+                columns: Static,
+            },
+            vt: MatrixDxD {
+                data: vt,
+                rows: n,
+                columns: n,
+            },
+        }
+    }
+}
+impl<T: Float, const M: usize> MatrixSxD<T, M> {
+    /// Computes the thin singular value decomposition of `self`.
+    ///
+    /// Where `k = min(M, columns)`, `U` is `M` by `k` and `Vᵀ` is `k` by `columns`.
+    pub fn svd_thin(&self) -> Svd<MatrixSxD<T, M>, ColumnVectorD<T>, MatrixDxD<T>> {
+        let n = self.columns;
+        let k = n.min(M);
+        let mut a = self.data.clone();
+        let (mut s, mut u, mut vt) = (
+            vec![T::zero(); k],
+            vec![T::zero(); M * k],
+            vec![T::zero(); k * n],
+        );
+        InternalSvd::svd(&mut a, &mut s, &mut u, &mut vt, M, n, true);
+        Svd {
+            u: MatrixSxD {
+                data: u,
+                columns: k,
+                rows: Static,
+            },
+            s: ColumnVectorD {
+                data: s,
+                rows: k,
+                columns: Static,
+            },
+            vt: MatrixDxD {
+                data: vt,
+                rows: k,
+                columns: n,
+            },
+        }
+    }
+}
+// MatrixSxS
+// --------------------------------------------------
+impl<T: Float, const M: usize, const N: usize> MatrixSxS<T, M, N>
+where
+    [(); M * N]:,
+    [(); M * M]:,
+    [(); N * N]:,
+    [(); min(M, N) * 1]:,
+{
+    /// Computes the full singular value decomposition of `self`.
+    /// ```
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use static_la::MatrixSxS;
+    /// let a = MatrixSxS::<f64, 3, 2>::from([[3., 0.], [0., -2.], [0., 0.]]);
+    /// // `Svd<MatrixSxS<f64, 3, 3>, ColumnVectorS<f64, 2>, MatrixSxS<f64, 2, 2>>`
+    /// let svd = a.svd();
+    /// assert!((svd.s[(0, 0)] - 3.).abs() < 1e-12);
+    /// assert!((svd.s[(1, 0)] - 2.).abs() < 1e-12);
+    /// ```
+    pub fn svd(
+        &self,
+    ) -> Svd<MatrixSxS<T, M, M>, ColumnVectorS<T, { min(M, N) }>, MatrixSxS<T, N, N>> {
+        let mut a = self.data;
+        let (mut s, mut u, mut vt) = (
+            [T::zero(); min(M, N) * 1],
+            [T::zero(); M * M],
+            [T::zero(); N * N],
+        );
+        InternalSvd::svd(&mut a, &mut s, &mut u, &mut vt, M, N, false);
+        Svd {
+            u: MatrixSxS {
+                data: u,
+                rows: Static,
+                columns: Static,
+            },
+            s: MatrixSxS {
+                data: s,
+                rows: Static,
+                columns: Static,
+            },
+            vt: MatrixSxS {
+                data: vt,
+                rows: Static,
+                columns: Static,
+            },
+        }
+    }
+}
+impl<T: Float, const M: usize, const N: usize> MatrixSxS<T, M, N>
+where
+    [(); M * N]:,
+    [(); M * min(M, N)]:,
+    [(); min(M, N) * N]:,
+    [(); min(M, N) * 1]:,
+{
+    /// Computes the thin singular value decomposition of `self`.
+    ///
+    /// Where `K = min(M, N)`, `U` is `M` by `K` and `Vᵀ` is `K` by `N`.
+    pub fn svd_thin(
+        &self,
+    ) -> Svd<
+        MatrixSxS<T, M, { min(M, N) }>,
+        ColumnVectorS<T, { min(M, N) }>,
+        MatrixSxS<T, { min(M, N) }, N>,
+    > {
+        let mut a = self.data;
+        let (mut s, mut u, mut vt) = (
+            [T::zero(); min(M, N) * 1],
+            [T::zero(); M * min(M, N)],
+            [T::zero(); min(M, N) * N],
+        );
+        InternalSvd::svd(&mut a, &mut s, &mut u, &mut vt, M, N, true);
+        Svd {
+            u: MatrixSxS {
+                data: u,
+                rows: Static,
+                columns: Static,
+            },
+            s: MatrixSxS {
+                data: s,
+                rows: Static,
+                columns: Static,
+            },
+            vt: MatrixSxS {
+                data: vt,
+                rows: Static,
+                columns: Static,
+            },
+        }
+    }
+}
+
+// Tests
+// --------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::convert::TryFrom;
+
+    /// Checks `A = U·diag(s)·Vᵀ`, that `U` and `Vᵀ` have orthonormal columns and rows, and
+    ///  that `s` is descending.
+    fn check<T: num_traits::Float + std::fmt::Debug>(
+        a: &[T],
+        (u, s, vt): (&[T], &[T], &[T]),
+        m: usize,
+        n: usize,
+    ) {
+        let k = s.len();
+        let (uc, vr) = (u.len() / m, vt.len() / n);
+        let mut usv = vec![T::zero(); m * n];
+        for i in 0..m {
+            for j in 0..n {
+                usv[i * n + j] =
+                    (0..k).fold(T::zero(), |x, l| x + u[i * uc + l] * s[l] * vt[l * n + j]);
+            }
+        }
+        assert_approx_eq(a, &usv);
+        let utu = (0..uc * uc)
+            .map(|x| {
+                (0..m).fold(T::zero(), |y, l| {
+                    y + u[l * uc + x / uc] * u[l * uc + x % uc]
+                })
+            })
+            .collect::<Vec<_>>();
+        let vvt = (0..vr * vr)
+            .map(|x| {
+                (0..n).fold(T::zero(), |y, l| {
+                    y + vt[x / vr * n + l] * vt[x % vr * n + l]
+                })
+            })
+            .collect::<Vec<_>>();
+        assert_approx_eq(&utu, &identity(uc));
+        assert_approx_eq(&vvt, &identity(vr));
+        assert!(s.windows(2).all(|x| x[0] >= x[1]));
+    }
+    fn identity<T: num_traits::Float>(n: usize) -> Vec<T> {
+        (0..n * n)
+            .map(|i| {
+                if i % (n + 1) == 0 {
+                    T::one()
+                } else {
+                    T::zero()
+                }
+            })
+            .collect()
+    }
+    /// Singular values `2` and `√2`.
+    const A: [[f64; 2]; 3] = [[1., 1.], [1., -1.], [1., 1.]];
+    fn values() -> [f64; 2] {
+        [2., 2f64.sqrt()]
+    }
+
+    // f32
+    // --------------------------------------------------
+    #[test]
+    fn f32() {
+        let a = MatrixSxS::<f32, 3, 2>::from(A.map(|r| r.map(|x| x as f32)));
+        let svd = a.svd();
+        check(&a.data, (&svd.u.data, &svd.s.data, &svd.vt.data), 3, 2);
+        assert_approx_eq(&svd.s.data, &values().map(|x| x as f32));
+    }
+    // f16
+    // --------------------------------------------------
+    #[test]
+    fn f16() {
+        let f = half::f16::from_f64;
+        let a = MatrixSxS::<half::f16, 3, 2>::from(A.map(|r| r.map(f)));
+        let svd = a.svd();
+        check(&a.data, (&svd.u.data, &svd.s.data, &svd.vt.data), 3, 2);
+        assert_approx_eq(&svd.s.data, &values().map(f));
+    }
+    #[test]
+    fn f16_wide() {
+        let f = half::f16::from_f64;
+        let a = MatrixSxS::<half::f16, 2, 3>::from([[f(1.), f(2.), f(1.)], [f(2.), f(1.), f(2.)]]);
+        let svd = a.svd();
+        check(&a.data, (&svd.u.data, &svd.s.data, &svd.vt.data), 2, 3);
+        let svd = a.svd_thin();
+        check(&a.data, (&svd.u.data, &svd.s.data, &svd.vt.data), 2, 3);
+    }
+    #[test]
+    fn f16_rank_deficient() {
+        let f = half::f16::from_f64;
+        let a =
+            MatrixSxS::<half::f16, 3, 2>::from([[f(1.), f(2.)], [f(2.), f(4.)], [f(3.), f(6.)]]);
+        let svd = a.svd();
+        check(&a.data, (&svd.u.data, &svd.s.data, &svd.vt.data), 3, 2);
+    }
+    // MatrixDxD
+    // --------------------------------------------------
+    #[test]
+    fn dxd() {
+        let a = MatrixDxD::try_from(A.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let svd = a.svd();
+        assert_eq!((svd.u.rows(), svd.u.columns()), (3, 3));
+        assert_eq!((svd.vt.rows(), svd.vt.columns()), (2, 2));
+        check(&a.data, (&svd.u.data, &svd.s.data, &svd.vt.data), 3, 2);
+        assert_approx_eq(&svd.s.data, &values());
+    }
+    #[test]
+    fn dxd_thin() {
+        let a = MatrixDxD::try_from(A.iter().map(|r| r.to_vec()).collect::<Vec<_>>()).unwrap();
+        let svd = a.svd_thin();
+        assert_eq!((svd.u.rows(), svd.u.columns()), (3, 2));
+        assert_eq!((svd.vt.rows(), svd.vt.columns()), (2, 2));
+        check(&a.data, (&svd.u.data, &svd.s.data, &svd.vt.data), 3, 2);
+    }
+    // MatrixDxS
+    // --------------------------------------------------
+    #[test]
+    fn dxs() {
+        let a = MatrixDxS::from(A.to_vec());
+        let svd = a.svd();
+        let _: &MatrixSxS<f64, 2, 2> = &svd.vt;
+        check(&a.data, (&svd.u.data, &svd.s.data, &svd.vt.data), 3, 2);
+    }
+    #[test]
+    fn dxs_thin() {
+        let a = MatrixDxS::from(A.to_vec());
+        let svd = a.svd_thin();
+        let _: &MatrixDxS<f64, 2> = &svd.vt;
+        assert_eq!(svd.u.columns(), 2);
+        check(&a.data, (&svd.u.data, &svd.s.data, &svd.vt.data), 3, 2);
+    }
+    // MatrixSxD
+    // --------------------------------------------------
+    #[test]
+    fn sxd() {
+        let a = MatrixSxD::try_from(A.map(|r| r.to_vec())).unwrap();
+        let svd = a.svd();
+        let _: &MatrixSxS<f64, 3, 3> = &svd.u;
+        check(&a.data, (&svd.u.data, &svd.s.data, &svd.vt.data), 3, 2);
+    }
+    #[test]
+    fn sxd_thin() {
+        let a = MatrixSxD::try_from(A.map(|r| r.to_vec())).unwrap();
+        let svd = a.svd_thin();
+        let _: &MatrixSxD<f64, 3> = &svd.u;
+        assert_eq!(svd.u.columns(), 2);
+        check(&a.data, (&svd.u.data, &svd.s.data, &svd.vt.data), 3, 2);
+    }
+    // MatrixSxS
+    // --------------------------------------------------
+    #[test]
+    fn sxs() {
+        let a = MatrixSxS::from(A);
+        let svd = a.svd();
+        let _: &Svd<MatrixSxS<f64, 3, 3>, ColumnVectorS<f64, 2>, MatrixSxS<f64, 2, 2>> = &svd;
+        check(&a.data, (&svd.u.data, &svd.s.data, &svd.vt.data), 3, 2);
+        assert_approx_eq(&svd.s.data, &values());
+    }
+    #[test]
+    fn sxs_thin() {
+        let a = MatrixSxS::from(A);
+        let svd = a.svd_thin();
+        let _: &Svd<MatrixSxS<f64, 3, 2>, ColumnVectorS<f64, 2>, MatrixSxS<f64, 2, 2>> = &svd;
+        check(&a.data, (&svd.u.data, &svd.s.data, &svd.vt.data), 3, 2);
+    }
+    #[test]
+    fn sxs_wide() {
+        let a = MatrixSxS::<f64, 2, 3>::from([[1., 2., 1.], [2., 1., 2.]]);
+        let svd = a.svd_thin();
+        let _: &Svd<MatrixSxS<f64, 2, 2>, ColumnVectorS<f64, 2>, MatrixSxS<f64, 2, 3>> = &svd;
+        check(&a.data, (&svd.u.data, &svd.s.data, &svd.vt.data), 2, 3);
+    }
+}
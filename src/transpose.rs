@@ -1,35 +1,45 @@
 use crate::*;
+use num_complex::Complex;
+use num_traits::Num;
+use std::ops::Neg;
 
-// MatrixSxS
+// Matrix
 // --------------------------------------------------
-impl<T: Default + Copy, const ROWS: usize, const COLUMNS: usize> MatrixSxS<T, ROWS, COLUMNS>
+impl<T, R: Dim, C: Dim, L: Layout> Matrix<T, R, C, L>
 where
-    [(); ROWS * COLUMNS]:,
-    [(); COLUMNS * ROWS]:,
+    (R, C, L): Storage<T>,
 {
-    /// Returns the transposition of `self` as a new matrix.
+    /// Returns the transposition of `self`, this is free as it only swaps the layout.
+    ///
+    /// The transposition of a row-major matrix is column-major and vice versa, use
+    ///  [`Matrix::to_layout`] to reorder the data afterwards if needed.
     /// ```
-    /// use static_la::MatrixSxS;
+    /// use static_la::*;
     /// let a = MatrixSxS::<i32,2,3>::from([[1,2,3],[4,5,6]]);
-    /// assert_eq!(a.transpose(),MatrixSxS::<i32,3,2>::from([[1,4],[2,5],[3,6]]));
+    /// let b: Matrix<i32, Static<3>, Static<2>, ColumnMajor> = a.transpose();
+    /// assert_eq!(b,MatrixSxS::<i32,3,2>::from([[1,4],[2,5],[3,6]]));
+    /// assert_eq!(b.as_slice(),&[1,2,3,4,5,6]);
     /// ```
-    pub fn transpose(&self) -> MatrixSxS<T, COLUMNS, ROWS> {
-        let mut data = [Default::default(); COLUMNS * ROWS];
-        for i in 0..ROWS {
-            for j in 0..COLUMNS {
-                data[j * ROWS + i] = self.data[i * COLUMNS + j].clone();
-            }
+    pub fn transpose(self) -> Matrix<T, C, R, L::Transposed>
+    where
+        (C, R, L::Transposed): Storage<T, Buffer = <(R, C, L) as Storage<T>>::Buffer>,
+    {
+        Matrix {
+            data: self.data,
+            rows: self.columns,
+            columns: self.rows,
         }
-        MatrixSxS { data }
     }
 }
+// MatrixSxS
+// --------------------------------------------------
 impl<T, const ROWS: usize, const COLUMNS: usize> MatrixSxS<T, ROWS, COLUMNS>
 where
     [(); ROWS * COLUMNS]:,
 {
     /// Returns a transposition mask over `self` which allows operations by reference on the transposition of `self` without cloning the underlying data.
     ///
-    /// This is generally preferable over [`Self::transpose`].
+    /// Unlike [`Matrix::transpose`] this borrows `self` and results of operations are row-major.
     pub fn transpose_ref<'a>(&'a self) -> TransposeSxS<'a, T, ROWS, COLUMNS> {
         TransposeSxS(&self)
     }
@@ -37,16 +47,15 @@ where
 /// A mask allowing operations on the transposition of a `MatrixSxD` by reference without cloning
 ///  the underlying data.
 ///
-/// This type only implements operations in which it is consumed. This is because as part of any
-///  operation it is in, it performs a transpose on the underlying data. While this allows
-///  performing operations on the transposition of a matrix without cloning the underlying data
-///  it means it is only performant to do this when you only need the transpose for 1 operation.
-///  If you where to use this for multiple operations you would be performing the transposition
-///  for each operation. So unless you have particularly heavy memory requirements it is best
-///  to use [`MatrixSxS::transpose`] instead if you need to use the transposition in multiple operations.
+/// This type only implements elementwise operations in which it is consumed. This is because as
+///  part of these it performs a transpose on the underlying data. As [`Matrix::transpose`] is
+///  free when `self` can be given up, this is only useful when `self` is still needed afterwards.
+///
+/// [`Matmul`] is the exception, it borrows this type and passes the transposition on to the
+///  multiplication itself so the underlying data is never copied.
 #[derive(Debug)]
 pub struct TransposeSxS<'a, T, const ROWS: usize, const COLUMNS: usize>(
-    &'a MatrixSxS<T, ROWS, COLUMNS>,
+    pub(crate) &'a MatrixSxS<T, ROWS, COLUMNS>,
 )
 where
     [(); ROWS * COLUMNS]:;
@@ -63,31 +72,10 @@ where
 }
 // MatrixSxD
 // --------------------------------------------------
-impl<T: Default + Clone, const ROWS: usize> MatrixSxD<T, ROWS> {
-    /// Returns the transposition of `self` as a new matrix.
-    /// ```
-    /// use static_la::*;
-    /// use std::convert::TryFrom;
-    /// let a = MatrixSxD::<i32,2>::try_from([vec![1,2,3],vec![4,5,6]]).unwrap();
-    /// assert_eq!(a.transpose(),MatrixDxS::<i32,2>::from(vec![[1,4],[2,5],[3,6]]));
-    /// ```
-    pub fn transpose(&self) -> MatrixDxS<T, ROWS> {
-        let mut data = vec![Default::default(); ROWS * self.columns];
-        for i in 0..ROWS {
-            for j in 0..self.columns {
-                data[j * ROWS + i] = self.data[i * self.columns + j].clone();
-            }
-        }
-        MatrixDxS {
-            data,
-            rows: self.columns,
-        }
-    }
-}
 impl<T, const ROWS: usize> MatrixSxD<T, ROWS> {
     /// Returns a transposition mask over `self` which allows operations by reference on the transposition of `self` without cloning the underlying data.
     ///
-    /// This is generally preferable over [`Self::transpose`].
+    /// Unlike [`Matrix::transpose`] this borrows `self` and results of operations are row-major.
     pub fn transpose_ref<'a>(&'a self) -> TransposeSxD<'a, T, ROWS> {
         TransposeSxD(&self)
     }
@@ -95,15 +83,14 @@ impl<T, const ROWS: usize> MatrixSxD<T, ROWS> {
 /// A mask allowing operations on the transposition of a `MatrixSxD` by reference without cloning
 ///  the underlying data.
 ///
-/// This type only implements operations in which it is consumed. This is because as part of any
-///  operation it is in, it performs a transpose on the underlying data. While this allows
-///  performing operations on the transposition of a matrix without cloning the underlying data
-///  it means it is only performant to do this when you only need the transpose for 1 operation.
-///  If you where to use this for multiple operations you would be performing the transposition
-///  for each operation. So unless you have particularly heavy memory requirements it is best
-///  to use [`MatrixSxD::transpose`] instead if you need to use the transposition in multiple operations.
+/// This type only implements elementwise operations in which it is consumed. This is because as
+///  part of these it performs a transpose on the underlying data. As [`Matrix::transpose`] is
+///  free when `self` can be given up, this is only useful when `self` is still needed afterwards.
+///
+/// [`Matmul`] is the exception, it borrows this type and passes the transposition on to the
+///  multiplication itself so the underlying data is never copied.
 #[derive(Debug)]
-pub struct TransposeSxD<'a, T, const ROWS: usize>(&'a MatrixSxD<T, ROWS>);
+pub struct TransposeSxD<'a, T, const ROWS: usize>(pub(crate) &'a MatrixSxD<T, ROWS>);
 impl<'a, T, const ROWS: usize> TransposeSxD<'a, T, ROWS> {
     /// An iterator over elements in transpose order.
     pub fn iter(&'a self) -> impl Iterator<Item = &'a T> {
@@ -118,31 +105,10 @@ impl<'a, T, const ROWS: usize> TransposeSxD<'a, T, ROWS> {
 }
 // MatrixDxS
 // --------------------------------------------------
-impl<T: Default + Clone, const COLUMNS: usize> MatrixDxS<T, COLUMNS> {
-    /// Returns the transposition of `self` as a new matrix.
-    /// ```
-    /// use static_la::*;
-    /// use std::convert::TryFrom;
-    /// let a = MatrixDxS::<i32,3>::from(vec![[1,2,3],[4,5,6]]);
-    /// assert_eq!(a.transpose(),MatrixSxD::<i32,3>::try_from([vec![1,4],vec![2,5],vec![3,6]]).unwrap());
-    /// ```
-    pub fn transpose(&self) -> MatrixSxD<T, COLUMNS> {
-        let mut data = vec![Default::default(); self.rows * COLUMNS];
-        for i in 0..self.rows {
-            for j in 0..COLUMNS {
-                data[j * self.rows + i] = self.data[i * COLUMNS + j].clone();
-            }
-        }
-        MatrixSxD {
-            data,
-            columns: self.rows,
-        }
-    }
-}
 impl<T, const COLUMNS: usize> MatrixDxS<T, COLUMNS> {
     /// Returns a transposition mask over `self` which allows operations by reference on the transposition of `self` without cloning the underlying data.
     ///
-    /// This is generally preferable over [`Self::transpose`].
+    /// Unlike [`Matrix::transpose`] this borrows `self` and results of operations are row-major.
     pub fn transpose_ref<'a>(&'a self) -> TransposeDxS<'a, T, COLUMNS> {
         TransposeDxS(&self)
     }
@@ -150,15 +116,14 @@ impl<T, const COLUMNS: usize> MatrixDxS<T, COLUMNS> {
 /// A mask allowing operations on the transposition of a `MatrixDxS` by reference without cloning
 ///  the underlying data.
 ///
-/// This type only implements operations in which it is consumed. This is because as part of any
-///  operation it is in, it performs a transpose on the underlying data. While this allows
-///  performing operations on the transposition of a matrix without cloning the underlying data
-///  it means it is only performant to do this when you only need the transpose for 1 operation.
-///  If you where to use this for multiple operations you would be performing the transposition
-///  for each operation. So unless you have particularly heavy memory requirements it is best
-///  to use [`MatrixDxS::transpose`] instead if you need to use the transposition in multiple operations.
+/// This type only implements elementwise operations in which it is consumed. This is because as
+///  part of these it performs a transpose on the underlying data. As [`Matrix::transpose`] is
+///  free when `self` can be given up, this is only useful when `self` is still needed afterwards.
+///
+/// [`Matmul`] is the exception, it borrows this type and passes the transposition on to the
+///  multiplication itself so the underlying data is never copied.
 #[derive(Debug)]
-pub struct TransposeDxS<'a, T, const COLUMNS: usize>(&'a MatrixDxS<T, COLUMNS>);
+pub struct TransposeDxS<'a, T, const COLUMNS: usize>(pub(crate) &'a MatrixDxS<T, COLUMNS>);
 impl<'a, T, const COLUMNS: usize> TransposeDxS<'a, T, COLUMNS> {
     /// An iterator over elements in transpose order.
     pub fn iter(&'a self) -> impl Iterator<Item = &'a T> {
@@ -173,32 +138,10 @@ impl<'a, T, const COLUMNS: usize> TransposeDxS<'a, T, COLUMNS> {
 }
 // MatrixDxD
 // --------------------------------------------------
-impl<T: Default + Clone> MatrixDxD<T> {
-    /// Returns the transposition of `self` as a new matrix.
-    /// ```
-    /// use static_la::MatrixDxD;
-    /// use std::convert::TryFrom;
-    /// let a = MatrixDxD::try_from(vec![vec![1,2,3],vec![4,5,6]]).unwrap();
-    /// assert_eq!(a.transpose(),MatrixDxD::try_from(vec![vec![1,4],vec![2,5],vec![3,6]]).unwrap());
-    /// ```
-    pub fn transpose(&self) -> MatrixDxD<T> {
-        let mut data = vec![Default::default(); self.rows * self.columns];
-        for i in 0..self.rows {
-            for j in 0..self.columns {
-                data[j * self.rows + i] = self.data[i * self.columns + j].clone();
-            }
-        }
-        MatrixDxD {
-            data,
-            rows: self.columns,
-            columns: self.rows,
-        }
-    }
-}
 impl<T> MatrixDxD<T> {
     /// Returns a transposition mask over `self` which allows operations by reference on the transposition of `self` without cloning the underlying data.
     ///
-    /// This is generally preferable over [`Self::transpose`].
+    /// Unlike [`Matrix::transpose`] this borrows `self` and results of operations are row-major.
     pub fn transpose_ref<'a>(&'a self) -> TransposeDxD<'a, T> {
         TransposeDxD(&self)
     }
@@ -206,15 +149,14 @@ impl<T> MatrixDxD<T> {
 /// A mask allowing operations on the transposition of a `MatrixDxD` by reference without cloning
 ///  the underlying data.
 ///
-/// This type only implements operations in which it is consumed. This is because as part of any
-///  operation it is in, it performs a transpose on the underlying data. While this allows
-///  performing operations on the transposition of a matrix without cloning the underlying data
-///  it means it is only performant to do this when you only need the transpose for 1 operation.
-///  If you where to use this for multiple operations you would be performing the transposition
-///  for each operation. So unless you have particularly heavy memory requirements it is best
-///  to use [`MatrixDxD::transpose`] instead if you need to use the transposition in multiple operations.
+/// This type only implements elementwise operations in which it is consumed. This is because as
+///  part of these it performs a transpose on the underlying data. As [`Matrix::transpose`] is
+///  free when `self` can be given up, this is only useful when `self` is still needed afterwards.
+///
+/// [`Matmul`] is the exception, it borrows this type and passes the transposition on to the
+///  multiplication itself so the underlying data is never copied.
 #[derive(Debug)]
-pub struct TransposeDxD<'a, T>(&'a MatrixDxD<T>);
+pub struct TransposeDxD<'a, T>(pub(crate) &'a MatrixDxD<T>);
 impl<'a, T> TransposeDxD<'a, T> {
     /// An iterator over elements in transpose order.
     pub fn iter(&'a self) -> impl Iterator<Item = &'a T> {
@@ -231,3 +173,204 @@ impl<'a, T> TransposeDxD<'a, T> {
         self.0.rows
     }
 }
+// Conjugate transpose
+// --------------------------------------------------
+impl<T: Clone + Num + Neg<Output = T>, const ROWS: usize, const COLUMNS: usize>
+    MatrixSxS<Complex<T>, ROWS, COLUMNS>
+where
+    [(); ROWS * COLUMNS]:,
+    [(); COLUMNS * ROWS]:,
+{
+    /// Returns the conjugate transposition of `self` as a new matrix.
+    /// ```
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use num_complex::Complex;
+    /// use static_la::MatrixSxS;
+    /// let a = MatrixSxS::<_, 1, 2>::from([[Complex::new(1, 2), Complex::new(3, -4)]]);
+    /// let b = MatrixSxS::<_, 2, 1>::from([[Complex::new(1, -2)], [Complex::new(3, 4)]]);
+    /// assert_eq!(a.conj_transpose(), b);
+    /// ```
+    pub fn conj_transpose(&self) -> MatrixSxS<Complex<T>, COLUMNS, ROWS> {
+        MatrixSxS {
+            data: std::array::from_fn(|i| self.data[(i % ROWS) * COLUMNS + i / ROWS].conj()),
+            rows: Static,
+            columns: Static,
+        }
+    }
+}
+impl<T, const ROWS: usize, const COLUMNS: usize> MatrixSxS<Complex<T>, ROWS, COLUMNS>
+where
+    [(); ROWS * COLUMNS]:,
+{
+    /// Returns a conjugate transposition mask over `self` which allows operations by reference on the conjugate transposition of `self` without cloning the underlying data.
+    pub fn conj_transpose_ref<'a>(&'a self) -> ConjugateTransposeSxS<'a, T, ROWS, COLUMNS> {
+        ConjugateTransposeSxS(&self)
+    }
+}
+/// A mask allowing operations on the conjugate transposition of a `MatrixSxS` by reference
+///  without cloning the underlying data.
+///
+/// Like [`TransposeSxS`] the conjugate transposition is performed each time the mask is iterated
+///  over, while [`Matmul`] passes it on to the multiplication itself so the underlying data is
+///  never copied.
+#[derive(Debug)]
+pub struct ConjugateTransposeSxS<'a, T, const ROWS: usize, const COLUMNS: usize>(
+    pub(crate) &'a MatrixSxS<Complex<T>, ROWS, COLUMNS>,
+)
+where
+    [(); ROWS * COLUMNS]:;
+impl<'a, T: Clone + Num + Neg<Output = T>, const ROWS: usize, const COLUMNS: usize>
+    ConjugateTransposeSxS<'a, T, ROWS, COLUMNS>
+where
+    [(); ROWS * COLUMNS]:,
+{
+    /// An iterator over conjugated elements in transpose order.
+    pub fn iter(&'a self) -> impl Iterator<Item = Complex<T>> + 'a {
+        (0..COLUMNS)
+            .map(move |j| (0..ROWS).map(move |i| self.0.data[i * COLUMNS + j].conj()))
+            .flatten()
+    }
+    /// Gets transposed number of rows.
+    pub fn rows(&self) -> usize {
+        COLUMNS
+    }
+    /// Gets transposed number of columns.
+    pub fn columns(&self) -> usize {
+        ROWS
+    }
+}
+impl<T: Clone + Num + Neg<Output = T>, const ROWS: usize> MatrixSxD<Complex<T>, ROWS> {
+    /// Returns the conjugate transposition of `self` as a new matrix.
+    pub fn conj_transpose(&self) -> MatrixDxS<Complex<T>, ROWS> {
+        MatrixDxS {
+            data: self.conj_transpose_ref().iter().collect(),
+            rows: self.columns,
+            columns: Static,
+        }
+    }
+}
+impl<T, const ROWS: usize> MatrixSxD<Complex<T>, ROWS> {
+    /// Returns a conjugate transposition mask over `self` which allows operations by reference on the conjugate transposition of `self` without cloning the underlying data.
+    pub fn conj_transpose_ref<'a>(&'a self) -> ConjugateTransposeSxD<'a, T, ROWS> {
+        ConjugateTransposeSxD(&self)
+    }
+}
+/// A mask allowing operations on the conjugate transposition of a `MatrixSxD` by reference
+///  without cloning the underlying data.
+///
+/// Like [`TransposeSxD`] the conjugate transposition is performed each time the mask is iterated
+///  over, while [`Matmul`] passes it on to the multiplication itself so the underlying data is
+///  never copied.
+#[derive(Debug)]
+pub struct ConjugateTransposeSxD<'a, T, const ROWS: usize>(
+    pub(crate) &'a MatrixSxD<Complex<T>, ROWS>,
+);
+impl<'a, T: Clone + Num + Neg<Output = T>, const ROWS: usize> ConjugateTransposeSxD<'a, T, ROWS> {
+    /// An iterator over conjugated elements in transpose order.
+    pub fn iter(&'a self) -> impl Iterator<Item = Complex<T>> + 'a {
+        (0..self.0.columns)
+            .map(move |j| (0..ROWS).map(move |i| self.0.data[i * self.0.columns + j].conj()))
+            .flatten()
+    }
+    /// Gets transposed number of rows.
+    pub fn rows(&self) -> usize {
+        self.0.columns
+    }
+    /// Gets transposed number of columns.
+    pub fn columns(&self) -> usize {
+        ROWS
+    }
+}
+impl<T: Clone + Num + Neg<Output = T>, const COLUMNS: usize> MatrixDxS<Complex<T>, COLUMNS> {
+    /// Returns the conjugate transposition of `self` as a new matrix.
+    pub fn conj_transpose(&self) -> MatrixSxD<Complex<T>, COLUMNS> {
+        MatrixSxD {
+            data: self.conj_transpose_ref().iter().collect(),
+            columns: self.rows,
+            rows: Static,
+        }
+    }
+}
+impl<T, const COLUMNS: usize> MatrixDxS<Complex<T>, COLUMNS> {
+    /// Returns a conjugate transposition mask over `self` which allows operations by reference on the conjugate transposition of `self` without cloning the underlying data.
+    pub fn conj_transpose_ref<'a>(&'a self) -> ConjugateTransposeDxS<'a, T, COLUMNS> {
+        ConjugateTransposeDxS(&self)
+    }
+}
+/// A mask allowing operations on the conjugate transposition of a `MatrixDxS` by reference
+///  without cloning the underlying data.
+///
+/// Like [`TransposeDxS`] the conjugate transposition is performed each time the mask is iterated
+///  over, while [`Matmul`] passes it on to the multiplication itself so the underlying data is
+///  never copied.
+#[derive(Debug)]
+pub struct ConjugateTransposeDxS<'a, T, const COLUMNS: usize>(
+    pub(crate) &'a MatrixDxS<Complex<T>, COLUMNS>,
+);
+impl<'a, T: Clone + Num + Neg<Output = T>, const COLUMNS: usize>
+    ConjugateTransposeDxS<'a, T, COLUMNS>
+{
+    /// An iterator over conjugated elements in transpose order.
+    pub fn iter(&'a self) -> impl Iterator<Item = Complex<T>> + 'a {
+        (0..COLUMNS)
+            .map(move |j| (0..self.0.rows).map(move |i| self.0.data[i * COLUMNS + j].conj()))
+            .flatten()
+    }
+    /// Gets transposed number of rows.
+    pub fn rows(&self) -> usize {
+        COLUMNS
+    }
+    /// Gets transposed number of columns.
+    pub fn columns(&self) -> usize {
+        self.0.rows
+    }
+}
+impl<T: Clone + Num + Neg<Output = T>> MatrixDxD<Complex<T>> {
+    /// Returns the conjugate transposition of `self` as a new matrix.
+    /// ```
+    /// use num_complex::Complex;
+    /// use static_la::MatrixDxD;
+    /// use std::convert::TryFrom;
+    /// let a = MatrixDxD::try_from(vec![vec![Complex::new(1, 2), Complex::new(3, -4)]]).unwrap();
+    /// let b = MatrixDxD::try_from(vec![vec![Complex::new(1, -2)], vec![Complex::new(3, 4)]]).unwrap();
+    /// assert_eq!(a.conj_transpose(), b);
+    /// ```
+    pub fn conj_transpose(&self) -> MatrixDxD<Complex<T>> {
+        MatrixDxD {
+            data: self.conj_transpose_ref().iter().collect(),
+            rows: self.columns,
+            columns: self.rows,
+        }
+    }
+}
+impl<T> MatrixDxD<Complex<T>> {
+    /// Returns a conjugate transposition mask over `self` which allows operations by reference on the conjugate transposition of `self` without cloning the underlying data.
+    pub fn conj_transpose_ref<'a>(&'a self) -> ConjugateTransposeDxD<'a, T> {
+        ConjugateTransposeDxD(&self)
+    }
+}
+/// A mask allowing operations on the conjugate transposition of a `MatrixDxD` by reference
+///  without cloning the underlying data.
+///
+/// Like [`TransposeDxD`] the conjugate transposition is performed each time the mask is iterated
+///  over, while [`Matmul`] passes it on to the multiplication itself so the underlying data is
+///  never copied.
+#[derive(Debug)]
+pub struct ConjugateTransposeDxD<'a, T>(pub(crate) &'a MatrixDxD<Complex<T>>);
+impl<'a, T: Clone + Num + Neg<Output = T>> ConjugateTransposeDxD<'a, T> {
+    /// An iterator over conjugated elements in transpose order.
+    pub fn iter(&'a self) -> impl Iterator<Item = Complex<T>> + 'a {
+        (0..self.0.columns)
+            .map(move |j| (0..self.0.rows).map(move |i| self.0.data[i * self.0.columns + j].conj()))
+            .flatten()
+    }
+    /// Gets transposed number of rows.
+    pub fn rows(&self) -> usize {
+        self.0.columns
+    }
+    /// Gets transposed number of columns.
+    pub fn columns(&self) -> usize {
+        self.0.rows
+    }
+}
@@ -66,6 +66,7 @@ impl<T: Clone, const ROWS: usize> TryFrom<[Vec<T>; ROWS]> for MatrixSxD<T, ROWS>
         Ok(Self {
             data: data.concat(),
             columns: data[0].len(),
+            rows: Static,
         })
     }
 }
@@ -76,6 +77,7 @@ impl<T, const ROWS: usize> TryFrom<(usize, Vec<T>)> for MatrixSxD<T, ROWS> {
             Ok(Self {
                 data: data,
                 columns,
+                rows: Static,
             })
         } else {
             Err("Inside `Vec`s differ in length.")
@@ -89,6 +91,7 @@ impl<T: Clone, const ROWS: usize> TryFrom<(usize, &[T])> for MatrixSxD<T, ROWS>
             Ok(Self {
                 data: Vec::from(data),
                 columns,
+                rows: Static,
             })
         } else {
             Err("Inside `Vec`s differ in length.")
@@ -101,7 +104,11 @@ impl<T, const COLUMNS: usize> TryFrom<(usize, Vec<T>)> for MatrixDxS<T, COLUMNS>
     type Error = &'static str;
     fn try_from((rows, data): (usize, Vec<T>)) -> Result<Self, Self::Error> {
         if COLUMNS * rows == data.len() {
-            Ok(Self { data: data, rows })
+            Ok(Self {
+                data: data,
+                rows,
+                columns: Static,
+            })
         } else {
             Err("Inside `Vec`s differ in length.")
         }
@@ -114,6 +121,7 @@ impl<T: Clone, const COLUMNS: usize> TryFrom<(usize, &[T])> for MatrixDxS<T, COL
             Ok(Self {
                 data: Vec::from(data),
                 rows,
+                columns: Static,
             })
         } else {
             Err("Inside `Vec`s differ in length.")